@@ -0,0 +1,54 @@
+use std::hint::black_box;
+use bram_audio_parser::{AudioData, WavBinary};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const FRAMES: usize = 1 << 20;
+
+fn pcm_wav(bits: u16) -> Vec<u8> {
+    let width = bits as usize / 8;
+    let audio: Vec<u8> = (0..FRAMES * 2 * width).map(|i| (i * 31) as u8).collect();
+    let block_align = 2 * width as u16;
+    let mut data = Vec::with_capacity(44 + audio.len());
+    data.extend_from_slice(b"RIFF");
+    data.extend_from_slice(&(36 + audio.len() as u32).to_le_bytes());
+    data.extend_from_slice(b"WAVEfmt ");
+    data.extend_from_slice(&16u32.to_le_bytes());
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&2u16.to_le_bytes());
+    data.extend_from_slice(&44100u32.to_le_bytes());
+    data.extend_from_slice(&(44100 * block_align as u32).to_le_bytes());
+    data.extend_from_slice(&block_align.to_le_bytes());
+    data.extend_from_slice(&bits.to_le_bytes());
+    data.extend_from_slice(b"data");
+    data.extend_from_slice(&(audio.len() as u32).to_le_bytes());
+    data.extend_from_slice(&audio);
+    data
+}
+
+fn scalar_i16(bytes: &[u8]) -> Vec<i16> {
+    let mut samples = Vec::with_capacity(bytes.len() / 2);
+    for i in 0..bytes.len() / 2 {
+        samples.push(i16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]]));
+    }
+    samples.into_iter().map(|sample| ((sample as i32) << 16 >> 16) as i16).collect()
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for bits in [16u16, 24] {
+        let wav = WavBinary::from_bytes(pcm_wav(bits)).unwrap();
+        group.throughput(Throughput::Bytes(wav.data.len() as u64));
+        group.bench_function(format!("pcm{bits}_to_i16"), |b| {
+            b.iter(|| AudioData::try_from(black_box(&wav)).unwrap())
+        });
+        group.bench_function(format!("pcm{bits}_to_f32"), |b| {
+            b.iter(|| AudioData::<f32>::from_wav(black_box(&wav)).unwrap())
+        });
+    }
+    let wav = pcm_wav(16);
+    group.bench_function("pcm16_scalar_reference", |b| b.iter(|| scalar_i16(black_box(&wav[44..]))));
+    group.finish();
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
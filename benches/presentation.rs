@@ -0,0 +1,32 @@
+use std::hint::black_box;
+use bram_audio_parser::{encode_wav, load_presentation_from_bytes, AudioData, DownsampleStrategy, Presentation};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const SAMPLE_RATE: u32 = 44100;
+const FRAMES: usize = SAMPLE_RATE as usize * 180;
+
+fn long_recording() -> Vec<u8> {
+    let samples = (0..FRAMES * 2).map(|i| ((i * 7919) % 65536) as u16 as i16).collect();
+    encode_wav(&AudioData { samples, channels: 2, sample_rate: SAMPLE_RATE, bits_per_sample: 16, channel_mask: None })
+}
+
+fn presentation(c: &mut Criterion) {
+    let bytes = long_recording();
+    let path = std::env::temp_dir().join("bram_bench_long_recording.wav");
+    std::fs::write(&path, &bytes).unwrap();
+
+    let mut group = c.benchmark_group("presentation");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(FRAMES as u64));
+    group.bench_function("from_bytes_decimate", |b| b.iter(|| load_presentation_from_bytes(black_box(&bytes), 100).unwrap()));
+    for (name, strategy) in [("file_peak", DownsampleStrategy::Peak), ("file_percentile", DownsampleStrategy::Percentile { low: 10.0, high: 90.0 })] {
+        group.bench_function(name, |b| {
+            b.iter(|| Presentation::builder(black_box(&path)).rate(100).strategy(strategy).build_stereo().unwrap())
+        });
+    }
+    group.finish();
+    let _ = std::fs::remove_file(path);
+}
+
+criterion_group!(benches, presentation);
+criterion_main!(benches);
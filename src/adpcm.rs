@@ -0,0 +1,303 @@
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::error::BramError;
+
+pub(crate) const WAVE_FORMAT_ADPCM: u16 = 0x0002;
+pub(crate) const WAVE_FORMAT_IMA_ADPCM: u16 = 0x0011;
+
+const IMA_INDEX_TABLE: [i32; 16] = [
+    -1, -1, -1, -1, 2, 4, 6, 8,
+    -1, -1, -1, -1, 2, 4, 6, 8,
+];
+
+const IMA_STEP_TABLE: [i32; 89] = [
+    7, 8, 9, 10, 11, 12, 13, 14, 16, 17,
+    19, 21, 23, 25, 28, 31, 34, 37, 41, 45,
+    50, 55, 60, 66, 73, 80, 88, 97, 107, 118,
+    130, 143, 157, 173, 190, 209, 230, 253, 279, 307,
+    337, 371, 408, 449, 494, 544, 598, 658, 724, 796,
+    876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878, 2066,
+    2272, 2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358,
+    5894, 6484, 7132, 7845, 8630, 9493, 10442, 11487, 12635, 13899,
+    15289, 16818, 18500, 20350, 22385, 24623, 27086, 29794, 32767,
+];
+
+const MS_ADAPTATION_TABLE: [i32; 16] = [
+    230, 230, 230, 230, 307, 409, 512, 614,
+    768, 614, 512, 409, 307, 230, 230, 230,
+];
+
+pub(crate) const MS_DEFAULT_COEFFICIENTS: [(i16, i16); 7] = [
+    (256, 0),
+    (512, -256),
+    (0, 0),
+    (192, 64),
+    (240, 0),
+    (460, -208),
+    (392, -232),
+];
+
+struct ImaChannel {
+    predictor: i32,
+    step_index: i32,
+}
+
+impl ImaChannel {
+    fn decode(&mut self, nibble: u8) -> i16 {
+        let step = IMA_STEP_TABLE[self.step_index as usize];
+        let mut diff = step >> 3;
+        if nibble & 1 != 0 {
+            diff += step >> 2;
+        }
+        if nibble & 2 != 0 {
+            diff += step >> 1;
+        }
+        if nibble & 4 != 0 {
+            diff += step;
+        }
+        if nibble & 8 != 0 {
+            self.predictor -= diff;
+        } else {
+            self.predictor += diff;
+        }
+        self.predictor = self.predictor.clamp(i16::MIN as i32, i16::MAX as i32);
+        self.step_index = (self.step_index + IMA_INDEX_TABLE[nibble as usize]).clamp(0, 88);
+        self.predictor as i16
+    }
+}
+
+struct MsChannel {
+    coefficients: (i32, i32),
+    delta: i32,
+    sample1: i32,
+    sample2: i32,
+}
+
+impl MsChannel {
+    fn decode(&mut self, nibble: u8) -> i16 {
+        let signed = if nibble & 8 != 0 { nibble as i32 - 16 } else { nibble as i32 };
+        let predicted = (self.sample1 * self.coefficients.0 + self.sample2 * self.coefficients.1) >> 8;
+        let sample = (predicted + signed * self.delta).clamp(i16::MIN as i32, i16::MAX as i32);
+        self.sample2 = self.sample1;
+        self.sample1 = sample;
+        self.delta = ((MS_ADAPTATION_TABLE[nibble as usize] * self.delta) >> 8).max(16);
+        sample as i16
+    }
+}
+
+pub(crate) fn decode_ima(data: &[u8], channels: u16, block_align: usize, frames: Option<usize>) -> Result<Vec<i16>, BramError> {
+    let channels = channels as usize;
+    let header_size = 4 * channels;
+    if channels == 0 || block_align <= header_size || !(block_align - header_size).is_multiple_of(4 * channels) {
+        return Err(BramError::Decode("invalid ima adpcm block alignment".to_string()));
+    }
+
+    let mut samples = Vec::new();
+    for block in data.chunks(block_align) {
+        if block.len() < header_size {
+            break;
+        }
+        let mut states: Vec<ImaChannel> = (0..channels)
+            .map(|ch| {
+                let header = &block[ch * 4..ch * 4 + 4];
+                ImaChannel {
+                    predictor: i16::from_le_bytes([header[0], header[1]]) as i32,
+                    step_index: (header[2] as i32).clamp(0, 88),
+                }
+            })
+            .collect();
+        for state in &states {
+            samples.push(state.predictor as i16);
+        }
+
+        let body = &block[header_size..];
+        let group_size = 4 * channels;
+        let mut frame_buffer = vec![0i16; 8 * channels];
+        for group in body.chunks_exact(group_size) {
+            for (ch, state) in states.iter_mut().enumerate() {
+                for (i, byte) in group[ch * 4..ch * 4 + 4].iter().enumerate() {
+                    frame_buffer[(i * 2) * channels + ch] = state.decode(byte & 0x0F);
+                    frame_buffer[(i * 2 + 1) * channels + ch] = state.decode(byte >> 4);
+                }
+            }
+            samples.extend_from_slice(&frame_buffer);
+        }
+    }
+
+    truncate_to_frames(&mut samples, channels, frames);
+    Ok(samples)
+}
+
+pub(crate) fn decode_ms(data: &[u8], channels: u16, block_align: usize, coefficients: &[(i16, i16)], frames: Option<usize>) -> Result<Vec<i16>, BramError> {
+    let channels = channels as usize;
+    let header_size = 7 * channels;
+    if channels == 0 || channels > 2 || block_align <= header_size {
+        return Err(BramError::Decode("invalid ms adpcm block alignment".to_string()));
+    }
+
+    let mut samples = Vec::new();
+    for block in data.chunks(block_align) {
+        if block.len() < header_size {
+            break;
+        }
+        let mut states = Vec::with_capacity(channels);
+        for ch in 0..channels {
+            let predictor = block[ch] as usize;
+            let (c1, c2) = *coefficients.get(predictor).ok_or_else(|| BramError::Decode("invalid ms adpcm predictor index".to_string()))?;
+            let read_i16 = |offset: usize| i16::from_le_bytes([block[offset], block[offset + 1]]) as i32;
+            states.push(MsChannel {
+                coefficients: (c1 as i32, c2 as i32),
+                delta: read_i16(channels + ch * 2),
+                sample1: read_i16(channels * 3 + ch * 2),
+                sample2: read_i16(channels * 5 + ch * 2),
+            });
+        }
+        for state in &states {
+            samples.push(state.sample2 as i16);
+        }
+        for state in &states {
+            samples.push(state.sample1 as i16);
+        }
+
+        let mut ch = 0;
+        for byte in &block[header_size..] {
+            for nibble in [byte >> 4, byte & 0x0F] {
+                samples.push(states[ch].decode(nibble));
+                ch = (ch + 1) % channels;
+            }
+        }
+    }
+
+    truncate_to_frames(&mut samples, channels, frames);
+    Ok(samples)
+}
+
+pub(crate) fn read_ms_coefficients(fmt: &[u8]) -> Vec<(i16, i16)> {
+    if fmt.len() < 22 {
+        return MS_DEFAULT_COEFFICIENTS.to_vec();
+    }
+    let count = u16::from_le_bytes([fmt[20], fmt[21]]) as usize;
+    let coefficients: Vec<(i16, i16)> = fmt[22..]
+        .chunks_exact(4)
+        .take(count)
+        .map(|c| (
+            i16::from_le_bytes([c[0], c[1]]),
+            i16::from_le_bytes([c[2], c[3]]),
+        ))
+        .collect();
+    if coefficients.is_empty() {
+        MS_DEFAULT_COEFFICIENTS.to_vec()
+    } else {
+        coefficients
+    }
+}
+
+fn truncate_to_frames(samples: &mut Vec<i16>, channels: usize, frames: Option<usize>) {
+    let complete = samples.len() - samples.len() % channels;
+    let limit = frames.map_or(complete, |frames| complete.min(frames * channels));
+    samples.truncate(limit);
+}
+
+#[cfg(test)]
+mod adpcm_tests {
+    use crate::adpcm::{decode_ima, decode_ms, read_ms_coefficients, MS_DEFAULT_COEFFICIENTS};
+
+    #[test]
+    fn decode_ima_mono_block() {
+        let block = vec![
+            0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x77, 0x77,
+        ];
+        let samples = decode_ima(&block, 1, 8, None).unwrap();
+        assert_eq!(samples.len(), 9);
+        assert_eq!(samples[0], 16);
+        assert_eq!(samples[1], 16);
+        assert!(samples[5] > samples[4]);
+        assert!(samples[8] > samples[7]);
+    }
+
+    #[test]
+    fn decode_ima_negative_nibble_decreases_sample() {
+        let block = vec![
+            0x00, 0x00, 0x00, 0x00,
+            0x0F, 0x00, 0x00, 0x00,
+        ];
+        let samples = decode_ima(&block, 1, 8, None).unwrap();
+        assert!(samples[1] < 0);
+    }
+
+    #[test]
+    fn decode_ima_stereo_interleaves_channels() {
+        let block = vec![
+            0x64, 0x00, 0x00, 0x00,
+            0x9C, 0xFF, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let samples = decode_ima(&block, 2, 16, None).unwrap();
+        assert_eq!(samples.len(), 18);
+        assert_eq!(samples[0], 100);
+        assert_eq!(samples[1], -100);
+        assert!(samples[16] >= 100);
+        assert!(samples[17] <= -100);
+    }
+
+    #[test]
+    fn decode_ima_truncates_to_fact_frames() {
+        let block = vec![
+            0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        let samples = decode_ima(&block, 1, 8, Some(5)).unwrap();
+        assert_eq!(samples.len(), 5);
+    }
+
+    #[test]
+    fn decode_ima_rejects_invalid_block_align() {
+        assert!(decode_ima(&[0; 8], 1, 3, None).is_err());
+    }
+
+    #[test]
+    fn decode_ms_mono_block() {
+        let block = vec![
+            0x00,
+            0x10, 0x00,
+            0x64, 0x00,
+            0x32, 0x00,
+            0x00, 0x00,
+        ];
+        let samples = decode_ms(&block, 1, 9, &MS_DEFAULT_COEFFICIENTS, None).unwrap();
+        assert_eq!(samples.len(), 6);
+        assert_eq!(samples[0], 50);
+        assert_eq!(samples[1], 100);
+        assert_eq!(samples[2], 100);
+    }
+
+    #[test]
+    fn decode_ms_rejects_unknown_predictor() {
+        let block = vec![
+            0x09,
+            0x10, 0x00,
+            0x00, 0x00,
+            0x00, 0x00,
+            0x00, 0x00,
+        ];
+        assert!(decode_ms(&block, 1, 9, &MS_DEFAULT_COEFFICIENTS, None).is_err());
+    }
+
+    #[test]
+    fn read_ms_coefficients_falls_back_to_defaults() {
+        let fmt = vec![0u8; 16];
+        assert_eq!(read_ms_coefficients(&fmt), MS_DEFAULT_COEFFICIENTS.to_vec());
+    }
+
+    #[test]
+    fn read_ms_coefficients_from_extension() {
+        let mut fmt = vec![0u8; 20];
+        fmt.extend_from_slice(&1u16.to_le_bytes());
+        fmt.extend_from_slice(&256i16.to_le_bytes());
+        fmt.extend_from_slice(&(-10i16).to_le_bytes());
+        assert_eq!(read_ms_coefficients(&fmt), vec![(256, -10)]);
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::string::ToString;
+#[cfg(feature = "std")]
+use std::path::Path;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
+#[cfg(feature = "std")]
+pub(crate) fn has_aiff_extension(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ["aif", "aiff", "aifc"].iter().any(|aiff| ext.eq_ignore_ascii_case(aiff)))
+}
+
+pub(crate) fn check(data: &[u8]) -> bool {
+    data.len() >= 12
+        && &data[0..4] == b"FORM"
+        && (&data[8..12] == b"AIFF" || &data[8..12] == b"AIFC")
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<AudioData, BramError> {
+    if !check(data) {
+        return Err(BramError::InvalidHeader);
+    }
+    let is_aifc = &data[8..12] == b"AIFC";
+    let (comm_pos, comm) = find_chunk(data, b"COMM")
+        .ok_or(BramError::MissingChunk { id: *b"COMM" })?;
+    if comm.len() < 18 {
+        return Err(BramError::IncompleteChunk { id: *b"COMM", offset: comm_pos });
+    }
+    let channels = u16::from_be_bytes([comm[0], comm[1]]);
+    let frames = u32::from_be_bytes([comm[2], comm[3], comm[4], comm[5]]) as usize;
+    let bits_per_sample = u16::from_be_bytes([comm[6], comm[7]]);
+    let sample_rate = read_extended(&comm[8..18]);
+    let little_endian = match (is_aifc, comm.get(18..22)) {
+        (false, _) | (true, Some(b"NONE")) | (true, Some(b"twos")) => false,
+        (true, Some(b"sowt")) => true,
+        _ => {
+            return Err(BramError::Decode("unsupported aifc compression type".to_string()));
+        }
+    };
+
+    let (ssnd_pos, ssnd) = find_chunk(data, b"SSND")
+        .ok_or(BramError::MissingChunk { id: *b"SSND" })?;
+    if ssnd.len() < 8 {
+        return Err(BramError::IncompleteChunk { id: *b"SSND", offset: ssnd_pos });
+    }
+    let offset = u32::from_be_bytes([ssnd[0], ssnd[1], ssnd[2], ssnd[3]]) as usize;
+    let audio = ssnd.get(8 + offset..).unwrap_or_default();
+
+    let bytes_per_sample = (bits_per_sample as usize).div_ceil(8);
+    if !(1..=4).contains(&bytes_per_sample) {
+        return Err(BramError::Decode("unsupported aiff sample size".to_string()));
+    }
+    let sample_count = (frames * channels as usize).min(audio.len() / bytes_per_sample);
+    let samples = audio
+        .chunks_exact(bytes_per_sample)
+        .take(sample_count)
+        .map(|bytes| {
+            let mut word = [0u8; 4];
+            if little_endian {
+                for (i, byte) in bytes.iter().rev().enumerate() {
+                    word[i] = *byte;
+                }
+            } else {
+                word[..bytes.len()].copy_from_slice(bytes);
+            }
+            (i32::from_be_bytes(word) >> 16) as i16
+        })
+        .collect();
+
+    Ok(AudioData {
+        samples,
+        channels,
+        sample_rate,
+        bits_per_sample,
+        channel_mask: None,
+    })
+}
+
+fn find_chunk<'a>(data: &'a [u8], id: &[u8; 4]) -> Option<(usize, &'a [u8])> {
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let chunk_id = &data[pos..pos + 4];
+        let chunk_size = u32::from_be_bytes([
+            data[pos + 4],
+            data[pos + 5],
+            data[pos + 6],
+            data[pos + 7],
+        ]) as usize;
+        if chunk_id == id {
+            let end = (pos + 8 + chunk_size).min(data.len());
+            return Some((pos, &data[pos + 8..end]));
+        }
+        pos += 8 + chunk_size + chunk_size % 2;
+    }
+    None
+}
+
+fn read_extended(bytes: &[u8]) -> u32 {
+    let exponent = (((bytes[0] & 0x7F) as i32) << 8) | bytes[1] as i32;
+    let mantissa = u64::from_be_bytes([
+        bytes[2], bytes[3], bytes[4], bytes[5],
+        bytes[6], bytes[7], bytes[8], bytes[9],
+    ]);
+    if exponent == 0 && mantissa == 0 {
+        return 0;
+    }
+    let value = mantissa as f64 * 2f64.powi(exponent - 16383 - 63);
+    value.round() as u32
+}
+
+#[cfg(test)]
+mod aiff_tests {
+    #[cfg(feature = "std")]
+    use crate::aiff::has_aiff_extension;
+    use crate::aiff::{parse, read_extended};
+
+    fn aiff_bytes(form_type: &[u8; 4], compression: Option<&[u8; 4]>, sample_bytes: &[u8]) -> Vec<u8> {
+        let mut comm = Vec::new();
+        comm.extend_from_slice(&2u16.to_be_bytes());
+        comm.extend_from_slice(&2u32.to_be_bytes());
+        comm.extend_from_slice(&16u16.to_be_bytes());
+        comm.extend_from_slice(&[0x40, 0x0E, 0xAC, 0x44, 0, 0, 0, 0, 0, 0]);
+        if let Some(compression) = compression {
+            comm.extend_from_slice(compression);
+            comm.extend_from_slice(&[0, 0]);
+        }
+        let mut data = Vec::new();
+        data.extend_from_slice(b"FORM");
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(form_type);
+        data.extend_from_slice(b"COMM");
+        data.extend_from_slice(&(comm.len() as u32).to_be_bytes());
+        data.extend_from_slice(&comm);
+        data.extend_from_slice(b"SSND");
+        data.extend_from_slice(&(sample_bytes.len() as u32 + 8).to_be_bytes());
+        data.extend_from_slice(&[0; 8]);
+        data.extend_from_slice(sample_bytes);
+        data
+    }
+
+    #[test]
+    fn read_extended_decodes_common_rates() {
+        assert_eq!(read_extended(&[0x40, 0x0E, 0xAC, 0x44, 0, 0, 0, 0, 0, 0]), 44100);
+        assert_eq!(read_extended(&[0x40, 0x0E, 0xBB, 0x80, 0, 0, 0, 0, 0, 0]), 48000);
+    }
+
+    #[test]
+    fn parse_big_endian_aiff() {
+        let bytes = aiff_bytes(b"AIFF", None, &[0x00, 0x00, 0x7F, 0xFF, 0x80, 0x00, 0x00, 0x01]);
+        let audio = parse(&bytes).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.sample_rate, 44100);
+        assert_eq!(audio.samples, vec![0, 32767, -32768, 1]);
+    }
+
+    #[test]
+    fn parse_little_endian_sowt_aifc() {
+        let bytes = aiff_bytes(b"AIFC", Some(b"sowt"), &[0x00, 0x00, 0xFF, 0x7F, 0x00, 0x80, 0x01, 0x00]);
+        let audio = parse(&bytes).unwrap();
+        assert_eq!(audio.samples, vec![0, 32767, -32768, 1]);
+    }
+
+    #[test]
+    fn parse_rejects_compressed_aifc() {
+        let bytes = aiff_bytes(b"AIFC", Some(b"ima4"), &[0; 8]);
+        assert!(parse(&bytes).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_non_aiff() {
+        assert!(parse(b"RIFF\x00\x00\x00\x00WAVE").is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn has_aiff_extension_is_case_insensitive() {
+        assert!(has_aiff_extension("take.AIF"));
+        assert!(has_aiff_extension("take.aifc"));
+        assert!(!has_aiff_extension("take.wav"));
+    }
+}
@@ -0,0 +1,533 @@
+use std::ops::Range;
+use std::time::Duration;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::fft;
+
+const STATS_LEN: usize = 5;
+const LOG_FLOOR: f32 = 1e-10;
+const REGION_BLOCK_SECONDS: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureConfig {
+    pub frame_size: usize,
+    pub hop_size: usize,
+    pub bands: usize,
+}
+
+impl Default for FeatureConfig {
+    fn default() -> Self {
+        Self {
+            frame_size: 1024,
+            hop_size: 512,
+            bands: 16,
+        }
+    }
+}
+
+impl FeatureConfig {
+    pub fn vector_len(&self) -> usize {
+        self.bands * 2 + STATS_LEN
+    }
+}
+
+pub fn feature_vector(audio: &AudioData, config: &FeatureConfig) -> Result<Vec<f32>, BramError> {
+    if !config.frame_size.is_power_of_two() || config.frame_size < 2 || config.hop_size == 0 || config.bands == 0 {
+        return Err(BramError::InvalidParameter("frame size must be a power of two and hop size and bands non-zero"));
+    }
+    let samples = mono_samples(audio);
+    let filterbank = mel_filterbank(config.bands, config.frame_size, audio.sample_rate);
+    let window = fft::hann_window(config.frame_size);
+    let mut band_sums = vec![0.0f64; config.bands];
+    let mut band_squares = vec![0.0f64; config.bands];
+    let mut centroid_sum = 0.0f64;
+    let mut frame_count = 0usize;
+    for frame in frames(&samples, config.frame_size, config.hop_size) {
+        let spectrum = fft::power_spectrum(frame, &window);
+        for (band, weights) in filterbank.iter().enumerate() {
+            let energy: f32 = weights.iter().map(|(bin, weight)| spectrum[*bin] * weight).sum();
+            let log_energy = (energy + LOG_FLOOR).log10() as f64;
+            band_sums[band] += log_energy;
+            band_squares[band] += log_energy * log_energy;
+        }
+        let total: f32 = spectrum.iter().sum();
+        if total > 0.0 {
+            let weighted: f32 = spectrum.iter().enumerate().map(|(bin, power)| bin as f32 * power).sum();
+            centroid_sum += (weighted / total / (spectrum.len() - 1) as f32) as f64;
+        }
+        frame_count += 1;
+    }
+
+    let mut vector = Vec::with_capacity(config.vector_len());
+    let frames = frame_count.max(1) as f64;
+    vector.extend(band_sums.iter().map(|sum| (sum / frames) as f32));
+    vector.extend(band_sums.iter().zip(&band_squares).map(|(sum, squares)| {
+        let mean = sum / frames;
+        (squares / frames - mean * mean).max(0.0).sqrt() as f32
+    }));
+    let rms = (samples.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / samples.len().max(1) as f64).sqrt() as f32;
+    let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+    let crossings = samples.windows(2).filter(|pair| (pair[0] < 0.0) != (pair[1] < 0.0)).count();
+    vector.push(rms);
+    vector.push(peak);
+    vector.push(crossings as f32 / samples.len().max(1) as f32);
+    vector.push((centroid_sum / frames) as f32);
+    vector.push(if rms > 0.0 { peak / rms } else { 0.0 });
+    Ok(vector)
+}
+
+pub fn interesting_region(audio: &AudioData, window: Duration) -> Range<usize> {
+    let samples = mono_samples(audio);
+    let window_frames = ((window.as_secs_f64() * audio.sample_rate as f64) as usize).min(samples.len());
+    if window_frames == 0 {
+        return 0..0;
+    }
+    let block = ((REGION_BLOCK_SECONDS * audio.sample_rate as f64) as usize).clamp(1, window_frames);
+    let levels: Vec<f64> = samples
+        .chunks(block)
+        .map(|chunk| (chunk.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / chunk.len() as f64).sqrt())
+        .collect();
+    let blocks_per_window = (window_frames / block).clamp(1, levels.len());
+    let mut sums = vec![0.0f64; levels.len() + 1];
+    for (index, level) in levels.iter().enumerate() {
+        sums[index + 1] = sums[index] + level;
+    }
+    let score = |start: usize| sums[start + blocks_per_window] - sums[start];
+    let best = (0..=levels.len() - blocks_per_window).fold(0, |best, start| {
+        if score(start) > score(best) { start } else { best }
+    });
+    let start = (best * block).min(samples.len() - window_frames);
+    start..start + window_frames
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopConfig {
+    pub seam: Duration,
+    pub search: Duration,
+}
+
+impl Default for LoopConfig {
+    fn default() -> Self {
+        Self {
+            seam: Duration::from_millis(20),
+            search: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopAnalysis {
+    pub seam_correlation: f32,
+    pub seam_level_difference_db: f32,
+    pub loop_start: usize,
+    pub loop_end: usize,
+    pub loop_correlation: f32,
+}
+
+pub fn loop_analysis(audio: &AudioData, config: &LoopConfig) -> Result<LoopAnalysis, BramError> {
+    let samples = mono_samples(audio);
+    let seam = (config.seam.as_secs_f64() * audio.sample_rate as f64) as usize;
+    if seam == 0 {
+        return Err(BramError::InvalidParameter("loop seam window must cover at least one frame"));
+    }
+    if samples.len() < seam * 2 {
+        return Err(BramError::InvalidParameter("audio is shorter than two seam windows"));
+    }
+    let head = &samples[..seam];
+    let tail = &samples[samples.len() - seam..];
+    let search = (config.search.as_secs_f64() * audio.sample_rate as f64) as usize;
+    let latest_end = samples.len() - seam;
+    let earliest_end = latest_end.saturating_sub(search).max(seam);
+    let (loop_end, loop_correlation) = (earliest_end..=latest_end)
+        .rev()
+        .map(|end| (end, correlation(head, &samples[end..end + seam])))
+        .fold((latest_end, f32::MIN), |best, candidate| if candidate.1 > best.1 { candidate } else { best });
+    Ok(LoopAnalysis {
+        seam_correlation: correlation(tail, head),
+        seam_level_difference_db: 20.0 * ((rms(tail) + LOG_FLOOR) / (rms(head) + LOG_FLOOR)).log10(),
+        loop_start: 0,
+        loop_end,
+        loop_correlation,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipConfig {
+    pub threshold: i16,
+    pub min_run: usize,
+}
+
+impl Default for ClipConfig {
+    fn default() -> Self {
+        Self {
+            threshold: i16::MAX,
+            min_run: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClippedRegion {
+    pub channel: u16,
+    pub frames: Range<usize>,
+    pub start: Duration,
+    pub end: Duration,
+}
+
+pub fn clipped_regions(audio: &AudioData, config: &ClipConfig) -> Result<Vec<ClippedRegion>, BramError> {
+    if config.min_run == 0 || config.threshold <= 0 {
+        return Err(BramError::InvalidParameter("clip threshold and minimum run must be positive"));
+    }
+    let channels = audio.channels.max(1) as usize;
+    let frames = audio.samples.len() / channels;
+    let time = |frame: usize| Duration::from_secs_f64(frame as f64 / audio.sample_rate.max(1) as f64);
+    let mut regions = Vec::new();
+    for channel in 0..channels {
+        let mut run_start = None;
+        for frame in 0..=frames {
+            let clipped = frame < frames
+                && (audio.samples[frame * channels + channel] as i32).abs() >= config.threshold as i32;
+            match (clipped, run_start) {
+                (true, None) => run_start = Some(frame),
+                (false, Some(start)) => {
+                    if frame - start >= config.min_run {
+                        regions.push(ClippedRegion {
+                            channel: channel as u16,
+                            frames: start..frame,
+                            start: time(start),
+                            end: time(frame),
+                        });
+                    }
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(regions)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorrelationConfig {
+    pub window: Duration,
+    pub threshold: f32,
+}
+
+impl Default for CorrelationConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_millis(100),
+            threshold: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StereoCorrelation {
+    pub frames_per_window: usize,
+    pub windows: Vec<f32>,
+    pub overall: f32,
+    pub problem_ratio: f32,
+    pub problem_regions: Vec<Range<usize>>,
+}
+
+pub fn stereo_correlation(audio: &AudioData, config: &CorrelationConfig) -> Result<StereoCorrelation, BramError> {
+    let frames_per_window = (config.window.as_secs_f64() * audio.sample_rate as f64) as usize;
+    if frames_per_window == 0 {
+        return Err(BramError::InvalidParameter("correlation window must cover at least one frame"));
+    }
+    let (left, right) = stereo_channels(audio)?;
+    let windows = windowed_correlation(&left, &right, frames_per_window);
+    let mut problem_regions: Vec<Range<usize>> = Vec::new();
+    for (index, value) in windows.iter().enumerate() {
+        if *value >= config.threshold {
+            continue;
+        }
+        let frames = index * frames_per_window..((index + 1) * frames_per_window).min(left.len());
+        match problem_regions.last_mut() {
+            Some(region) if region.end == frames.start => region.end = frames.end,
+            _ => problem_regions.push(frames),
+        }
+    }
+    let problem_frames: usize = problem_regions.iter().map(|region| region.len()).sum();
+    Ok(StereoCorrelation {
+        frames_per_window,
+        overall: correlation(&left, &right),
+        problem_ratio: problem_frames as f32 / left.len().max(1) as f32,
+        problem_regions,
+        windows,
+    })
+}
+
+pub fn correlation_track(audio: &AudioData, presentation: &StereoAudioPresentation) -> Result<Vec<f32>, BramError> {
+    if presentation.samples_per_point == 0 {
+        return Err(BramError::InvalidParameter("presentation has no samples per point"));
+    }
+    let (left, right) = stereo_channels(audio)?;
+    let mut track = windowed_correlation(&left, &right, presentation.samples_per_point);
+    track.resize(presentation.left_channel_points.len(), 0.0);
+    Ok(track)
+}
+
+fn stereo_channels(audio: &AudioData) -> Result<(Vec<f32>, Vec<f32>), BramError> {
+    if audio.channels != 2 {
+        return Err(BramError::UnsupportedChannels { channels: audio.channels });
+    }
+    Ok(audio
+        .samples
+        .chunks_exact(2)
+        .map(|frame| (frame[0] as f32 / 32768.0, frame[1] as f32 / 32768.0))
+        .unzip())
+}
+
+fn windowed_correlation(left: &[f32], right: &[f32], frames_per_window: usize) -> Vec<f32> {
+    left.chunks(frames_per_window)
+        .zip(right.chunks(frames_per_window))
+        .map(|(left, right)| correlation(left, right))
+        .collect()
+}
+
+#[cfg(feature = "arrow")]
+pub fn feature_vector_arrow(audio: &AudioData, config: &FeatureConfig) -> Result<arrow_array::Float32Array, BramError> {
+    feature_vector(audio, config).map(arrow_array::Float32Array::from)
+}
+
+pub(crate) fn mono_samples(audio: &AudioData) -> Vec<f32> {
+    let channels = audio.channels.max(1) as usize;
+    audio
+        .samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().map(|s| *s as f32).sum::<f32>() / (channels as f32 * 32768.0))
+        .collect()
+}
+
+fn correlation(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let energy = a.iter().map(|x| x * x).sum::<f32>() * b.iter().map(|y| y * y).sum::<f32>();
+    if energy > 0.0 { dot / energy.sqrt() } else { 0.0 }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+}
+
+pub(crate) fn frames(samples: &[f32], frame_size: usize, hop_size: usize) -> impl Iterator<Item = &[f32]> {
+    let count = if samples.len() <= frame_size {
+        1
+    } else {
+        (samples.len() - frame_size) / hop_size + 1
+    };
+    (0..count).map(move |index| {
+        let start = index * hop_size;
+        &samples[start..(start + frame_size).min(samples.len())]
+    })
+}
+
+pub(crate) fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+pub(crate) fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+}
+
+pub(crate) fn mel_filterbank(bands: usize, frame_size: usize, sample_rate: u32) -> Vec<Vec<(usize, f32)>> {
+    let bins = frame_size / 2 + 1;
+    let nyquist = sample_rate as f32 / 2.0;
+    let max_mel = hz_to_mel(nyquist);
+    let edges: Vec<f32> = (0..bands + 2)
+        .map(|i| mel_to_hz(max_mel * i as f32 / (bands + 1) as f32) / nyquist.max(1.0) * (bins - 1) as f32)
+        .collect();
+    (0..bands)
+        .map(|band| {
+            let (left, center, right) = (edges[band], edges[band + 1], edges[band + 2]);
+            (0..bins)
+                .filter_map(|bin| {
+                    let position = bin as f32;
+                    let weight = if position <= left || position >= right {
+                        0.0
+                    } else if position <= center {
+                        (position - left) / (center - left)
+                    } else {
+                        (right - position) / (right - center)
+                    };
+                    (weight > 0.0).then_some((bin, weight))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod analysis_tests {
+    use std::time::Duration;
+    use crate::analysis::{clipped_regions, correlation_track, feature_vector, frames, interesting_region, loop_analysis, mel_filterbank, stereo_correlation, ClipConfig, CorrelationConfig, FeatureConfig, LoopConfig};
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
+    use crate::error::BramError;
+
+    fn tone(frequency: f32, sample_rate: u32, amplitude: f32) -> AudioData {
+        AudioData {
+            samples: (0..sample_rate)
+                .map(|i| (amplitude * 32767.0 * (2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32).sin()) as i16)
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn feature_vector_has_fixed_length() {
+        let config = FeatureConfig::default();
+        let long = feature_vector(&tone(440.0, 16000, 0.5), &config).unwrap();
+        let short = feature_vector(&AudioData { samples: vec![0; 10], channels: 1, sample_rate: 16000, bits_per_sample: 16, channel_mask: None }, &config).unwrap();
+        assert_eq!(long.len(), config.vector_len());
+        assert_eq!(short.len(), config.vector_len());
+    }
+
+    #[test]
+    fn feature_vector_separates_low_and_high_tones() {
+        let config = FeatureConfig::default();
+        let low = feature_vector(&tone(200.0, 16000, 0.5), &config).unwrap();
+        let high = feature_vector(&tone(5000.0, 16000, 0.5), &config).unwrap();
+        let centroid = config.bands * 2 + 3;
+        assert!(high[centroid] > low[centroid] * 4.0);
+        assert!(low[0] > high[0]);
+        let rms = config.bands * 2;
+        assert!((low[rms] - 0.3535).abs() < 0.01);
+        assert!((low[rms + 1] - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn feature_vector_rejects_bad_frame_size() {
+        let config = FeatureConfig { frame_size: 1000, ..FeatureConfig::default() };
+        let result = feature_vector(&tone(440.0, 8000, 0.5), &config);
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn interesting_region_skips_leading_silence() {
+        let mut audio = tone(440.0, 8000, 0.5);
+        audio.samples[..6000].fill(0);
+        let region = interesting_region(&audio, Duration::from_millis(200));
+        assert_eq!(region.len(), 1600);
+        assert!(region.start >= 6000);
+    }
+
+    #[test]
+    fn interesting_region_is_clamped_to_audio_length() {
+        let audio = tone(440.0, 8000, 0.5);
+        assert_eq!(interesting_region(&audio, Duration::from_secs(30)), 0..8000);
+        let empty = AudioData { samples: Vec::new(), ..audio };
+        assert_eq!(interesting_region(&empty, Duration::from_secs(1)), 0..0);
+    }
+
+    #[test]
+    fn loop_analysis_suggests_end_on_whole_period() {
+        let mut audio = tone(100.0, 8000, 0.5);
+        audio.samples.truncate(7957);
+        let analysis = loop_analysis(&audio, &LoopConfig::default()).unwrap();
+        assert!(analysis.seam_correlation < 0.9);
+        assert!(analysis.seam_level_difference_db.abs() < 0.5);
+        assert_eq!(analysis.loop_start, 0);
+        assert_eq!(analysis.loop_end % 80, 0);
+        assert!(analysis.loop_correlation > 0.999);
+    }
+
+    #[test]
+    fn loop_analysis_reports_level_mismatch_and_short_audio() {
+        let mut audio = tone(100.0, 8000, 0.5);
+        for sample in &mut audio.samples[4000..] {
+            *sample /= 10;
+        }
+        let analysis = loop_analysis(&audio, &LoopConfig::default()).unwrap();
+        assert!((analysis.seam_level_difference_db + 20.0).abs() < 0.5);
+        audio.samples.truncate(100);
+        assert!(matches!(loop_analysis(&audio, &LoopConfig::default()), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn clipped_regions_finds_full_scale_runs_per_channel() {
+        let samples = vec![
+            0, 0,
+            32767, 100,
+            32767, -32768,
+            32767, -32768,
+            0, 0,
+            -32768, -32768,
+        ];
+        let audio = AudioData { samples, channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let regions = clipped_regions(&audio, &ClipConfig { threshold: i16::MAX, min_run: 2 }).unwrap();
+        assert_eq!(regions.len(), 2);
+        assert_eq!((regions[0].channel, regions[0].frames.clone()), (0, 1..4));
+        assert_eq!((regions[0].start, regions[0].end), (Duration::from_millis(250), Duration::from_secs(1)));
+        assert_eq!((regions[1].channel, regions[1].frames.clone()), (1, 2..4));
+
+        let trailing = clipped_regions(&audio, &ClipConfig { threshold: i16::MAX, min_run: 1 }).unwrap();
+        assert!(trailing.iter().any(|region| region.channel == 1 && region.frames == (5..6)));
+        assert!(matches!(clipped_regions(&audio, &ClipConfig { threshold: 0, min_run: 1 }), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn frames_cover_signal_with_hop() {
+        let samples = vec![0.0; 10];
+        assert_eq!(frames(&samples, 4, 2).count(), 4);
+        assert_eq!(frames(&samples[..3], 4, 2).count(), 1);
+    }
+
+    #[test]
+    fn mel_filterbank_bands_are_non_empty_and_ordered() {
+        let bank = mel_filterbank(8, 512, 16000);
+        assert_eq!(bank.len(), 8);
+        assert!(bank.iter().all(|band| !band.is_empty()));
+        assert!(bank.windows(2).all(|pair| pair[0][0].0 <= pair[1][0].0));
+    }
+
+    fn stereo_tone(frames: usize, invert_from: usize) -> AudioData {
+        let samples = (0..frames)
+            .flat_map(|frame| {
+                let left = ((frame as f32 * 0.05).sin() * 12000.0) as i16;
+                let right = if frame >= invert_from { -left } else { left };
+                [left, right]
+            })
+            .collect();
+        AudioData { samples, channels: 2, sample_rate: 1000, bits_per_sample: 16, channel_mask: None }
+    }
+
+    #[test]
+    fn stereo_correlation_flags_out_of_phase_windows() {
+        let identical = stereo_correlation(&stereo_tone(2000, usize::MAX), &CorrelationConfig::default()).unwrap();
+        assert_eq!(identical.frames_per_window, 100);
+        assert_eq!(identical.windows.len(), 20);
+        assert!(identical.windows.iter().all(|value| (value - 1.0).abs() < 1e-5));
+        assert!(identical.problem_regions.is_empty());
+        assert_eq!(identical.problem_ratio, 0.0);
+
+        let half = stereo_correlation(&stereo_tone(2000, 1000), &CorrelationConfig::default()).unwrap();
+        assert!((half.windows[15] + 1.0).abs() < 1e-5);
+        assert_eq!(half.problem_regions, vec![1000..2000]);
+        assert_eq!(half.problem_ratio, 0.5);
+        assert!(half.overall.abs() < 0.1);
+    }
+
+    #[test]
+    fn correlation_track_aligns_with_presentation_points() {
+        let audio = stereo_tone(2000, 1000);
+        let presentation = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio, 10)).unwrap();
+        let track = correlation_track(&audio, &presentation).unwrap();
+        assert_eq!(track.len(), presentation.left_channel_points.len());
+        assert!(track[..10].iter().all(|value| *value > 0.99));
+        assert!(track[10..].iter().all(|value| *value < -0.99));
+    }
+
+    #[test]
+    fn stereo_correlation_rejects_invalid_input() {
+        let mono = AudioData { samples: vec![0; 100], channels: 1, sample_rate: 1000, bits_per_sample: 16, channel_mask: None };
+        assert!(matches!(stereo_correlation(&mono, &CorrelationConfig::default()), Err(BramError::UnsupportedChannels { channels: 1 })));
+        let config = CorrelationConfig { window: Duration::ZERO, ..CorrelationConfig::default() };
+        assert!(matches!(stereo_correlation(&stereo_tone(10, 0), &config), Err(BramError::InvalidParameter(_))));
+    }
+}
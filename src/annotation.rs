@@ -0,0 +1,51 @@
+use alloc::string::String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnnotationKind {
+    Synthetic,
+    Boundary,
+    Marker,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Annotation {
+    pub start_point: usize,
+    pub end_point: usize,
+    pub kind: AnnotationKind,
+    pub label: String,
+}
+
+impl Annotation {
+    pub fn synthetic(start_point: usize, end_point: usize, label: impl Into<String>) -> Self {
+        Self {
+            start_point,
+            end_point,
+            kind: AnnotationKind::Synthetic,
+            label: label.into(),
+        }
+    }
+
+    pub fn boundary(point: usize, label: impl Into<String>) -> Self {
+        Self {
+            start_point: point,
+            end_point: point,
+            kind: AnnotationKind::Boundary,
+            label: label.into(),
+        }
+    }
+
+    pub fn marker(start_point: usize, end_point: usize, label: impl Into<String>) -> Self {
+        Self {
+            start_point,
+            end_point,
+            kind: AnnotationKind::Marker,
+            label: label.into(),
+        }
+    }
+
+    pub fn contains(&self, point: usize) -> bool {
+        (self.start_point..self.end_point).contains(&point)
+    }
+}
@@ -0,0 +1,72 @@
+use std::io;
+use std::path::Path;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::wav_binary::WavBinary;
+
+pub async fn load_presentation_async(path: impl AsRef<Path>, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    let data = tokio::fs::read(path).await?;
+    let parse = crate::other_container_parser(path);
+    tokio::task::spawn_blocking(move || {
+        let audiodata = match parse {
+            Some(parse) => parse(&data)?,
+            None => AudioData::try_from(&WavBinary::from_bytes(data)?)?,
+        };
+        let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+        StereoAudioPresentation::try_from(&ratedaudiodata)
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+#[cfg(test)]
+mod async_load_tests {
+    use std::fs;
+    use std::io::ErrorKind;
+    use crate::async_load::load_presentation_async;
+    use crate::error::BramError;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn load_presentation_async_matches_sync_loader() {
+        let path = std::env::temp_dir().join("async_load_test.wav");
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&44u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x00, 0x01, 0x00]);
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&8u32.to_le_bytes());
+        data.extend_from_slice(&[0x02, 0x00, 0x10, 0x00]);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&8u32.to_le_bytes());
+        for sample in [-32768i16, 0, 32767, 0] {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        fs::write(&path, data).unwrap();
+        let path = path.to_str().unwrap();
+
+        let presentation = block_on(load_presentation_async(path, 2)).unwrap();
+        let expected = crate::load_presentation(path, 2).unwrap();
+        assert_eq!(presentation.left_channel_points, expected.left_channel_points);
+        assert_eq!(presentation.left_channel_points, vec![0.0, (32767.0 / 32768.0 + 1.0) / 2.0]);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn load_presentation_async_reports_missing_file() {
+        let result = block_on(load_presentation_async("./async_missing.wav", 2));
+        assert!(matches!(result, Err(BramError::Io(error)) if error.kind() == ErrorKind::NotFound));
+    }
+}
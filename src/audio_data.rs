@@ -1,149 +1,879 @@
-use std::io;
-use std::io::{Error, ErrorKind};
-use crate::wav_binary::WavBinary;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::io::{self, ErrorKind, Read, Seek, SeekFrom};
+use crate::adpcm;
+use crate::error::BramError;
+use crate::sample::Sample;
+#[cfg(feature = "std")]
+use crate::warning::Warning;
+use crate::wav_binary::{Chunk, Chunks, WavBinary};
+#[cfg(feature = "std")]
+use crate::wav_binary::{self, is_padding_chunk, HeaderDiagnosis};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
+pub(crate) const WAVE_FORMAT_PCM: u16 = 0x0001;
+pub(crate) const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+pub(crate) const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+#[cfg(feature = "std")]
+const READER_BLOCKS_PER_READ: usize = 4096;
+pub(crate) const KSDATAFORMAT_SUBTYPE_BASE: [u8; 12] = [
+    0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
 
 #[derive(Debug, Clone)]
-pub(crate) struct AudioData {
-    pub samples: Vec<i16>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioData<S: Sample = i16> {
+    pub samples: Vec<S>,
     pub channels: u16,
-    pub sample_rate: u32
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub channel_mask: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonFinitePolicy {
+    #[default]
+    Zero,
+    Clamp,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapDirection {
+    Backward,
+    Forward,
+    Nearest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncation {
+    pub declared_bytes: usize,
+    pub available_bytes: usize,
+    pub recovered_frames: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderInfo {
+    pub format_tag: u16,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub block_align: u16,
+    pub data_size: usize,
+    pub fact_frames: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WavFormat {
+    pub format_tag: u16,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub byte_rate: u32,
+    pub block_align: u16,
+    pub bits_per_sample: u16,
+    pub extension_size: u16,
+    pub valid_bits_per_sample: Option<u16>,
+    pub channel_mask: Option<u32>,
+    pub sub_format: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ChunkIndex<'a> {
+    pub(crate) fmt: Option<Chunk<'a>>,
+    pub(crate) data: Option<Chunk<'a>>,
+    pub(crate) fact: Option<Chunk<'a>>,
+    pub(crate) chunks: Vec<Chunk<'a>>,
+    pub(crate) trailing_garbage: Option<Range<usize>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnavailableReason {
+    UnsupportedFormat { tag: u16 },
+    UnsupportedBitDepth { bits: u16 },
+    PackedSamples { bits: u16, block_align: u16 },
+}
+
+impl Default for HeaderInfo {
+    fn default() -> Self {
+        Self {
+            format_tag: WAVE_FORMAT_PCM,
+            channels: 1,
+            sample_rate: 0,
+            bits_per_sample: 16,
+            block_align: 0,
+            data_size: 0,
+            fact_frames: None,
+        }
+    }
+}
+
+impl HeaderInfo {
+    pub fn frames(&self) -> usize {
+        match self.fact_frames {
+            Some(frames) if self.is_compressed() => frames,
+            _ if self.block_align == 0 => 0,
+            _ => self.data_size / self.block_align as usize,
+        }
+    }
+
+    pub(crate) fn is_compressed(&self) -> bool {
+        matches!(self.format_tag, adpcm::WAVE_FORMAT_ADPCM | adpcm::WAVE_FORMAT_IMA_ADPCM)
+    }
+
+    pub fn duration(&self) -> Duration {
+        if self.sample_rate == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(self.frames() as f64 / self.sample_rate as f64)
+    }
+
+    pub fn unavailable_reason(&self) -> Option<UnavailableReason> {
+        let bits = self.bits_per_sample;
+        match self.format_tag {
+            adpcm::WAVE_FORMAT_ADPCM | adpcm::WAVE_FORMAT_IMA_ADPCM => return None,
+            WAVE_FORMAT_PCM if (1..=32).contains(&bits) => {}
+            WAVE_FORMAT_IEEE_FLOAT if bits == 32 || bits == 64 => {}
+            WAVE_FORMAT_PCM | WAVE_FORMAT_IEEE_FLOAT => return Some(UnavailableReason::UnsupportedBitDepth { bits }),
+            tag => return Some(UnavailableReason::UnsupportedFormat { tag }),
+        }
+        let block_align = self.block_align;
+        if block_align != 0 && (block_align as usize) < self.channels as usize * self.sample_width() {
+            return Some(UnavailableReason::PackedSamples { bits, block_align });
+        }
+        None
+    }
+
+    fn sample_width(&self) -> usize {
+        (self.bits_per_sample as usize).div_ceil(8)
+    }
 }
 
+impl WavFormat {
+    pub fn parse(fmt: &[u8]) -> Result<Self, BramError> {
+        Self::parse_at(fmt, 0)
+    }
+
+    pub(crate) fn parse_at(fmt: &[u8], offset: usize) -> Result<Self, BramError> {
+        if fmt.len() < 16 {
+            return Err(BramError::IncompleteChunk { id: *b"fmt ", offset });
+        }
+        let extensible = AudioData::read_extensible_block(fmt);
+        Ok(WavFormat {
+            format_tag: u16::from_le_bytes([fmt[0], fmt[1]]),
+            channels: u16::from_le_bytes([fmt[2], fmt[3]]),
+            sample_rate: u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]),
+            byte_rate: u32::from_le_bytes([fmt[8], fmt[9], fmt[10], fmt[11]]),
+            block_align: u16::from_le_bytes([fmt[12], fmt[13]]),
+            bits_per_sample: u16::from_le_bytes([fmt[14], fmt[15]]),
+            extension_size: fmt.get(16..18).map_or(0, |size| u16::from_le_bytes([size[0], size[1]])),
+            valid_bits_per_sample: extensible.map(|_| u16::from_le_bytes([fmt[18], fmt[19]])),
+            channel_mask: extensible.map(|(channel_mask, _)| channel_mask),
+            sub_format: extensible.map(|(_, sub_format)| sub_format),
+        })
+    }
+
+    pub fn effective_tag(&self) -> u16 {
+        self.sub_format.unwrap_or(self.format_tag)
+    }
+
+    pub fn validate(&self) -> Result<(), BramError> {
+        if !matches!(self.effective_tag(), WAVE_FORMAT_PCM | WAVE_FORMAT_IEEE_FLOAT) {
+            return Ok(());
+        }
+        let expected = self.sample_rate as u64 * self.block_align as u64;
+        if self.byte_rate as u64 != expected {
+            return Err(BramError::InconsistentFormat { byte_rate: self.byte_rate, expected });
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ChunkIndex<'a> {
+    pub(crate) fn scan(data: &'a [u8]) -> Self {
+        let mut index = ChunkIndex::default();
+        let mut chunks = Chunks::new(data);
+        for chunk in chunks.by_ref() {
+            match &chunk.id {
+                b"fmt " => { index.fmt.get_or_insert(chunk); }
+                b"data" => { index.data.get_or_insert(chunk); }
+                b"fact" => { index.fact.get_or_insert(chunk); }
+                _ => {}
+            }
+            index.chunks.push(chunk);
+        }
+        index.trailing_garbage = chunks.trailing_garbage();
+        index
+    }
+
+    pub(crate) fn fmt_payload(&self) -> &'a [u8] {
+        self.fmt.map_or(&[], |chunk| chunk.payload)
+    }
+
+    pub(crate) fn format(&self) -> Result<WavFormat, BramError> {
+        let fmt = self.fmt.ok_or(BramError::MissingChunk { id: *b"fmt " })?;
+        WavFormat::parse_at(fmt.payload, fmt.offset - 8)
+    }
+
+    pub(crate) fn fact_frames(&self) -> Option<usize> {
+        let fact = self.fact?.payload;
+        if fact.len() < 4 {
+            return None;
+        }
+        Some(u32::from_le_bytes([fact[0], fact[1], fact[2], fact[3]]) as usize)
+    }
+
+    pub(crate) fn header_info(&self) -> Result<HeaderInfo, BramError> {
+        let format = self.format()?;
+        Ok(HeaderInfo {
+            format_tag: format.effective_tag(),
+            channels: format.channels,
+            sample_rate: format.sample_rate,
+            bits_per_sample: format.bits_per_sample,
+            block_align: format.block_align,
+            data_size: self.data.map_or(0, |chunk| chunk.declared_size),
+            fact_frames: self.fact_frames(),
+        })
+    }
+
+    fn data_bytes(&self) -> Result<&'a [u8], BramError> {
+        let data = self.data.ok_or(BramError::MissingChunk { id: *b"data" })?;
+        if data.payload.len() < data.declared_size {
+            return Err(BramError::Truncated { offset: data.offset + data.payload.len(), chunk: *b"data" });
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(offset = data.offset, size = data.declared_size, "data chunk located");
+        Ok(data.payload)
+    }
+}
+
+impl fmt::Display for UnavailableReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnavailableReason::UnsupportedFormat { tag } => write!(f, "unsupported format tag 0x{:04X}", tag),
+            UnavailableReason::UnsupportedBitDepth { bits } => write!(f, "unsupported bit depth {}", bits),
+            UnavailableReason::PackedSamples { bits, block_align } => write!(
+                f,
+                "packed {}-bit samples with block align {} are not supported",
+                bits,
+                block_align
+            ),
+        }
+    }
+}
+
+
 impl TryFrom<&WavBinary> for AudioData {
-    type Error = Error;
+    type Error = BramError;
 
     fn try_from(wav: &WavBinary) -> Result<Self, Self::Error> {
+        Self::from_wav(wav)
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) struct SampleStream<R> {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub channel_mask: Option<u32>,
+    reader: Option<io::Take<R>>,
+    header: Vec<u8>,
+    block: Vec<u8>,
+    block_align: usize,
+    is_adpcm: bool,
+    declared_size: Option<u64>,
+    data_offset: u64,
+    bytes_read: u64,
+    remaining_samples: Option<usize>,
+    buffered: Option<Vec<i16>>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> SampleStream<R> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "scan_chunks", skip_all, err))]
+    pub(crate) fn open(mut reader: R) -> Result<Self, BramError> {
+        let mut riff = [0u8; 12];
+        reader.read_exact(&mut riff)?;
+        if &riff[0..4] == b"RIFX" {
+            let mut data = riff.to_vec();
+            reader.read_to_end(&mut data)?;
+            let audio = AudioData::try_from(&WavBinary { data })?;
+            return Ok(SampleStream {
+                channels: audio.channels,
+                sample_rate: audio.sample_rate,
+                bits_per_sample: audio.bits_per_sample,
+                channel_mask: audio.channel_mask,
+                reader: None,
+                header: Vec::new(),
+                block: Vec::new(),
+                block_align: 1,
+                is_adpcm: false,
+                declared_size: None,
+                data_offset: 0,
+                bytes_read: 0,
+                remaining_samples: None,
+                buffered: Some(audio.samples),
+            });
+        }
+        let diagnosis = wav_binary::diagnose(&riff);
+        if diagnosis != HeaderDiagnosis::Valid {
+            return Err(BramError::Header(diagnosis));
+        }
+        let mut header = riff.to_vec();
+        let mut fact_frames = None;
+        let mut pos = 12u64;
+        let data_size = loop {
+            let mut chunk_header = [0u8; 8];
+            match reader.read_exact(&mut chunk_header) {
+                Ok(()) => {}
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                    return Err(BramError::MissingChunk { id: *b"data" });
+                }
+                Err(error) => return Err(error.into()),
+            }
+            let id = &chunk_header[0..4];
+            let chunk_size = u32::from_le_bytes([chunk_header[4], chunk_header[5], chunk_header[6], chunk_header[7]]);
+            pos += 8;
+            if id == b"data" {
+                break chunk_size;
+            }
+            let padded_size = chunk_size as u64 + chunk_size as u64 % 2;
+            pos += padded_size;
+            if id == b"fmt " || id == b"fact" {
+                let mut payload = Vec::new();
+                reader.by_ref().take(padded_size).read_to_end(&mut payload)?;
+                if id == b"fact" && payload.len() >= 4 {
+                    fact_frames = Some(u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]]) as usize);
+                } else if id == b"fmt " {
+                    header.extend_from_slice(&chunk_header);
+                    header.extend_from_slice(&payload);
+                }
+            } else {
+                io::copy(&mut reader.by_ref().take(padded_size), &mut io::sink())?;
+            }
+        };
+
+        let chunks = ChunkIndex::scan(&header);
+        let format = chunks.format()?;
+        let (channels, sample_rate) = (format.channels, format.sample_rate);
+        let block_align = (format.block_align as usize).max(1);
+        let info = chunks.header_info()?;
+        let declared_size = match data_size {
+            u32::MAX => None,
+            size => Some(size as u64),
+        };
+        let stream = SampleStream {
+            channels,
+            sample_rate,
+            bits_per_sample: info.bits_per_sample,
+            channel_mask: format.channel_mask,
+            reader: Some(reader.take(declared_size.unwrap_or(u64::MAX))),
+            header,
+            block: vec![0u8; block_align * READER_BLOCKS_PER_READ],
+            block_align,
+            is_adpcm: matches!(info.format_tag, adpcm::WAVE_FORMAT_ADPCM | adpcm::WAVE_FORMAT_IMA_ADPCM),
+            declared_size,
+            data_offset: pos,
+            bytes_read: 0,
+            remaining_samples: fact_frames.filter(|_| info.is_compressed()).map(|frames| frames * channels as usize),
+            buffered: None,
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(offset = stream.data_offset, size = ?stream.declared_size, "data chunk located");
+        Ok(stream)
+    }
+
+    pub(crate) fn read_all(mut self) -> Result<AudioData, BramError> {
+        let mut samples = Vec::new();
+        while let Some(block) = self.next_block()? {
+            samples.extend_from_slice(&block);
+        }
+
+        Ok(AudioData {
+            samples,
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        })
+    }
+
+    pub(crate) fn next_block(&mut self) -> Result<Option<Vec<i16>>, BramError> {
+        self.next_block_as()
+    }
+
+    pub(crate) fn next_block_as<S: Sample>(&mut self) -> Result<Option<Vec<S>>, BramError> {
+        if let Some(samples) = self.buffered.take() {
+            return Ok(Some(samples.iter().map(|sample| S::from_f64(sample.to_f64())).collect()));
+        }
+        let Some(reader) = self.reader.as_mut() else {
+            return Ok(None);
+        };
+        let mut filled = 0;
+        while filled < self.block.len() {
+            match reader.read(&mut self.block[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        self.bytes_read += filled as u64;
+        if filled < self.block.len() {
+            self.reader = None;
+            if self.declared_size.is_some_and(|size| self.bytes_read < size) {
+                return Err(BramError::Truncated { offset: (self.data_offset + self.bytes_read) as usize, chunk: *b"data" });
+            }
+        }
+        let usable = if self.is_adpcm { filled } else { filled - filled % self.block_align };
+        if usable == 0 {
+            return Ok(None);
+        }
+        let mut samples = AudioData::decode_samples(&self.header, &self.block[..usable], self.channels)?;
+        if let Some(remaining) = self.remaining_samples.as_mut() {
+            samples.truncate(*remaining);
+            *remaining -= samples.len();
+        }
+        Ok(Some(samples))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read + Seek> SampleStream<R> {
+    pub(crate) fn skip_frames(&mut self, frames: usize) -> Result<(), BramError> {
+        let samples = frames * self.channels.max(1) as usize;
+        match self.reader.as_mut() {
+            Some(reader) if !self.is_adpcm && self.buffered.is_none() => {
+                let skip = ((frames * self.block_align) as u64).min(reader.limit());
+                reader.get_mut().seek(SeekFrom::Current(skip as i64))?;
+                reader.set_limit(reader.limit() - skip);
+                self.bytes_read += skip;
+                if let Some(remaining) = self.remaining_samples.as_mut() {
+                    *remaining = remaining.saturating_sub(samples);
+                }
+            }
+            _ => {
+                let mut to_skip = samples;
+                while to_skip > 0 {
+                    let Some(mut block) = self.next_block()? else {
+                        break;
+                    };
+                    if block.len() > to_skip {
+                        block.drain(..to_skip);
+                        self.buffered = Some(block);
+                        to_skip = 0;
+                    } else {
+                        to_skip -= block.len();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn from_wav(wav: &WavBinary) -> Result<Self, BramError> {
         if !wav.check() {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "not a valid wav file"
-            ));
+            return Err(BramError::Header(wav.diagnose_header()));
+        }
+        let wav = wav.to_little_endian();
+        Self::from_wav_bytes(&wav.data)
+    }
+
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+
+    pub fn iter_frames(&self) -> core::slice::ChunksExact<'_, S> {
+        self.samples.chunks_exact(self.channels.max(1) as usize)
+    }
+
+    pub fn frames_f32(&self) -> impl Iterator<Item = Vec<f32>> + '_ {
+        self.iter_frames().map(|frame| frame.iter().map(|sample| sample.to_f64() as f32).collect())
+    }
+
+    pub fn duration(&self) -> Duration {
+        if self.sample_rate == 0 {
+            return Duration::ZERO;
         }
-        let (channels, sample_rate) = Self::read_format_info(&wav.data)?;
-        let samples = Self::extract_samples(&wav.data)?;
+        Duration::from_secs_f64(self.frames() as f64 / self.sample_rate as f64)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "decode_wav", skip_all, fields(bytes = data.len()), err))]
+    pub(crate) fn from_wav_bytes(data: &[u8]) -> Result<Self, BramError> {
+        let chunks = {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("scan_chunks").entered();
+            ChunkIndex::scan(data)
+        };
+        let format = chunks.format()?;
+        let samples = AudioData::extract_samples(&chunks)?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(channels = format.channels, sample_rate = format.sample_rate, samples = samples.len(), "samples decoded");
 
         Ok(AudioData {
             samples,
-            channels,
-            sample_rate,
+            channels: format.channels,
+            sample_rate: format.sample_rate,
+            bits_per_sample: format.bits_per_sample,
+            channel_mask: format.channel_mask,
         })
     }
+
+    pub fn find_first_above(&self, threshold_db: f64) -> Option<usize> {
+        let threshold = db_to_amplitude(threshold_db);
+        self.samples
+            .chunks_exact(self.channels.max(1) as usize)
+            .position(|frame| frame.iter().any(|sample| sample.to_f64().abs() >= threshold))
+    }
+
+    pub fn find_last_above(&self, threshold_db: f64) -> Option<usize> {
+        let threshold = db_to_amplitude(threshold_db);
+        self.samples
+            .chunks_exact(self.channels.max(1) as usize)
+            .rposition(|frame| frame.iter().any(|sample| sample.to_f64().abs() >= threshold))
+    }
+
+    pub fn auto_trim_range(&self, threshold_db: f64, pad: Duration) -> Range<usize> {
+        let (Some(first), Some(last)) = (self.find_first_above(threshold_db), self.find_last_above(threshold_db)) else {
+            return 0..0;
+        };
+        let pad_frames = (pad.as_secs_f64() * self.sample_rate as f64).round() as usize;
+        first.saturating_sub(pad_frames)..(last + 1 + pad_frames).min(self.frames())
+    }
+
+    pub fn auto_trim(&self, threshold_db: f64, pad: Duration) -> AudioData<S> {
+        let range = self.auto_trim_range(threshold_db, pad);
+        let channels = self.channels.max(1) as usize;
+        AudioData {
+            samples: self.samples[range.start * channels..range.end * channels].to_vec(),
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        }
+    }
+
+    pub fn channel(&self, index: u16) -> Option<AudioData<S>> {
+        if index >= self.channels {
+            return None;
+        }
+        Some(AudioData {
+            samples: self.samples.iter().skip(index as usize).step_by(self.channels as usize).copied().collect(),
+            channels: 1,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: None,
+        })
+    }
+
+    pub fn sanitize(&mut self, policy: NonFinitePolicy) -> Result<usize, BramError> {
+        let positions: Vec<usize> = self
+            .samples
+            .iter()
+            .enumerate()
+            .filter(|(_, sample)| !sample.to_f64().is_finite())
+            .map(|(index, _)| index)
+            .collect();
+        if policy == NonFinitePolicy::Error && !positions.is_empty() {
+            return Err(BramError::NonFiniteSamples { positions });
+        }
+        for index in &positions {
+            let value = self.samples[*index].to_f64();
+            self.samples[*index] = match policy {
+                NonFinitePolicy::Clamp if value.is_infinite() => S::from_f64(value.signum()),
+                _ => S::default(),
+            };
+        }
+        Ok(positions.len())
+    }
+
+    pub fn snap_to_zero_crossing(&self, frame: usize, direction: SnapDirection) -> usize {
+        let frames = self.frames();
+        if frame == 0 || frame >= frames {
+            return frame.min(frames);
+        }
+        let channels = self.channels.max(1) as usize;
+        let level = |index: usize| -> f64 {
+            self.samples[index * channels..(index + 1) * channels].iter().map(|sample| sample.to_f64()).sum()
+        };
+        let is_crossing = |index: usize| {
+            let (before, after) = (level(index - 1), level(index));
+            after == 0.0 || (before < 0.0) != (after < 0.0)
+        };
+        let backward = (1..=frame).rev().find(|&index| is_crossing(index));
+        let forward = (frame..frames).find(|&index| is_crossing(index));
+        match direction {
+            SnapDirection::Backward => backward.unwrap_or(0),
+            SnapDirection::Forward => forward.unwrap_or(frames),
+            SnapDirection::Nearest => match (backward, forward) {
+                (Some(backward), Some(forward)) if frame - backward <= forward - frame => backward,
+                (_, Some(forward)) => forward,
+                (backward, None) => backward.unwrap_or(frame),
+            },
+        }
+    }
+
+    pub fn convert<T: Sample>(&self) -> AudioData<T> {
+        AudioData {
+            samples: self.samples.iter().map(|sample| T::from_f64(sample.to_f64())).collect(),
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        }
+    }
 }
 
 impl AudioData {
-    fn find_data_chunk(data: &[u8]) -> Option<usize> {
-        let mut pos = 12;
-        while pos + 8 <= data.len() {
-            let chunk_id = &data[pos..pos + 4];
-            let chunk_size = u32::from_le_bytes([
-                data[pos + 4],
-                data[pos + 5],
-                data[pos + 6],
-                data[pos + 7],
-            ]) as usize;
-            if chunk_id == b"data" {
-                return Some(pos);
-            }
-            pos += 8 + chunk_size;
+    #[cfg(feature = "std")]
+    pub(crate) fn try_from_lenient(wav: &WavBinary) -> Result<(Self, Vec<Warning>), BramError> {
+        if !wav.check() {
+            return Err(BramError::Header(wav.diagnose_header()));
         }
-        None
+        let wav = wav.to_little_endian();
+        let mut warnings = Vec::new();
+        let chunks = ChunkIndex::scan(&wav.data);
+        Self::collect_skipped_chunks(&chunks, &mut warnings);
+        let format = chunks.format()?;
+        let (channels, sample_rate) = (format.channels, format.sample_rate);
+        let audio_bytes = Self::data_chunk_bytes_lenient(&chunks, &mut warnings)?;
+        let header = chunks.header_info()?;
+        let samples = match header.format_tag {
+            adpcm::WAVE_FORMAT_ADPCM | adpcm::WAVE_FORMAT_IMA_ADPCM => {
+                Self::decode_chunks(&chunks, audio_bytes, channels)?
+            }
+            _ => {
+                let frame_size = channels.max(1) as usize * header.sample_width().max(1);
+                let partial = audio_bytes.len() % frame_size;
+                if partial != 0 {
+                    warnings.push(Warning::DroppedPartialFrame { bytes: partial });
+                }
+                Self::decode_chunks(&chunks, &audio_bytes[..audio_bytes.len() - partial], channels)?
+            }
+        };
+
+        Ok((AudioData {
+            samples,
+            channels,
+            sample_rate,
+            bits_per_sample: header.bits_per_sample,
+            channel_mask: format.channel_mask,
+        }, warnings))
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn try_from_checked(wav: &WavBinary) -> Result<(Self, Vec<Warning>), BramError> {
+        let (audio, mut warnings) = Self::try_from_lenient(wav)?;
+        let wav = wav.to_little_endian();
+        Self::collect_spec_deviations(&wav.data, &ChunkIndex::scan(&wav.data), &mut warnings);
+        Ok((audio, warnings))
     }
 
-    fn read_format_info(data: &[u8]) -> io::Result<(u16, u32)> {
-        let mut pos = 12;
-        while pos + 8 < data.len() {
-            let chunk_id = &data[pos..pos + 4];
-            if chunk_id == b"fmt " {
-                let channels = u16::from_le_bytes([
-                    data[pos + 10],
-                    data[pos + 11],
-                ]);
-                let sample_rate = u32::from_le_bytes([
-                    data[pos + 12],
-                    data[pos + 13],
-                    data[pos + 14],
-                    data[pos + 15],
-                ]);
-                return Ok((channels, sample_rate));
+    #[cfg(feature = "std")]
+    pub(crate) fn try_from_truncated(wav: &WavBinary) -> Result<(Self, Option<Truncation>), BramError> {
+        let (audio, warnings) = Self::try_from_lenient(wav)?;
+        let truncation = warnings.iter().find_map(|warning| match warning {
+            Warning::ClampedChunkSize { id, declared, available } if id == b"data" && *declared != u32::MAX as usize => {
+                Some(Truncation {
+                    declared_bytes: *declared,
+                    available_bytes: *available,
+                    recovered_frames: audio.frames(),
+                })
             }
-            let chunk_size = u32::from_le_bytes([
-                data[pos + 4],
-                data[pos + 5],
-                data[pos + 6],
-                data[pos + 7],
-            ]) as usize;
+            _ => None,
+        });
+        Ok((audio, truncation))
+    }
 
-            pos += 8 + chunk_size;
+    #[cfg(feature = "std")]
+    pub(crate) fn from_reader(reader: impl Read) -> Result<Self, BramError> {
+        SampleStream::open(reader)?.read_all()
+    }
+
+    #[cfg(feature = "std")]
+    fn collect_skipped_chunks(chunks: &ChunkIndex, warnings: &mut Vec<Warning>) {
+        let mut seen_fmt = false;
+        let mut seen_data = false;
+        for chunk in &chunks.chunks {
+            let duplicate = match &chunk.id {
+                b"fmt " => core::mem::replace(&mut seen_fmt, true),
+                b"data" => core::mem::replace(&mut seen_data, true),
+                _ => false,
+            };
+            let overruns = &chunk.id != b"data" && chunk.payload.len() < chunk.declared_size;
+            if duplicate || overruns {
+                warnings.push(Warning::SkippedChunk { id: chunk.id, offset: chunk.offset - 8 });
+            } else if is_padding_chunk(&chunk.id) {
+                warnings.push(Warning::SkippedPadding { id: chunk.id, offset: chunk.offset - 8, size: chunk.declared_size });
+            }
+        }
+        if let Some(garbage) = &chunks.trailing_garbage {
+            warnings.push(Warning::TrailingGarbage { offset: garbage.start, bytes: garbage.len() });
         }
-        Err(Error::new(
-            ErrorKind::InvalidData,
-            "no chunk format found"
-        ))
     }
 
-    fn extract_samples(data: &[u8]) -> io::Result<Vec<i16>> {
-        let data_pos = Self::find_data_chunk(data)
-            .ok_or_else(|| Error::new(
-                ErrorKind::InvalidData,
-                "no data chunk found"
-            ))?;
-        if data_pos + 8 > data.len() {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "incomplete data chunk"
-            ));
+    #[cfg(feature = "std")]
+    fn collect_spec_deviations(data: &[u8], chunks: &ChunkIndex, warnings: &mut Vec<Warning>) {
+        let declared = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let actual = chunks.trailing_garbage.as_ref().map_or(data.len(), |garbage| garbage.start) - 8;
+        if declared != actual {
+            warnings.push(Warning::RiffSizeMismatch { declared, actual });
+        }
+        if let (Some(fmt), Some(audio)) = (chunks.fmt, chunks.data)
+            && audio.offset < fmt.offset
+        {
+            warnings.push(Warning::UnexpectedChunkOrder { id: audio.id, offset: audio.offset - 8 });
+        }
+        let Ok(format) = chunks.format() else {
+            return;
+        };
+        if !matches!(format.effective_tag(), WAVE_FORMAT_PCM | WAVE_FORMAT_IEEE_FLOAT) {
+            return;
+        }
+        let expected = format.channels.saturating_mul(format.bits_per_sample.div_ceil(8));
+        if format.block_align != expected {
+            warnings.push(Warning::BlockAlignMismatch { block_align: format.block_align, expected });
         }
+        let expected = format.sample_rate.saturating_mul(format.block_align as u32);
+        if format.byte_rate != expected {
+            warnings.push(Warning::ByteRateMismatch { byte_rate: format.byte_rate, expected });
+        }
+    }
 
-        let data_size = u32::from_le_bytes([
-            data[data_pos + 4],
-            data[data_pos + 5],
-            data[data_pos + 6],
-            data[data_pos + 7],
-        ]) as usize;
+    #[cfg(feature = "std")]
+    fn data_chunk_bytes_lenient<'a>(chunks: &ChunkIndex<'a>, warnings: &mut Vec<Warning>) -> Result<&'a [u8], BramError> {
+        let data = chunks.data.ok_or(BramError::MissingChunk { id: *b"data" })?;
+        let available = data.payload.len();
+        if data.declared_size > available {
+            warnings.push(Warning::ClampedChunkSize { id: *b"data", declared: data.declared_size, available });
+        }
+        Ok(data.payload)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn read_header_info(data: &[u8]) -> Result<HeaderInfo, BramError> {
+        ChunkIndex::scan(data).header_info()
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn decode_samples<S: Sample>(data: &[u8], audio_bytes: &[u8], channels: u16) -> Result<Vec<S>, BramError> {
+        Self::decode_chunks(&ChunkIndex::scan(data), audio_bytes, channels)
+    }
 
-        let audio_start = data_pos + 8;
-        let audio_end = audio_start + data_size;
+    fn decode_chunks<S: Sample>(chunks: &ChunkIndex, audio_bytes: &[u8], channels: u16) -> Result<Vec<S>, BramError> {
+        let mut samples = Vec::new();
+        Self::decode_chunks_into(chunks, audio_bytes, channels, &mut samples)?;
+        Ok(samples)
+    }
 
-        if audio_end > data.len() {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "Données audio incomplètes"
-            ));
+    fn decode_chunks_into<S: Sample>(chunks: &ChunkIndex, audio_bytes: &[u8], channels: u16, samples: &mut Vec<S>) -> Result<(), BramError> {
+        let header = chunks.header_info().unwrap_or_default();
+        let block_align = header.block_align as usize;
+        match header.format_tag {
+            adpcm::WAVE_FORMAT_ADPCM => {
+                let coefficients = adpcm::read_ms_coefficients(chunks.fmt_payload());
+                let decoded = adpcm::decode_ms(audio_bytes, channels, block_align, &coefficients, header.fact_frames)?;
+                samples.extend(Self::widen_samples::<S>(decoded));
+            }
+            adpcm::WAVE_FORMAT_IMA_ADPCM => {
+                let decoded = adpcm::decode_ima(audio_bytes, channels, block_align, header.fact_frames)?;
+                samples.extend(Self::widen_samples::<S>(decoded));
+            }
+            _ => {
+                if header.unavailable_reason().is_some() {
+                    return Err(BramError::UnsupportedFormat { tag: header.format_tag, bits: header.bits_per_sample });
+                }
+                Self::extend_samples(samples, audio_bytes, header.format_tag, header.bits_per_sample)?;
+            }
         }
+        Ok(())
+    }
 
-        let audio_bytes = &data[audio_start..audio_end];
-        Self::bytes_to_i16_samples(audio_bytes)
+    fn widen_samples<S: Sample>(samples: Vec<i16>) -> impl Iterator<Item = S> {
+        samples.into_iter().map(|sample| S::from_i32((sample as i32) << 16))
     }
-    
-    fn bytes_to_i16_samples(bytes: &[u8]) -> io::Result<Vec<i16>> {
 
-        if bytes.len() % 2 != 0 {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "invalid binary data"
-            ));
+    #[cfg(test)]
+    fn bytes_to_samples<S: Sample>(bytes: &[u8], format_tag: u16, bits_per_sample: u16) -> Result<Vec<S>, BramError> {
+        let mut samples = Vec::new();
+        Self::extend_samples(&mut samples, bytes, format_tag, bits_per_sample)?;
+        Ok(samples)
+    }
+
+    fn extend_samples<S: Sample>(samples: &mut Vec<S>, bytes: &[u8], format_tag: u16, bits_per_sample: u16) -> Result<(), BramError> {
+        let width = (bits_per_sample as usize).div_ceil(8);
+        if width == 0 || !bytes.len().is_multiple_of(width) {
+            return Err(BramError::Decode("odd number of sample bytes".to_string()));
         }
 
-        let num_samples = bytes.len() / 2;
-        let mut samples = Vec::with_capacity(num_samples);
+        let chunks = bytes.chunks_exact(width);
+        samples.reserve(chunks.len());
+        match (format_tag, width) {
+            (WAVE_FORMAT_IEEE_FLOAT, 4) => samples.extend(chunks.map(|b| S::from_f64(f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64))),
+            (WAVE_FORMAT_IEEE_FLOAT, _) => {
+                samples.extend(chunks.map(|b| S::from_f64(f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))))
+            }
+            (_, 1) => samples.extend(chunks.map(|b| S::from_i32((b[0] as i32 - 128) << 24))),
+            (_, 2) => samples.extend(chunks.map(|b| S::from_i32((i16::from_le_bytes([b[0], b[1]]) as i32) << 16))),
+            (_, 3) => samples.extend(chunks.map(|b| S::from_i32(i32::from_le_bytes([0, b[0], b[1], b[2]])))),
+            _ => samples.extend(chunks.map(|b| S::from_i32(i32::from_le_bytes([b[0], b[1], b[2], b[3]])))),
+        }
+        Ok(())
+    }
 
-        for i in 0..num_samples {
-            let byte_index = i * 2;
-            let sample = i16::from_le_bytes([
-                bytes[byte_index],
-                bytes[byte_index + 1],
-            ]);
-            samples.push(sample);
+    fn read_extensible_block(fmt: &[u8]) -> Option<(u32, u16)> {
+        let tag = u16::from_le_bytes([fmt[0], fmt[1]]);
+        if tag != WAVE_FORMAT_EXTENSIBLE || fmt.len() < 40 || fmt[28..40] != KSDATAFORMAT_SUBTYPE_BASE {
+            return None;
         }
+        let channel_mask = u32::from_le_bytes([fmt[20], fmt[21], fmt[22], fmt[23]]);
+        let sub_format = u16::from_le_bytes([fmt[24], fmt[25]]);
+        Some((channel_mask, sub_format))
+    }
 
-        Ok(samples)
+    #[cfg(feature = "std")]
+    pub(crate) fn decode_wav_bytes_into<S: Sample>(data: &[u8], samples: &mut Vec<S>) -> Result<WavFormat, BramError> {
+        let chunks = ChunkIndex::scan(data);
+        let format = chunks.format()?;
+        Self::decode_chunks_into(&chunks, chunks.data_bytes()?, format.channels, samples)?;
+        Ok(format)
+    }
+
+    pub(crate) fn read_wav_format(data: &[u8]) -> Result<WavFormat, BramError> {
+        ChunkIndex::scan(data).format()
+    }
+
+    fn extract_samples<S: Sample>(chunks: &ChunkIndex) -> Result<Vec<S>, BramError> {
+        let channels = chunks.format().map_or(1, |format| format.channels);
+        let audio_bytes = chunks.data_bytes()?;
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("decode_samples", bytes = audio_bytes.len(), channels).entered();
+        Self::decode_chunks(chunks, audio_bytes, channels)
     }
 }
 
+fn db_to_amplitude(db: f64) -> f64 {
+    10f64.powf(db / 20.0)
+}
+
 #[cfg(test)]
 mod audio_data_tests {
-    use std::io::ErrorKind;
-    use crate::audio_data::AudioData;
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+    use std::time::Duration;
+    use crate::audio_data::{AudioData, ChunkIndex, NonFinitePolicy, SnapDirection};
+    #[cfg(feature = "std")]
+    use crate::audio_data::{SampleStream, Truncation, UnavailableReason};
+    use crate::error::BramError;
+    #[cfg(feature = "std")]
+    use crate::warning::Warning;
     use crate::wav_binary::WavBinary;
 
     #[test]
@@ -216,9 +946,9 @@ mod audio_data_tests {
             0x04, 0x00, 0x00, 0x00,
             0x00, 0x00, 0xFF, 0x7F,
         ];
-        let result = AudioData::find_data_chunk(&wav_data);
+        let result = ChunkIndex::scan(&wav_data).data;
         assert!(result.is_some());
-        assert_eq!(result.unwrap(), 36);
+        assert_eq!(result.unwrap().offset, 44);
     }
 
     #[test]
@@ -232,12 +962,12 @@ mod audio_data_tests {
             0x01, 0x00,
             0x02, 0x00,
         ];
-        let result = AudioData::find_data_chunk(&wav_data);
+        let result = ChunkIndex::scan(&wav_data).data;
         assert!(result.is_none());
     }
 
     #[test]
-    fn read_format_info_works_if_valid() {
+    fn read_wav_format_works_if_valid() {
         let wav_data = vec![
             b'R', b'I', b'F', b'F',
             0x24, 0x00, 0x00, 0x00,
@@ -251,15 +981,15 @@ mod audio_data_tests {
             0x04, 0x00,
             0x10, 0x00,
         ];
-        let result = AudioData::read_format_info(&wav_data);
+        let result = AudioData::read_wav_format(&wav_data);
         assert!(result.is_ok());
-        let (channels, sample_rate) = result.unwrap();
-        assert_eq!(channels, 2);
-        assert_eq!(sample_rate, 44100);
+        let format = result.unwrap();
+        assert_eq!(format.channels, 2);
+        assert_eq!(format.sample_rate, 44100);
     }
 
     #[test]
-    fn read_format_info_returns_error_if_invalid() {
+    fn read_wav_format_returns_error_if_invalid() {
         let wav_data = vec![
             b'R', b'I', b'F', b'F',
             0x24, 0x00, 0x00, 0x00,
@@ -267,9 +997,9 @@ mod audio_data_tests {
             b'd', b'a', b't', b'a',
             0x04, 0x00, 0x00, 0x00,
         ];
-        let result = AudioData::read_format_info(&wav_data);
+        let result = AudioData::read_wav_format(&wav_data);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(matches!(result, Err(BramError::MissingChunk { id }) if &id == b"fmt "));
     }
 
     #[test]
@@ -285,7 +1015,7 @@ mod audio_data_tests {
             0x00, 0x80,
             0x01, 0x00,
         ];
-        let result = AudioData::extract_samples(&wav_data);
+        let result = AudioData::extract_samples::<i16>(&ChunkIndex::scan(&wav_data));
         assert!(result.is_ok());
         let samples = result.unwrap();
         assert_eq!(samples.len(), 4);
@@ -302,21 +1032,21 @@ mod audio_data_tests {
             0x24, 0x00, 0x00, 0x00,
             b'W', b'A', b'V', b'E',
         ];
-        let result = AudioData::extract_samples(&wav_data);
+        let result = AudioData::extract_samples::<i16>(&ChunkIndex::scan(&wav_data));
         assert!(result.is_err());
     }
 
     #[test]
     fn bytes_to_i16_samples_return_error_if_invalid() {
         let bytes = vec![0x00, 0x00, 0xFF];
-        let result = AudioData::bytes_to_i16_samples(&bytes);
+        let result = AudioData::bytes_to_samples::<i16>(&bytes, 1, 16);
         assert!(result.is_err());
     }
 
     #[test]
     fn bytes_to_i16_samples_return_expected_values() {
         let bytes = vec![0x00, 0x00, 0xFF, 0x7F, 0x00, 0x80, 0x01, 0x00];
-        let result = AudioData::bytes_to_i16_samples(&bytes);
+        let result = AudioData::bytes_to_samples::<i16>(&bytes, 1, 16);
         assert!(result.is_ok());
         let samples = result.unwrap();
         assert_eq!(samples.len(), 4);
@@ -325,4 +1055,688 @@ mod audio_data_tests {
         assert_eq!(samples[2], -32768);
         assert_eq!(samples[3], 1);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lenient_parse_matches_strict_parse_on_valid_file() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x24, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x10, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            0x02, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x10, 0xB1, 0x02, 0x00,
+            0x04, 0x00,
+            0x10, 0x00,
+            b'd', b'a', b't', b'a',
+            0x08, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xFF, 0x7F,
+            0x00, 0x80, 0x01, 0x00,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let (audio, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(audio.samples, vec![0, 32767, -32768, 1]);
+        assert!(warnings.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lenient_parse_clamps_oversized_data_chunk() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x24, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x10, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            0x02, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x10, 0xB1, 0x02, 0x00,
+            0x04, 0x00,
+            0x10, 0x00,
+            b'd', b'a', b't', b'a',
+            0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0xFF, 0x7F,
+            0x00, 0x80, 0x01, 0x00,
+        ];
+        let wav = WavBinary { data: wav_data.clone() };
+        assert!(AudioData::try_from(&wav).is_err());
+        let (audio, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(audio.samples.len(), 4);
+        assert_eq!(warnings, vec![Warning::ClampedChunkSize { id: *b"data", declared: 4096, available: 8 }]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn truncated_parse_recovers_complete_frames_and_reports_truncation() {
+        let mut data = pcm_wav(1, 2, 16, 4, &[0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, 0x00]);
+        data[40..44].copy_from_slice(&4000u32.to_le_bytes());
+        let (audio, truncation) = AudioData::try_from_truncated(&WavBinary { data }).unwrap();
+        assert_eq!(audio.samples, vec![1, 2, 3, 4]);
+        assert_eq!(truncation, Some(Truncation { declared_bytes: 4000, available_bytes: 10, recovered_frames: 2 }));
+
+        let intact = WavBinary { data: pcm_wav(1, 1, 16, 2, &[0x01, 0x00]) };
+        assert_eq!(AudioData::try_from_truncated(&intact).unwrap().1, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lenient_parse_drops_partial_frame() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x24, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x10, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            0x02, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x10, 0xB1, 0x02, 0x00,
+            0x04, 0x00,
+            0x10, 0x00,
+            b'd', b'a', b't', b'a',
+            0x07, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0xFF, 0x7F,
+            0x00, 0x80, 0x01,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let (audio, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(audio.samples, vec![0, 32767]);
+        assert_eq!(warnings, vec![Warning::DroppedPartialFrame { bytes: 3 }]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lenient_parse_reports_duplicate_chunks() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x24, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x10, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            0x01, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x88, 0x58, 0x01, 0x00,
+            0x02, 0x00,
+            0x10, 0x00,
+            b'd', b'a', b't', b'a',
+            0x02, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            b'd', b'a', b't', b'a',
+            0x02, 0x00, 0x00, 0x00,
+            0x02, 0x00,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let (audio, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(audio.samples, vec![1]);
+        assert_eq!(warnings, vec![Warning::SkippedChunk { id: *b"data", offset: 46 }]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lenient_parse_reports_padding_and_trailing_garbage() {
+        let mut wav_data = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+        wav_data.extend_from_slice(b"JUNK\x1c\x00\x00\x00");
+        wav_data.extend_from_slice(&[0; 28]);
+        wav_data.extend_from_slice(b"fmt \x10\x00\x00\x00\x01\x00\x01\x00\x44\xAC\x00\x00\x88\x58\x01\x00\x02\x00\x10\x00");
+        wav_data.extend_from_slice(b"FLLR\x04\x00\x00\x00\x00\x00\x00\x00");
+        wav_data.extend_from_slice(b"data\x04\x00\x00\x00\x01\x00\x02\x00");
+        wav_data.extend_from_slice(&[0xFF, 0xFE, 0x00, 0x13, 0x37, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        let wav = WavBinary { data: wav_data };
+        let (audio, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(audio.samples, vec![1, 2]);
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::SkippedPadding { id: *b"JUNK", offset: 12, size: 28 },
+                Warning::SkippedPadding { id: *b"FLLR", offset: 72, size: 4 },
+                Warning::TrailingGarbage { offset: 96, bytes: 10 },
+            ]
+        );
+        assert_eq!(AudioData::try_from(&wav).unwrap().samples, vec![1, 2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn checked_parse_reports_spec_deviations() {
+        let mut wav_data = b"RIFF\x30\x00\x00\x00WAVE".to_vec();
+        wav_data.extend_from_slice(b"data\x04\x00\x00\x00\x01\x00\x02\x00");
+        wav_data.extend_from_slice(b"fmt \x10\x00\x00\x00\x01\x00\x01\x00\x44\xAC\x00\x00\x44\xAC\x00\x00\x03\x00\x10\x00");
+        let wav = WavBinary { data: wav_data };
+        let (audio, warnings) = AudioData::try_from_checked(&wav).unwrap();
+        assert_eq!(audio.samples, vec![1, 2]);
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::RiffSizeMismatch { declared: 48, actual: 40 },
+                Warning::UnexpectedChunkOrder { id: *b"data", offset: 12 },
+                Warning::BlockAlignMismatch { block_align: 3, expected: 2 },
+                Warning::ByteRateMismatch { byte_rate: 44100, expected: 132300 },
+            ]
+        );
+        assert!(AudioData::try_from_lenient(&wav).unwrap().1.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn checked_parse_accepts_consistent_file() {
+        let data = pcm_wav(1, 2, 16, 4, &[0x00, 0x00, 0xFF, 0x7F]);
+        let (_, warnings) = AudioData::try_from_checked(&WavBinary { data }).unwrap();
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn create_audio_data_from_ima_adpcm_wavbinary() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x38, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x14, 0x00, 0x00, 0x00,
+            0x11, 0x00,
+            0x01, 0x00,
+            0x40, 0x1F, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00,
+            0x08, 0x00,
+            0x04, 0x00,
+            0x02, 0x00,
+            0x09, 0x00,
+            b'f', b'a', b'c', b't',
+            0x04, 0x00, 0x00, 0x00,
+            0x07, 0x00, 0x00, 0x00,
+            b'd', b'a', b't', b'a',
+            0x08, 0x00, 0x00, 0x00,
+            0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x77, 0x77,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.channels, 1);
+        assert_eq!(audio.sample_rate, 8000);
+        assert_eq!(audio.samples.len(), 7);
+        assert_eq!(audio.samples[0], 16);
+    }
+
+    #[test]
+    fn create_audio_data_from_ms_adpcm_wavbinary() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x30, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x14, 0x00, 0x00, 0x00,
+            0x02, 0x00,
+            0x01, 0x00,
+            0x40, 0x1F, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00,
+            0x09, 0x00,
+            0x04, 0x00,
+            0x02, 0x00,
+            0x06, 0x00,
+            b'd', b'a', b't', b'a',
+            0x09, 0x00, 0x00, 0x00,
+            0x00,
+            0x10, 0x00,
+            0x64, 0x00,
+            0x32, 0x00,
+            0x00, 0x00,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![50, 100, 100, 100, 100, 100]);
+    }
+
+    #[test]
+    fn create_audio_data_from_extensible_wavbinary() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x3C, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x28, 0x00, 0x00, 0x00,
+            0xFE, 0xFF,
+            0x02, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x10, 0xB1, 0x02, 0x00,
+            0x04, 0x00,
+            0x10, 0x00,
+            0x16, 0x00,
+            0x10, 0x00,
+            0x03, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+            b'd', b'a', b't', b'a',
+            0x04, 0x00, 0x00, 0x00,
+            0xFF, 0x7F, 0x00, 0x80,
+        ];
+        let wav = WavBinary { data: wav_data };
+        assert_eq!(AudioData::read_wav_format(&wav.data).unwrap().effective_tag(), 1);
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.channel_mask, Some(0x3));
+        assert_eq!(audio.samples, vec![32767, -32768]);
+
+        let format = wav.format().unwrap();
+        assert_eq!((format.format_tag, format.effective_tag()), (0xFFFE, 1));
+        assert_eq!((format.byte_rate, format.block_align, format.extension_size), (176400, 4, 22));
+        assert_eq!((format.valid_bits_per_sample, format.channel_mask), (Some(16), Some(0x3)));
+        assert!(format.validate().is_ok());
+    }
+
+    #[test]
+    fn wav_format_validation_checks_byte_rate() {
+        let mut data = pcm_wav(1, 2, 16, 4, &[0; 8]);
+        let format = AudioData::read_wav_format(&data).unwrap();
+        assert_eq!((format.channels, format.sample_rate, format.byte_rate), (2, 8000, 32000));
+        assert_eq!((format.extension_size, format.sub_format), (0, None));
+        assert!(format.validate().is_ok());
+
+        data[28..32].copy_from_slice(&16000u32.to_le_bytes());
+        let result = AudioData::read_wav_format(&data).unwrap().validate();
+        assert!(matches!(result, Err(BramError::InconsistentFormat { byte_rate: 16000, expected: 32000 })));
+
+        let mut adpcm = pcm_wav(0x11, 1, 4, 256, &[]);
+        adpcm[28..32].copy_from_slice(&4055u32.to_le_bytes());
+        assert!(AudioData::read_wav_format(&adpcm).unwrap().validate().is_ok());
+    }
+
+    #[test]
+    fn wav_format_requires_full_fmt_chunk() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[]);
+        data[16..20].copy_from_slice(&12u32.to_le_bytes());
+        data.drain(32..36);
+        assert!(matches!(AudioData::read_wav_format(&data), Err(BramError::IncompleteChunk { .. })));
+    }
+
+    #[test]
+    fn extensible_with_unknown_subformat_guid_stays_opaque() {
+        let mut fmt = vec![0xFE, 0xFF];
+        fmt.extend_from_slice(&[0u8; 38]);
+        fmt[24] = 0x01;
+        assert_eq!(AudioData::read_extensible_block(&fmt), None);
+    }
+
+    #[test]
+    fn audio_data_without_extensible_has_no_channel_mask() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F',
+            0x24, 0x00, 0x00, 0x00,
+            b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ',
+            0x10, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+            0x01, 0x00,
+            0x44, 0xAC, 0x00, 0x00,
+            0x88, 0x58, 0x01, 0x00,
+            0x02, 0x00,
+            0x10, 0x00,
+            b'd', b'a', b't', b'a',
+            0x02, 0x00, 0x00, 0x00,
+            0x01, 0x00,
+        ];
+        let wav = WavBinary { data: wav_data };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.channel_mask, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sample_stream_skip_frames_seeks_into_data() {
+        let audio: Vec<u8> = (0..8i16).flat_map(|sample| (sample * 100).to_le_bytes()).collect();
+        let mut stream = SampleStream::open(Cursor::new(pcm_wav(1, 2, 16, 4, &audio))).unwrap();
+        stream.skip_frames(2).unwrap();
+        assert_eq!(stream.next_block().unwrap(), Some(vec![400, 500, 600, 700]));
+        assert_eq!(stream.next_block().unwrap(), None);
+
+        let mut stream = SampleStream::open(Cursor::new(pcm_wav(1, 2, 16, 4, &audio))).unwrap();
+        stream.skip_frames(10).unwrap();
+        assert_eq!(stream.next_block().unwrap(), None);
+    }
+
+    fn pcm_wav(format_tag: u16, channels: u16, bits: u16, block_align: u16, audio: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&(36 + audio.len() as u32).to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&format_tag.to_le_bytes());
+        data.extend_from_slice(&channels.to_le_bytes());
+        data.extend_from_slice(&8000u32.to_le_bytes());
+        data.extend_from_slice(&(8000 * block_align as u32).to_le_bytes());
+        data.extend_from_slice(&block_align.to_le_bytes());
+        data.extend_from_slice(&bits.to_le_bytes());
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&(audio.len() as u32).to_le_bytes());
+        data.extend_from_slice(audio);
+        data
+    }
+
+    #[test]
+    fn decode_8_bit_unsigned_pcm() {
+        let wav = WavBinary { data: pcm_wav(1, 1, 8, 1, &[0x80, 0xFF, 0x00]) };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![0, 32512, -32768]);
+    }
+
+    #[test]
+    fn decode_24_bit_pcm_keeps_most_significant_bits() {
+        let wav = WavBinary { data: pcm_wav(1, 1, 24, 3, &[0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80]) };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![32767, -32768]);
+    }
+
+    #[test]
+    fn decode_32_bit_float() {
+        let mut audio_bytes = Vec::new();
+        for value in [0.0f32, 1.0, -1.0, 2.0] {
+            audio_bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        let wav = WavBinary { data: pcm_wav(3, 1, 32, 4, &audio_bytes) };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![0, 32767, -32768, 32767]);
+    }
+
+    #[test]
+    fn decode_24_bit_pcm_at_full_precision() {
+        let wav = WavBinary { data: pcm_wav(1, 1, 24, 3, &[0x01, 0x00, 0x00, 0x00, 0x00, 0x80]) };
+        let wide = AudioData::<i32>::from_wav(&wav).unwrap();
+        assert_eq!(wide.samples, vec![0x100, i32::MIN]);
+        let float = AudioData::<f32>::from_wav(&wav).unwrap();
+        assert_eq!(float.samples, vec![1.0 / 8388608.0, -1.0]);
+        assert_eq!(AudioData::try_from(&wav).unwrap().samples, vec![0, -32768]);
+    }
+
+    #[test]
+    fn convert_between_sample_types() {
+        let audio = AudioData {
+            samples: vec![0i16, 16384, -32768],
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let float = audio.convert::<f32>();
+        assert_eq!(float.samples, vec![0.0, 0.5, -1.0]);
+        assert_eq!(float.sample_rate, 8000);
+        assert_eq!(float.convert::<i32>().samples, vec![0, 1 << 30, i32::MIN]);
+        assert_eq!(float.convert::<i16>().samples, audio.samples);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn packed_20_bit_is_reported_unavailable() {
+        let wav = WavBinary { data: pcm_wav(1, 2, 20, 5, &[0; 10]) };
+        let header = AudioData::read_header_info(&wav.data).unwrap();
+        assert_eq!(header.unavailable_reason(), Some(UnavailableReason::PackedSamples { bits: 20, block_align: 5 }));
+        assert_eq!(header.duration(), std::time::Duration::from_secs_f64(2.0 / 8000.0));
+        assert!(AudioData::try_from(&wav).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unknown_format_tag_is_reported_unavailable() {
+        let wav = WavBinary { data: pcm_wav(6, 1, 8, 1, &[0; 4]) };
+        let header = AudioData::read_header_info(&wav.data).unwrap();
+        assert_eq!(header.unavailable_reason(), Some(UnavailableReason::UnsupportedFormat { tag: 6 }));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn float_with_16_bits_is_reported_unavailable() {
+        let wav = WavBinary { data: pcm_wav(3, 1, 16, 2, &[0; 4]) };
+        let header = AudioData::read_header_info(&wav.data).unwrap();
+        assert_eq!(header.unavailable_reason(), Some(UnavailableReason::UnsupportedBitDepth { bits: 16 }));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decoded_audio_reports_source_bit_depth_and_duration() {
+        let wav = WavBinary { data: pcm_wav(1, 2, 24, 6, &[0; 6 * 4000]) };
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.bits_per_sample, 24);
+        assert_eq!(audio.frames(), 4000);
+        assert_eq!(audio.duration(), std::time::Duration::from_millis(500));
+        let streamed = AudioData::from_reader(Cursor::new(wav.data)).unwrap();
+        assert_eq!(streamed.bits_per_sample, 24);
+    }
+
+    #[test]
+    fn find_first_and_last_above_threshold() {
+        let audio = AudioData {
+            samples: vec![0i16, 0, 10, -10, 0, 8000, 0, 0, -9000, 0, 20, 0],
+            channels: 2,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        assert_eq!(audio.find_first_above(-20.0), Some(2));
+        assert_eq!(audio.find_last_above(-20.0), Some(4));
+        assert_eq!(audio.find_first_above(-80.0), Some(1));
+        assert_eq!(audio.find_last_above(-80.0), Some(5));
+        assert_eq!(audio.find_first_above(0.0), None);
+        assert_eq!(audio.convert::<f32>().find_first_above(-20.0), Some(2));
+    }
+
+    #[test]
+    fn auto_trim_keeps_padding_around_signal() {
+        let mut samples = vec![0i16; 20];
+        samples[8] = 10000;
+        samples[11] = -10000;
+        let audio = AudioData {
+            samples,
+            channels: 1,
+            sample_rate: 1000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        assert_eq!(audio.auto_trim_range(-20.0, Duration::ZERO), 8..12);
+        assert_eq!(audio.auto_trim_range(-20.0, Duration::from_millis(3)), 5..15);
+        assert_eq!(audio.auto_trim_range(-20.0, Duration::from_millis(50)), 0..20);
+        let trimmed = audio.auto_trim(-20.0, Duration::from_millis(1));
+        assert_eq!(trimmed.samples, vec![0, 10000, 0, 0, -10000, 0]);
+        assert_eq!(trimmed.sample_rate, 1000);
+        assert!(audio.auto_trim(0.0, Duration::from_millis(3)).samples.is_empty());
+    }
+
+    #[test]
+    fn iter_frames_yields_interleaved_frames() {
+        let audio = AudioData {
+            samples: vec![1i16, 2, 3, 4, 5, 6, 7],
+            channels: 2,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let frames: Vec<&[i16]> = audio.iter_frames().collect();
+        assert_eq!(frames, vec![&[1, 2][..], &[3, 4], &[5, 6]]);
+        assert_eq!(audio.iter_frames().len(), audio.frames());
+        let first = audio.frames_f32().next().unwrap();
+        assert_eq!(first, vec![1.0 / 32768.0, 2.0 / 32768.0]);
+    }
+
+    #[test]
+    fn channel_extracts_single_track() {
+        let audio = AudioData {
+            samples: vec![1i16, 2, 3, 4, 5, 6],
+            channels: 3,
+            sample_rate: 8000,
+            bits_per_sample: 24,
+            channel_mask: Some(0x7),
+        };
+        let second = audio.channel(1).unwrap();
+        assert_eq!(second.samples, vec![2, 5]);
+        assert_eq!(second.channels, 1);
+        assert_eq!(second.bits_per_sample, 24);
+        assert_eq!(second.channel_mask, None);
+        assert!(audio.channel(3).is_none());
+    }
+
+    #[test]
+    fn sanitize_replaces_or_reports_non_finite_samples() {
+        let audio = AudioData {
+            samples: vec![0.5f32, f32::NAN, f32::INFINITY, -0.25, f32::NEG_INFINITY],
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 32,
+            channel_mask: None,
+        };
+        let mut zeroed = audio.clone();
+        assert_eq!(zeroed.sanitize(NonFinitePolicy::Zero).unwrap(), 3);
+        assert_eq!(zeroed.samples, vec![0.5, 0.0, 0.0, -0.25, 0.0]);
+        let mut clamped = audio.clone();
+        clamped.sanitize(NonFinitePolicy::Clamp).unwrap();
+        assert_eq!(clamped.samples, vec![0.5, 0.0, 1.0, -0.25, -1.0]);
+        let mut strict = audio.clone();
+        assert!(matches!(
+            strict.sanitize(NonFinitePolicy::Error),
+            Err(BramError::NonFiniteSamples { positions }) if positions == vec![1, 2, 4]
+        ));
+        assert_eq!(zeroed.sanitize(NonFinitePolicy::Error).unwrap(), 0);
+    }
+
+    #[test]
+    fn snap_to_zero_crossing_moves_to_sign_change() {
+        let audio = AudioData {
+            samples: vec![5i16, 3, 2, 1, -1, -4, -6, -2, 0, 4, 7, 9],
+            channels: 1,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        assert_eq!(audio.snap_to_zero_crossing(6, SnapDirection::Backward), 4);
+        assert_eq!(audio.snap_to_zero_crossing(6, SnapDirection::Forward), 8);
+        assert_eq!(audio.snap_to_zero_crossing(5, SnapDirection::Nearest), 4);
+        assert_eq!(audio.snap_to_zero_crossing(7, SnapDirection::Nearest), 8);
+        assert_eq!(audio.snap_to_zero_crossing(4, SnapDirection::Forward), 4);
+        assert_eq!(audio.snap_to_zero_crossing(10, SnapDirection::Forward), 12);
+        assert_eq!(audio.snap_to_zero_crossing(2, SnapDirection::Backward), 0);
+        assert_eq!(audio.snap_to_zero_crossing(99, SnapDirection::Nearest), 12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_matches_in_memory_parse_and_skips_other_chunks() {
+        let mut audio = Vec::new();
+        for sample in [0i16, 100, -100, 200, 32767, -32768] {
+            audio.extend_from_slice(&sample.to_le_bytes());
+        }
+        let mut data = pcm_wav(1, 2, 16, 4, &audio);
+        let list = [b'L', b'I', b'S', b'T', 4, 0, 0, 0, 1, 2, 3, 4];
+        data.splice(36..36, list);
+        let from_memory = AudioData::try_from(&WavBinary { data: data.clone() }).unwrap();
+        let from_reader = AudioData::from_reader(Cursor::new(data)).unwrap();
+        assert_eq!(from_reader.samples, from_memory.samples);
+        assert_eq!(from_reader.channels, 2);
+        assert_eq!(from_reader.sample_rate, 8000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_reads_unsized_data_chunk_until_eof() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[0x01, 0x00, 0x02, 0x00, 0x03]);
+        data[40..44].copy_from_slice(&u32::MAX.to_le_bytes());
+        let audio = AudioData::from_reader(Cursor::new(data)).unwrap();
+        assert_eq!(audio.samples, vec![1, 2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn empty_data_chunk_is_not_read_to_eof() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[]);
+        data.extend_from_slice(b"LIST\x08\x00\x00\x00INFOabcd");
+        let from_memory = AudioData::try_from(&WavBinary { data: data.clone() }).unwrap();
+        let from_reader = AudioData::from_reader(Cursor::new(data)).unwrap();
+        assert!(from_memory.samples.is_empty());
+        assert_eq!(from_reader.samples, from_memory.samples);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_decodes_ima_adpcm_and_truncates_to_fact() {
+        let wav_data = vec![
+            b'R', b'I', b'F', b'F', 0x38, 0x00, 0x00, 0x00, b'W', b'A', b'V', b'E',
+            b'f', b'm', b't', b' ', 0x14, 0x00, 0x00, 0x00,
+            0x11, 0x00, 0x01, 0x00, 0x40, 0x1F, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x09, 0x00,
+            b'f', b'a', b'c', b't', 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+            b'd', b'a', b't', b'a', 0x08, 0x00, 0x00, 0x00,
+            0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x77, 0x77,
+        ];
+        let audio = AudioData::from_reader(Cursor::new(wav_data)).unwrap();
+        assert_eq!(audio.samples.len(), 5);
+        assert_eq!(audio.samples[0], 16);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn odd_sized_chunks_are_skipped_with_pad_byte() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[0x01, 0x00, 0x02, 0x00]);
+        data.splice(12..12, b"LIST\x03\x00\x00\x00abc\x00bext\x01\x00\x00\x00z\x00".iter().copied());
+        let wav = WavBinary { data: data.clone() };
+        assert_eq!(AudioData::read_wav_format(&wav.data).unwrap().sample_rate, 8000);
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![1, 2]);
+        let (lenient, warnings) = AudioData::try_from_lenient(&wav).unwrap();
+        assert_eq!(lenient.samples, vec![1, 2]);
+        assert!(warnings.is_empty());
+        assert_eq!(AudioData::from_reader(Cursor::new(data)).unwrap().samples, vec![1, 2]);
+    }
+
+    #[test]
+    fn chunk_index_collects_all_chunks_in_one_pass() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[0x01, 0x00, 0x02, 0x00]);
+        data.splice(12..12, b"LIST\x04\x00\x00\x00INFO".iter().copied());
+        data.extend_from_slice(b"fmt \x10\x00\x00\x00");
+        data.extend_from_slice(&[0; 16]);
+        let index = ChunkIndex::scan(&data);
+        let ids: Vec<[u8; 4]> = index.chunks.iter().map(|chunk| chunk.id).collect();
+        assert_eq!(ids, vec![*b"LIST", *b"fmt ", *b"data", *b"fmt "]);
+        assert_eq!(index.fmt.unwrap().offset, 32);
+        assert_eq!(index.data.unwrap().payload, &[0x01, 0x00, 0x02, 0x00]);
+        assert!(index.fact.is_none());
+        assert_eq!(index.header_info().unwrap().sample_rate, 8000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn truncation_errors_report_offset_and_chunk() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[0x01, 0x00, 0x02, 0x00, 0x03, 0x00]);
+        data.truncate(data.len() - 2);
+        let error = AudioData::try_from(&WavBinary { data: data.clone() }).unwrap_err();
+        assert!(matches!(error, BramError::Truncated { offset: 48, chunk: [b'd', b'a', b't', b'a'] }));
+        let error = AudioData::from_reader(Cursor::new(data)).unwrap_err();
+        assert_eq!(error.to_string(), "unexpected end of file at offset 48 while reading 'data' chunk");
+
+        let mut short_fmt = pcm_wav(1, 1, 16, 2, &[]);
+        short_fmt[16] = 8;
+        assert!(matches!(AudioData::read_wav_format(&short_fmt), Err(BramError::IncompleteChunk { offset: 12, .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fact_chunk_is_ignored_for_pcm() {
+        for fact in [0u32, 2, 9] {
+            let mut data = pcm_wav(1, 1, 16, 2, &[0x01, 0x00, 0x02, 0x00, 0x03, 0x00]);
+            data.splice(36..36, b"fact\x04\x00\x00\x00".iter().copied().chain(fact.to_le_bytes()));
+            let info = AudioData::read_header_info(&data).unwrap();
+            assert_eq!((info.fact_frames, info.frames()), (Some(fact as usize), 3));
+            let audio = AudioData::try_from(&WavBinary { data: data.clone() }).unwrap();
+            assert_eq!(audio.samples, vec![1, 2, 3]);
+            assert_eq!(AudioData::from_reader(Cursor::new(data)).unwrap().samples, vec![1, 2, 3]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_without_data_chunk_fails() {
+        let mut data = pcm_wav(1, 1, 16, 2, &[]);
+        data.truncate(36);
+        let result = AudioData::from_reader(Cursor::new(data));
+        assert!(matches!(result, Err(BramError::MissingChunk { id }) if &id == b"data"));
+    }
+}
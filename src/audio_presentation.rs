@@ -1,73 +1,1058 @@
-use std::io::{Error, ErrorKind};
-use crate::audio_data::AudioData;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+#[cfg(feature = "std")]
+use core::ops::Range;
+use core::time::Duration;
+use crate::annotation::Annotation;
+use crate::audio_data::{AudioData, HeaderInfo, UnavailableReason};
+use crate::channel_layout::ChannelLayout;
+use crate::error::BramError;
+use crate::markers::Marker;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+use crate::sample::Sample;
+use crate::weighting::{Weighting, WeightingFilter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DownsampleStrategy {
+    #[default]
+    Decimate,
+    Percentile { low: f32, high: f32 },
+    MinMax,
+    Rms,
+    Peak,
+    Average,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    #[default]
+    Linear,
+    Cubic,
+}
+
+#[deprecated(note = "renamed to DownsampleStrategy")]
+pub type DownsampleMode = DownsampleStrategy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NormalizationMode {
+    #[default]
+    ZeroToOne,
+    MinusOneToOne,
+    Decibels { floor_db: f32 },
+    Linear { center: f32, scale: f32 },
+}
+
+impl NormalizationMode {
+    pub(crate) fn apply(self, value: f32) -> f32 {
+        match self {
+            NormalizationMode::ZeroToOne => to_zero_one(value),
+            NormalizationMode::MinusOneToOne => value,
+            NormalizationMode::Decibels { floor_db } => {
+                let db = 20.0 * value.abs().log10();
+                ((db - floor_db) / -floor_db).clamp(0.0, 1.0)
+            }
+            NormalizationMode::Linear { center, scale } => center + scale * value,
+        }
+    }
+
+    pub(crate) fn is_invertible(self) -> bool {
+        match self {
+            NormalizationMode::Decibels { .. } => false,
+            NormalizationMode::Linear { scale, .. } => scale != 0.0,
+            _ => true,
+        }
+    }
+
+    pub(crate) fn invert(self, value: f32) -> f32 {
+        match self {
+            NormalizationMode::ZeroToOne => value * 2.0 - 1.0,
+            NormalizationMode::Linear { center, scale } => (value - center) / scale,
+            _ => value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Envelope {
+    pub low: Vec<f32>,
+    pub high: Vec<f32>,
+}
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StereoAudioPresentation {
     pub left_channel_points: Vec<f32>,
     pub right_channel_points: Vec<f32>,
+    pub left_envelope: Option<Envelope>,
+    pub right_envelope: Option<Envelope>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spectral_centroid: Option<Vec<f32>>,
+    pub annotations: Vec<Annotation>,
+    pub source_sample_rate: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_channels: u16,
+    pub source_frames: usize,
+    pub samples_per_point: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Downmix {
+    #[default]
+    Average,
+    Left,
+    Right,
+    EnergyPreserving,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonoAudioPresentation {
+    pub points: Vec<f32>,
+    pub envelope: Option<Envelope>,
+    pub source_sample_rate: u32,
+    pub source_frames: usize,
+    pub samples_per_point: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+    pub left_level: u16,
+    pub right_level: u16,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultiChannelAudioPresentation {
+    pub channels: Vec<Vec<f32>>,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelSpec {
+    pub channel: usize,
+    pub rate: u32,
+    pub strategy: DownsampleStrategy,
+}
+
+impl ChannelSpec {
+    pub fn new(channel: usize, rate: u32, strategy: DownsampleStrategy) -> Self {
+        Self { channel, rate, strategy }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelPresentation {
+    pub spec: ChannelSpec,
+    pub points: Vec<f32>,
+    pub envelope: Option<Envelope>,
+    pub samples_per_point: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerAudioPresentation {
+    pub left_channel_points: Vec<u8>,
+    pub right_channel_points: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum PresentationOutcome {
+    Available(StereoAudioPresentation),
+    Unavailable { header: HeaderInfo, reason: UnavailableReason },
 }
 
 pub(crate) struct RatedAudioData {
-    pub audio_data: AudioData,
+    pub audio_data: AudioData<f32>,
     pub sample_rate: u32,
+    pub mode: DownsampleStrategy,
+    pub weighting: Weighting,
+    pub normalization: NormalizationMode,
+    pub interpolation: Interpolation,
 }
 
 impl RatedAudioData {
     pub(crate) fn new(audio_data: &AudioData, sample_rate: u32) -> Self {
+        Self::from_samples(audio_data, sample_rate)
+    }
+
+    pub(crate) fn from_samples<S: Sample>(audio_data: &AudioData<S>, sample_rate: u32) -> Self {
         Self {
-            audio_data: audio_data.clone(), sample_rate
+            audio_data: audio_data.convert(),
+            sample_rate,
+            mode: DownsampleStrategy::Decimate,
+            weighting: Weighting::None,
+            normalization: NormalizationMode::ZeroToOne,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn with_mode(mut self, mode: DownsampleStrategy) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn with_weighting(mut self, weighting: Weighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn with_normalization(mut self, normalization: NormalizationMode) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+pub(crate) struct PresentationAccumulator {
+    channels: usize,
+    sample_rate: u32,
+    samples_per_interval: usize,
+    mode: DownsampleStrategy,
+    normalization: NormalizationMode,
+    filter: Option<WeightingFilter>,
+    frame_index: usize,
+    points: [Vec<f32>; 2],
+    envelopes: [Envelope; 2],
+    buckets: [Vec<f32>; 2],
+    #[cfg(feature = "rayon")]
+    batch: Vec<f32>,
+}
+
+impl PresentationAccumulator {
+    pub(crate) fn new(
+        channels: u16,
+        sample_rate: u32,
+        rate: u32,
+        mode: DownsampleStrategy,
+        weighting: Weighting,
+        normalization: NormalizationMode,
+    ) -> Result<Self, BramError> {
+        if channels != 1 && channels != 2 {
+            return Err(BramError::UnsupportedChannels { channels });
+        }
+        if let DownsampleStrategy::Percentile { low, high } = mode
+            && (!(0.0..=100.0).contains(&low) || !(0.0..=100.0).contains(&high) || low > high)
+        {
+            return Err(BramError::InvalidParameter("percentiles must satisfy 0 <= low <= high <= 100"));
+        }
+        if let NormalizationMode::Decibels { floor_db } = normalization
+            && !(f32::MIN..0.0).contains(&floor_db)
+        {
+            return Err(BramError::InvalidParameter("decibel floor must be below 0 dBFS"));
+        }
+        let samples_per_interval = samples_per_interval(sample_rate, rate)?;
+        Ok(Self {
+            channels: channels as usize,
+            sample_rate,
+            samples_per_interval,
+            mode,
+            normalization,
+            filter: WeightingFilter::new(weighting, sample_rate, channels),
+            frame_index: 0,
+            points: [Vec::new(), Vec::new()],
+            envelopes: [Envelope::default(), Envelope::default()],
+            buckets: [Vec::with_capacity(samples_per_interval), Vec::with_capacity(samples_per_interval)],
+            #[cfg(feature = "rayon")]
+            batch: Vec::new(),
+        })
+    }
+
+    #[cfg(feature = "rayon")]
+    fn fork(&self) -> Self {
+        Self {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            samples_per_interval: self.samples_per_interval,
+            mode: self.mode,
+            normalization: self.normalization,
+            filter: None,
+            frame_index: 0,
+            points: [Vec::new(), Vec::new()],
+            envelopes: [Envelope::default(), Envelope::default()],
+            buckets: [Vec::with_capacity(self.samples_per_interval), Vec::with_capacity(self.samples_per_interval)],
+            batch: Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn recycle(&mut self, presentation: StereoAudioPresentation) {
+        let [left, right] = &mut self.points;
+        *left = presentation.left_channel_points;
+        *right = presentation.right_channel_points;
+        left.clear();
+        right.clear();
+        for (target, envelope) in self.envelopes.iter_mut().zip([presentation.left_envelope, presentation.right_envelope]) {
+            if let Some(mut envelope) = envelope {
+                envelope.low.clear();
+                envelope.high.clear();
+                *target = envelope;
+            }
+        }
+    }
+
+    pub(crate) fn push<S: Sample>(&mut self, samples: &[S]) {
+        match self.filter.as_mut() {
+            Some(filter) => {
+                let samples: Vec<f32> = samples.iter().map(|sample| sample.to_f64() as f32).collect();
+                let filtered = filter.process(&samples);
+                self.push_frames(&filtered);
+            }
+            #[cfg(feature = "rayon")]
+            None if samples.len() >= PARALLEL_MIN_FRAMES * self.channels => self.push_parallel(samples),
+            None => self.push_frames(samples),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn push_block(&mut self, block: &[f32]) {
+        #[cfg(feature = "rayon")]
+        if self.filter.is_none() {
+            self.batch.extend_from_slice(block);
+            if self.batch.len() >= PARALLEL_BATCH_FRAMES * self.channels {
+                self.flush_batch();
+            }
+            return;
+        }
+        self.push(block);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn flush_batch(&mut self) {
+        let mut batch = core::mem::take(&mut self.batch);
+        self.push(&batch);
+        batch.clear();
+        self.batch = batch;
+    }
+
+    #[cfg(feature = "rayon")]
+    fn push_parallel<S: Sample>(&mut self, samples: &[S]) {
+        use rayon::prelude::*;
+
+        let interval = self.samples_per_interval;
+        let frames = samples.len() / self.channels;
+        let lead = ((interval - self.frame_index % interval) % interval).min(frames);
+        let aligned = (frames - lead) / interval * interval;
+        let (head, rest) = samples.split_at(lead * self.channels);
+        let (body, tail) = rest.split_at(aligned * self.channels);
+        self.push_frames(head);
+
+        let intervals_per_region = (aligned / interval).div_ceil(rayon::current_num_threads() * 4).max(1);
+        let this = &*self;
+        let regions: Vec<_> = body
+            .par_chunks(intervals_per_region * interval * self.channels)
+            .map(|region| {
+                let mut accumulator = this.fork();
+                accumulator.push_frames(region);
+                (accumulator.points, accumulator.envelopes)
+            })
+            .collect();
+        for (points, envelopes) in regions {
+            for (channel, (mut points, mut envelope)) in points.into_iter().zip(envelopes).enumerate() {
+                self.points[channel].append(&mut points);
+                self.envelopes[channel].low.append(&mut envelope.low);
+                self.envelopes[channel].high.append(&mut envelope.high);
+            }
+        }
+        self.frame_index += aligned;
+        self.push_frames(tail);
+    }
+
+    fn push_frames<S: Sample>(&mut self, samples: &[S]) {
+        for frame in samples.chunks_exact(self.channels) {
+            let (left, right) = (frame[0].to_f64() as f32, frame[self.channels - 1].to_f64() as f32);
+            match self.mode {
+                DownsampleStrategy::Decimate => {
+                    if self.frame_index.is_multiple_of(self.samples_per_interval) {
+                        self.points[0].push(self.normalization.apply(left));
+                        self.points[1].push(self.normalization.apply(right));
+                    }
+                }
+                _ => {
+                    self.buckets[0].push(left);
+                    self.buckets[1].push(right);
+                    if self.buckets[0].len() == self.samples_per_interval {
+                        self.flush_buckets();
+                    }
+                }
+            }
+            self.frame_index += 1;
+        }
+    }
+
+    pub(crate) fn flush_buckets(&mut self) {
+        for channel in 0..2 {
+            let bucket = &mut self.buckets[channel];
+            if bucket.is_empty() {
+                continue;
+            }
+            let (point, low, high) = match self.mode {
+                DownsampleStrategy::Decimate => return,
+                DownsampleStrategy::Percentile { low, high } => {
+                    bucket.sort_unstable_by(f32::total_cmp);
+                    (percentile(bucket, 50.0), percentile(bucket, low), percentile(bucket, high))
+                }
+                DownsampleStrategy::MinMax => {
+                    let (low, high) = bucket_range(bucket);
+                    let point = if high.abs() >= low.abs() { high } else { low };
+                    (point, low, high)
+                }
+                DownsampleStrategy::Rms => {
+                    let mean_square = bucket.iter().map(|sample| (*sample as f64).powi(2)).sum::<f64>() / bucket.len() as f64;
+                    let rms = mean_square.sqrt() as f32;
+                    (rms, -rms, rms)
+                }
+                DownsampleStrategy::Peak => {
+                    let peak = bucket.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+                    (peak, -peak, peak)
+                }
+                DownsampleStrategy::Average => {
+                    let mean = bucket.iter().map(|sample| *sample as f64).sum::<f64>() / bucket.len() as f64;
+                    let (low, high) = bucket_range(bucket);
+                    (mean as f32, low, high)
+                }
+            };
+            self.points[channel].push(self.normalization.apply(point));
+            self.envelopes[channel].low.push(self.normalization.apply(low));
+            self.envelopes[channel].high.push(self.normalization.apply(high));
+            bucket.clear();
+        }
+    }
+
+    pub(crate) fn drain_into(&mut self, presentation: &mut StereoAudioPresentation) {
+        let [left, right] = &mut self.points;
+        presentation.left_channel_points.append(left);
+        presentation.right_channel_points.append(right);
+        if self.mode != DownsampleStrategy::Decimate {
+            for (target, envelope) in [&mut presentation.left_envelope, &mut presentation.right_envelope]
+                .into_iter()
+                .zip(&mut self.envelopes)
+            {
+                let target = target.get_or_insert_with(Envelope::default);
+                target.low.append(&mut envelope.low);
+                target.high.append(&mut envelope.high);
+            }
+        }
+        presentation.source_sample_rate = self.sample_rate;
+        presentation.source_channels = self.channels as u16;
+        presentation.source_frames = self.frame_index;
+        presentation.samples_per_point = self.samples_per_interval;
+    }
+
+    pub(crate) fn finish(mut self) -> StereoAudioPresentation {
+        #[cfg(feature = "rayon")]
+        self.flush_batch();
+        self.flush_buckets();
+        let [left_channel_points, right_channel_points] = self.points;
+        let [left_envelope, right_envelope] = match self.mode {
+            DownsampleStrategy::Decimate => [None, None],
+            _ => self.envelopes.map(Some),
+        };
+        StereoAudioPresentation {
+            left_channel_points,
+            right_channel_points,
+            left_envelope,
+            right_envelope,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: self.sample_rate,
+            source_channels: self.channels as u16,
+            source_frames: self.frame_index,
+            samples_per_point: self.samples_per_interval,
         }
     }
 }
 
 impl TryFrom<&RatedAudioData> for StereoAudioPresentation {
-    type Error = Error;
+    type Error = BramError;
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            name = "build_presentation",
+            skip_all,
+            fields(frames = rated_audio_data.audio_data.frames(), rate = rated_audio_data.sample_rate),
+            err
+        )
+    )]
     fn try_from(rated_audio_data: &RatedAudioData) -> Result<Self, Self::Error> {
         let samples = &rated_audio_data.audio_data;
-        if samples.channels != 1 && samples.channels != 2 {
-            return Err(Error::new(
-                ErrorKind::InvalidData,
-                "only mono or stereo audio is supported"
-            ));
+        if rated_audio_data.sample_rate > samples.sample_rate {
+            return interpolated_presentation(rated_audio_data);
         }
-        let samples_per_interval = samples.sample_rate / rated_audio_data.sample_rate;
-        let total_frames = samples.samples.len() / samples.channels as usize;
-        let num_points = (total_frames + samples_per_interval as usize - 1) / samples_per_interval as usize;
-        let mut left_channel_points = Vec::with_capacity(num_points);
-        let mut right_channel_points = Vec::with_capacity(num_points);
-        let mut frame_index = 0;
-        while frame_index < total_frames {
-            let sample_index = frame_index * samples.channels as usize;
+        let mut accumulator = PresentationAccumulator::new(
+            samples.channels,
+            samples.sample_rate,
+            rated_audio_data.sample_rate,
+            rated_audio_data.mode,
+            rated_audio_data.weighting,
+            rated_audio_data.normalization,
+        )?;
+        accumulator.push(&samples.samples);
+        let presentation = accumulator.finish();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(points = presentation.left_channel_points.len(), "presentation built");
+        Ok(presentation)
+    }
+}
 
-            let first_sample = samples.samples[sample_index];
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_FRAMES: usize = 1 << 16;
+#[cfg(feature = "rayon")]
+const PARALLEL_BATCH_FRAMES: usize = 1 << 20;
 
-            let second_sample = if samples.channels == 2 {
-                samples.samples[sample_index + 1]
-            } else {
-                first_sample
-            };
+fn interpolated_presentation(rated_audio_data: &RatedAudioData) -> Result<StereoAudioPresentation, BramError> {
+    let samples = &rated_audio_data.audio_data;
+    if samples.channels != 1 && samples.channels != 2 {
+        return Err(BramError::UnsupportedChannels { channels: samples.channels });
+    }
+    if samples.sample_rate == 0 {
+        return Err(BramError::InvalidParameter("source sample rate must be non-zero"));
+    }
+    let channels = samples.channels as usize;
+    let weighted = WeightingFilter::new(rated_audio_data.weighting, samples.sample_rate, samples.channels)
+        .map(|mut filter| filter.process(&samples.samples));
+    let weighted = weighted.as_deref().unwrap_or(&samples.samples);
+    let frames = weighted.len() / channels;
+    let num_points = (frames as u64 * rated_audio_data.sample_rate as u64).div_ceil(samples.sample_rate as u64) as usize;
+    let step = samples.sample_rate as f64 / rated_audio_data.sample_rate as f64;
+    let channel_points = |channel: usize| -> Vec<f32> {
+        let at = |frame: isize| weighted[frame.clamp(0, frames as isize - 1) as usize * channels + channel] as f64;
+        (0..num_points)
+            .map(|point| {
+                let position = point as f64 * step;
+                let index = position as isize;
+                let t = position - index as f64;
+                let value = match rated_audio_data.interpolation {
+                    Interpolation::Linear => at(index) + (at(index + 1) - at(index)) * t,
+                    Interpolation::Cubic => {
+                        let (p0, p1, p2, p3) = (at(index - 1), at(index), at(index + 1), at(index + 2));
+                        p1 + 0.5 * t * (p2 - p0 + t * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3 + t * (3.0 * (p1 - p2) + p3 - p0)))
+                    }
+                };
+                rated_audio_data.normalization.apply(value.clamp(-1.0, 1.0) as f32)
+            })
+            .collect()
+    };
+    Ok(StereoAudioPresentation {
+        left_channel_points: channel_points(0),
+        right_channel_points: channel_points(channels - 1),
+        left_envelope: None,
+        right_envelope: None,
+        spectral_centroid: None,
+        annotations: Vec::new(),
+        source_sample_rate: samples.sample_rate,
+        source_channels: samples.channels,
+        source_frames: frames,
+        samples_per_point: 1,
+    })
+}
+
+impl StereoAudioPresentation {
+    #[cfg(feature = "std")]
+    pub(crate) fn with_point_count(audio_data: &AudioData, num_points: usize) -> Result<Self, BramError> {
+        if num_points == 0 {
+            return Err(BramError::InvalidParameter("point count must be non-zero"));
+        }
+        if audio_data.channels != 1 && audio_data.channels != 2 {
+            return Err(BramError::UnsupportedChannels { channels: audio_data.channels });
+        }
+        let channels = audio_data.channels as usize;
+        let frames = audio_data.frames();
+        let sample = |frame: usize, channel: usize| {
+            audio_data.samples.get(frame * channels + channel).map_or(0.0, |sample| sample.to_f64() as f32)
+        };
+        let (left_channel_points, right_channel_points) = (0..num_points)
+            .map(|index| {
+                let frame = index * frames / num_points;
+                (to_zero_one(sample(frame, 0)), to_zero_one(sample(frame, channels - 1)))
+            })
+            .unzip();
+        Ok(StereoAudioPresentation {
+            left_channel_points,
+            right_channel_points,
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: audio_data.sample_rate,
+            source_channels: audio_data.channels,
+            source_frames: frames,
+            samples_per_point: frames / num_points,
+        })
+    }
 
-            let first_normalized = (first_sample as f32 + 32768.0) / 65535.0;
-            let second_normalized = (second_sample as f32 + 32768.0) / 65535.0;
+    pub fn duration(&self) -> Duration {
+        if self.source_sample_rate == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f64(self.source_frames as f64 / self.source_sample_rate as f64)
+    }
+
+    pub fn points_per_second(&self) -> f64 {
+        if self.source_sample_rate == 0 || self.samples_per_point == 0 {
+            return 0.0;
+        }
+        let points = self.left_channel_points.len();
+        if self.source_frames > 0 && points > self.source_frames {
+            return points as f64 * self.source_sample_rate as f64 / self.source_frames as f64;
+        }
+        self.source_sample_rate as f64 / self.samples_per_point as f64
+    }
+
+    pub fn brightness(&self) -> Option<Vec<f32>> {
+        let nyquist = self.source_sample_rate as f32 / 2.0;
+        let centroids = self.spectral_centroid.as_ref()?;
+        Some(centroids.iter().map(|centroid| if nyquist > 0.0 { (centroid / nyquist).clamp(0.0, 1.0) } else { 0.0 }).collect())
+    }
+
+    pub fn time_at(&self, index: usize) -> Duration {
+        Duration::from_secs_f64(self.point_time(index))
+    }
+
+    pub fn timed_points(&self) -> impl Iterator<Item = (Duration, f32, f32)> + '_ {
+        self.left_channel_points
+            .iter()
+            .zip(&self.right_channel_points)
+            .enumerate()
+            .map(|(index, (left, right))| (self.time_at(index), *left, *right))
+    }
 
-            left_channel_points.push(first_normalized);
-            right_channel_points.push(second_normalized);
+    pub(crate) fn point_time(&self, index: usize) -> f64 {
+        let points_per_second = self.points_per_second();
+        if points_per_second == 0.0 {
+            return 0.0;
+        }
+        index as f64 / points_per_second
+    }
 
-            frame_index += samples_per_interval as usize;
+    /// Regroups existing points into a coarser rate without re-decoding. `strategy` and `normalization` must be
+    /// the ones the presentation was built with: points are mapped back to sample values before buckets are
+    /// combined, so Decibels normalization and Percentile points can only be regrouped by re-decoding.
+    pub fn resample_points(
+        &self,
+        new_rate: u32,
+        strategy: DownsampleStrategy,
+        normalization: NormalizationMode,
+    ) -> Result<StereoAudioPresentation, BramError> {
+        if new_rate == 0 || self.samples_per_point == 0 {
+            return Err(BramError::InvalidParameter("presentation rate must be non-zero"));
+        }
+        let samples_per_point = (self.source_sample_rate / new_rate) as usize;
+        if samples_per_point < self.samples_per_point {
+            return Err(BramError::InvalidParameter("points can only be resampled to a coarser rate"));
+        }
+        if !samples_per_point.is_multiple_of(self.samples_per_point) {
+            return Err(BramError::InvalidParameter("new rate must group a whole number of existing points"));
         }
+        if strategy != DownsampleStrategy::Decimate && !normalization.is_invertible() {
+            return Err(BramError::InvalidParameter("points can only be combined under an invertible normalization"));
+        }
+        let factor = samples_per_point / self.samples_per_point;
+        let (left_channel_points, left_envelope) =
+            regroup_channel(&self.left_channel_points, self.left_envelope.as_ref(), factor, strategy, normalization)?;
+        let (right_channel_points, right_envelope) =
+            regroup_channel(&self.right_channel_points, self.right_envelope.as_ref(), factor, strategy, normalization)?;
         Ok(StereoAudioPresentation {
             left_channel_points,
             right_channel_points,
+            left_envelope,
+            right_envelope,
+            spectral_centroid: self.spectral_centroid.as_ref().map(|centroids| {
+                centroids.chunks(factor).map(|chunk| chunk.iter().sum::<f32>() / chunk.len() as f32).collect()
+            }),
+            annotations: self
+                .annotations
+                .iter()
+                .map(|annotation| Annotation {
+                    start_point: annotation.start_point / factor,
+                    end_point: annotation.end_point.div_ceil(factor),
+                    ..annotation.clone()
+                })
+                .collect(),
+            source_sample_rate: self.source_sample_rate,
+            source_channels: self.source_channels,
+            source_frames: self.source_frames,
+            samples_per_point: self.samples_per_point * factor,
+        })
+    }
+
+    pub fn append(&mut self, other: StereoAudioPresentation) -> Result<(), BramError> {
+        if self.source_sample_rate != other.source_sample_rate || self.samples_per_point != other.samples_per_point {
+            return Err(BramError::InvalidParameter("appended presentation must share sample rate and samples per point"));
+        }
+        if self.left_envelope.is_some() != other.left_envelope.is_some() {
+            return Err(BramError::InvalidParameter("appended presentation must use the same downsample strategy"));
+        }
+        let boundary = self.left_channel_points.len();
+        self.left_channel_points.extend(other.left_channel_points);
+        self.right_channel_points.extend(other.right_channel_points);
+        for (envelope, appended) in [
+            (&mut self.left_envelope, other.left_envelope),
+            (&mut self.right_envelope, other.right_envelope),
+        ] {
+            if let (Some(envelope), Some(appended)) = (envelope, appended) {
+                envelope.low.extend(appended.low);
+                envelope.high.extend(appended.high);
+            }
+        }
+        self.spectral_centroid = match (self.spectral_centroid.take(), other.spectral_centroid) {
+            (Some(mut centroids), Some(appended)) => {
+                centroids.extend(appended);
+                Some(centroids)
+            }
+            _ => None,
+        };
+        self.annotations.push(Annotation::boundary(boundary, format!("segment boundary at frame {}", self.source_frames)));
+        self.annotations.extend(other.annotations.into_iter().map(|mut annotation| {
+            annotation.start_point += boundary;
+            annotation.end_point += boundary;
+            annotation
+        }));
+        self.source_frames += other.source_frames;
+        Ok(())
+    }
+
+    pub fn annotate_markers(&mut self, markers: &[Marker]) {
+        let samples_per_point = self.samples_per_point.max(1);
+        for marker in markers {
+            let start_point = marker.position / samples_per_point;
+            let end_point = (marker.position + marker.length).div_ceil(samples_per_point).max(start_point);
+            self.annotations.push(Annotation::marker(start_point, end_point, marker.label.clone()));
+        }
+    }
+
+    pub fn runs(&self, levels: u16) -> impl Iterator<Item = Run> + '_ {
+        let quantize = move |point: f32| (point.clamp(0.0, 1.0) * levels.saturating_sub(1) as f32).round() as u16;
+        let len = self.left_channel_points.len().min(self.right_channel_points.len());
+        let mut index = 0;
+        core::iter::from_fn(move || {
+            if index >= len {
+                return None;
+            }
+            let start = index;
+            let left_level = quantize(self.left_channel_points[start]);
+            let right_level = quantize(self.right_channel_points[start]);
+            index += 1;
+            while index < len
+                && quantize(self.left_channel_points[index]) == left_level
+                && quantize(self.right_channel_points[index]) == right_level
+            {
+                index += 1;
+            }
+            Some(Run { start, length: index - start, left_level, right_level })
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn fill_frame_gaps(&mut self, gaps: &[Range<usize>]) {
+        let total_points = self.left_channel_points.len();
+        let source_frames = self.source_frames;
+        let samples_per_point = self.samples_per_point.max(1);
+        let to_point = |frame: usize| {
+            if source_frames > 0 && total_points > source_frames {
+                (frame * total_points).div_ceil(source_frames)
+            } else {
+                frame.div_ceil(samples_per_point)
+            }
+        };
+        for gap in gaps {
+            let points = to_point(gap.start)..to_point(gap.end);
+            if points.is_empty() {
+                continue;
+            }
+            interpolate(&mut self.left_channel_points, points.clone());
+            interpolate(&mut self.right_channel_points, points.clone());
+            for envelope in [&mut self.left_envelope, &mut self.right_envelope].into_iter().flatten() {
+                interpolate(&mut envelope.low, points.clone());
+                interpolate(&mut envelope.high, points.clone());
+            }
+            self.annotations.push(Annotation::synthetic(
+                points.start,
+                points.end,
+                format!("interpolated over {} missing frames", gap.len()),
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn interpolate(points: &mut Vec<f32>, range: Range<usize>) {
+    let original_len = points.len();
+    if range.end > points.len() {
+        points.resize(range.end, 0.5);
+    }
+    let before = range.start.checked_sub(1).and_then(|index| points.get(index).copied());
+    let after = (range.end < original_len).then(|| points[range.end]);
+    let span = (range.end - range.start + 1) as f32;
+    for (step, index) in range.clone().enumerate() {
+        points[index] = match (before, after) {
+            (Some(before), Some(after)) => before + (after - before) * (step + 1) as f32 / span,
+            (Some(value), None) | (None, Some(value)) => value,
+            (None, None) => 0.5,
+        };
+    }
+}
+
+impl MonoAudioPresentation {
+    #[cfg(feature = "std")]
+    pub(crate) fn new(rated_audio_data: &RatedAudioData, downmix: Downmix) -> Result<Self, BramError> {
+        let samples = &rated_audio_data.audio_data;
+        if samples.channels == 0 {
+            return Err(BramError::UnsupportedChannels { channels: 0 });
+        }
+        let channels = samples.channels as usize;
+        let mono: Vec<f32> = samples
+            .samples
+            .chunks_exact(channels)
+            .map(|frame| {
+                let sum = || frame.iter().map(|sample| *sample as f64).sum::<f64>();
+                let value = match downmix {
+                    Downmix::Average => sum() / channels as f64,
+                    Downmix::Left => frame[0] as f64,
+                    Downmix::Right => frame[channels.min(2) - 1] as f64,
+                    Downmix::EnergyPreserving => sum() / (channels as f64).sqrt(),
+                };
+                value.clamp(-1.0, 1.0) as f32
+            })
+            .collect();
+        let mut accumulator = PresentationAccumulator::new(
+            1,
+            samples.sample_rate,
+            rated_audio_data.sample_rate,
+            rated_audio_data.mode,
+            rated_audio_data.weighting,
+            rated_audio_data.normalization,
+        )?;
+        accumulator.push(&mono);
+        let stereo = accumulator.finish();
+        Ok(MonoAudioPresentation {
+            points: stereo.left_channel_points,
+            envelope: stereo.left_envelope,
+            source_sample_rate: stereo.source_sample_rate,
+            source_frames: stereo.source_frames,
+            samples_per_point: stereo.samples_per_point,
+        })
+    }
+}
+
+impl TryFrom<&RatedAudioData> for MultiChannelAudioPresentation {
+    type Error = BramError;
+
+    fn try_from(rated_audio_data: &RatedAudioData) -> Result<Self, Self::Error> {
+        let samples = &rated_audio_data.audio_data;
+        if samples.channels == 0 {
+            return Err(BramError::UnsupportedChannels { channels: 0 });
+        }
+        let channel_count = samples.channels as usize;
+        let channels = (0..channel_count)
+            .map(|channel| {
+                let channel_samples: Vec<f32> = samples.samples.iter().skip(channel).step_by(channel_count).copied().collect();
+                let mut accumulator = PresentationAccumulator::new(
+                    1,
+                    samples.sample_rate,
+                    rated_audio_data.sample_rate,
+                    rated_audio_data.mode,
+                    rated_audio_data.weighting,
+                    rated_audio_data.normalization,
+                )?;
+                accumulator.push(&channel_samples);
+                Ok(accumulator.finish().left_channel_points)
+            })
+            .collect::<Result<Vec<_>, BramError>>()?;
+        Ok(MultiChannelAudioPresentation {
+            channels,
+            labels: channel_labels(samples.channels, samples.channel_mask),
+        })
+    }
+}
+
+impl ChannelPresentation {
+    #[cfg(feature = "std")]
+    pub(crate) fn build(rated_audio_data: &RatedAudioData, spec: ChannelSpec) -> Result<Self, BramError> {
+        let samples = &rated_audio_data.audio_data;
+        let channels = samples.channels as usize;
+        if spec.channel >= channels {
+            return Err(BramError::InvalidParameter("channel index out of range"));
+        }
+        let channel_samples: Vec<f32> = samples.samples.iter().skip(spec.channel).step_by(channels).copied().collect();
+        let mut accumulator = PresentationAccumulator::new(
+            1,
+            samples.sample_rate,
+            spec.rate,
+            spec.strategy,
+            rated_audio_data.weighting,
+            rated_audio_data.normalization,
+        )?;
+        accumulator.push(&channel_samples);
+        let presentation = accumulator.finish();
+        Ok(ChannelPresentation {
+            spec,
+            points: presentation.left_channel_points,
+            envelope: presentation.left_envelope,
+            samples_per_point: presentation.samples_per_point,
+        })
+    }
+}
+
+impl IntegerAudioPresentation {
+    #[cfg(feature = "std")]
+    pub(crate) fn from_audio(samples: &AudioData<i16>, rate: u32) -> Result<Self, BramError> {
+        if samples.channels != 1 && samples.channels != 2 {
+            return Err(BramError::UnsupportedChannels { channels: samples.channels });
+        }
+        let channels = samples.channels as usize;
+        let samples_per_interval = samples_per_interval(samples.sample_rate, rate)?;
+        let total_frames = samples.samples.len() / channels;
+        let num_points = total_frames.div_ceil(samples_per_interval);
+        let mut left_channel_points = Vec::with_capacity(num_points);
+        let mut right_channel_points = Vec::with_capacity(num_points);
+        for frame_index in (0..total_frames).step_by(samples_per_interval) {
+            let sample_index = frame_index * channels;
+            left_channel_points.push(to_u8(samples.samples[sample_index]));
+            right_channel_points.push(to_u8(samples.samples[sample_index + channels - 1]));
+        }
+        Ok(IntegerAudioPresentation {
+            left_channel_points,
+            right_channel_points,
         })
     }
 }
 
+#[cfg(feature = "std")]
+fn to_u8(sample: i16) -> u8 {
+    ((sample as i32 + 32768) >> 8) as u8
+}
+
+pub(crate) fn channel_labels(channels: u16, channel_mask: Option<u32>) -> Vec<String> {
+    let layout = match (channel_mask, channels) {
+        (Some(mask), _) if mask != 0 => ChannelLayout::from_mask(mask, channels),
+        (_, 1) => return vec!["M".to_string()],
+        (_, 2) => return vec!["L".to_string(), "R".to_string()],
+        (_, 6 | 8) => ChannelLayout::default_for(channels),
+        _ => ChannelLayout { speakers: vec![None; channels as usize] },
+    };
+    layout
+        .speakers
+        .iter()
+        .enumerate()
+        .map(|(index, speaker)| match speaker {
+            Some(speaker) => speaker.label().to_string(),
+            None => format!("Ch{}", index + 1),
+        })
+        .collect()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn normalize(sample: i16) -> f32 {
+    to_zero_one(sample.to_f64() as f32)
+}
+
+fn to_zero_one(value: f32) -> f32 {
+    ((value + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+fn regroup_channel(
+    points: &[f32],
+    envelope: Option<&Envelope>,
+    factor: usize,
+    strategy: DownsampleStrategy,
+    normalization: NormalizationMode,
+) -> Result<(Vec<f32>, Option<Envelope>), BramError> {
+    let raw = |point: f32| normalization.invert(point);
+    let pick = |chunk: &[f32], better: fn(f32, f32) -> bool| {
+        chunk.iter().copied().reduce(|best, point| if better(raw(point), raw(best)) { point } else { best }).unwrap_or_default()
+    };
+    let mean = |chunk: &[f32], power: i32| chunk.iter().map(|point| (raw(*point) as f64).powi(power)).sum::<f64>() / chunk.len() as f64;
+    let extremes = envelope.map(|envelope| Envelope {
+        low: envelope.low.chunks(factor).map(|chunk| pick(chunk, |a, b| a < b)).collect(),
+        high: envelope.high.chunks(factor).map(|chunk| pick(chunk, |a, b| a > b)).collect(),
+    });
+    let points: Vec<f32> = match strategy {
+        DownsampleStrategy::Decimate => points.iter().step_by(factor).copied().collect(),
+        DownsampleStrategy::Peak => points.chunks(factor).map(|chunk| pick(chunk, |a, b| a > b)).collect(),
+        DownsampleStrategy::Rms => points.chunks(factor).map(|chunk| normalization.apply(mean(chunk, 2).sqrt() as f32)).collect(),
+        DownsampleStrategy::Average => points.chunks(factor).map(|chunk| normalization.apply(mean(chunk, 1) as f32)).collect(),
+        DownsampleStrategy::MinMax => {
+            let extremes = extremes.as_ref().ok_or(BramError::InvalidParameter("min-max points need their envelope to be regrouped"))?;
+            extremes
+                .low
+                .iter()
+                .zip(&extremes.high)
+                .map(|(low, high)| if raw(*high).abs() >= raw(*low).abs() { *high } else { *low })
+                .collect()
+        }
+        DownsampleStrategy::Percentile { .. } => {
+            return Err(BramError::InvalidParameter("percentile points cannot be regrouped without re-decoding"));
+        }
+    };
+    let envelope = match strategy {
+        DownsampleStrategy::Peak | DownsampleStrategy::Rms => envelope.map(|_| Envelope {
+            low: points.iter().map(|point| normalization.apply(-raw(*point))).collect(),
+            high: points.clone(),
+        }),
+        _ => extremes,
+    };
+    Ok((points, envelope))
+}
+
+fn bucket_range(bucket: &[f32]) -> (f32, f32) {
+    bucket.iter().fold((f32::INFINITY, f32::NEG_INFINITY), |(low, high), sample| (low.min(*sample), high.max(*sample)))
+}
+
+fn samples_per_interval(sample_rate: u32, rate: u32) -> Result<usize, BramError> {
+    if rate == 0 {
+        return Err(BramError::InvalidParameter("presentation rate must be non-zero"));
+    }
+    if rate > sample_rate {
+        return Err(BramError::InvalidParameter("presentation rate above the source rate requires interpolation"));
+    }
+    Ok((sample_rate / rate) as usize)
+}
+
+fn percentile(sorted: &[f32], percent: f32) -> f32 {
+    let index = (percent / 100.0 * (sorted.len() - 1) as f32).round() as usize;
+    sorted[index]
+}
+
 #[cfg(test)]
 mod audio_presentation_tests {
-    use std::io::ErrorKind;
+    use crate::annotation::{Annotation, AnnotationKind};
     use crate::audio_data::AudioData;
-    use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
+    use crate::audio_presentation::{channel_labels, DownsampleStrategy, Interpolation, MultiChannelAudioPresentation, NormalizationMode, PresentationAccumulator, RatedAudioData, Run, StereoAudioPresentation};
+    #[cfg(feature = "std")]
+    use crate::audio_presentation::{ChannelPresentation, ChannelSpec, Downmix, IntegerAudioPresentation, MonoAudioPresentation};
+    use crate::error::BramError;
+    use crate::markers::Marker;
+    use crate::weighting::Weighting;
+
+    fn zero_one(sample: f32) -> f32 {
+        (sample / 32768.0 + 1.0) / 2.0
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn zero_to_one_scaling_is_symmetric_around_silence() {
+        let audio_data = AudioData { samples: vec![0i16, 16384, -16384, -32768], channels: 1, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let presentation = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 4)).unwrap();
+        assert_eq!(presentation.left_channel_points, vec![0.5, 0.75, 0.25, 0.0]);
+
+        let deep = AudioData { samples: vec![0x100i32, -0x100], channels: 1, sample_rate: 2, bits_per_sample: 24, channel_mask: None };
+        let rated = RatedAudioData::from_samples(&deep, 2).with_normalization(NormalizationMode::MinusOneToOne);
+        let points = StereoAudioPresentation::try_from(&rated).unwrap().left_channel_points;
+        assert_eq!(points, vec![0x100 as f32 / 2147483648.0, -0x100 as f32 / 2147483648.0]);
+    }
 
     #[test]
     fn create_audio_presentation_from_audiodata_stereo() {
@@ -75,8 +1060,10 @@ mod audio_presentation_tests {
             samples: vec![0, 0, 32767, -32768, -32768, 32767, 16384, -16384],
             channels: 2,
             sample_rate: 10,
+            bits_per_sample: 16,
+            channel_mask: None,
         };
-        let rated_audio_data = RatedAudioData {audio_data, sample_rate: 5};
+        let rated_audio_data = RatedAudioData {audio_data: audio_data.convert(), sample_rate: 5, mode: DownsampleStrategy::Decimate, weighting: Weighting::None, normalization: NormalizationMode::ZeroToOne, interpolation: Interpolation::Linear};
         let result = StereoAudioPresentation::try_from(&rated_audio_data);
         assert!(result.is_ok());
         let presentation = result.unwrap();
@@ -91,9 +1078,11 @@ mod audio_presentation_tests {
         let audio_data = AudioData {
             samples: vec![0, 0, 32767, -32768, -32768, 32767, 16384, -16384],
             channels: 1,
-            sample_rate: 10
+            sample_rate: 10,
+            bits_per_sample: 16,
+            channel_mask: None,
         };
-        let rated_audio_data = RatedAudioData {audio_data, sample_rate: 5};
+        let rated_audio_data = RatedAudioData {audio_data: audio_data.convert(), sample_rate: 5, mode: DownsampleStrategy::Decimate, weighting: Weighting::None, normalization: NormalizationMode::ZeroToOne, interpolation: Interpolation::Linear};
         let result = StereoAudioPresentation::try_from(&rated_audio_data);
         assert!(result.is_ok());
         let presentation = result.unwrap();
@@ -103,16 +1092,671 @@ mod audio_presentation_tests {
         assert!((presentation.right_channel_points[0] - 0.5).abs() < 0.001);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn channel_presentations_use_independent_rates_and_strategies() {
+        let samples: Vec<i16> = (0..40).flat_map(|frame| [(frame * 800) as i16, -((frame % 4) * 8000) as i16]).collect();
+        let audio_data = AudioData { samples, channels: 2, sample_rate: 40, bits_per_sample: 16, channel_mask: None };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 10).with_normalization(NormalizationMode::MinusOneToOne);
+        let left = ChannelPresentation::build(&rated_audio_data, ChannelSpec::new(0, 4, DownsampleStrategy::Peak)).unwrap();
+        let right = ChannelPresentation::build(&rated_audio_data, ChannelSpec::new(1, 10, DownsampleStrategy::Rms)).unwrap();
+        assert_eq!((left.points.len(), left.samples_per_point), (4, 10));
+        assert_eq!(left.points[0], 7200.0 / 32768.0);
+        assert!(left.envelope.is_some());
+        assert_eq!((right.points.len(), right.samples_per_point), (10, 4));
+        assert!((right.points[0] - (8000.0f32 * 8000.0 * 14.0 / 4.0).sqrt() / 32768.0).abs() < 1e-6);
+
+        let missing = ChannelPresentation::build(&rated_audio_data, ChannelSpec::new(2, 4, DownsampleStrategy::Peak));
+        assert!(matches!(missing, Err(BramError::InvalidParameter(_))));
+    }
+
     #[test]
     fn create_audio_presentation_from_audiodata_fail_too_many_chanels() {
         let audio_data = AudioData {
             samples: vec![0, 0, 32767, -32768, -32768, 32767, 16384, -16384],
             channels: 3,
             sample_rate: 10,
+            bits_per_sample: 16,
+            channel_mask: None,
         };
-        let rated_audio_data = RatedAudioData {audio_data, sample_rate: 5};
+        let rated_audio_data = RatedAudioData {audio_data: audio_data.convert(), sample_rate: 5, mode: DownsampleStrategy::Decimate, weighting: Weighting::None, normalization: NormalizationMode::ZeroToOne, interpolation: Interpolation::Linear};
         let result = StereoAudioPresentation::try_from(&rated_audio_data);
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(matches!(result, Err(BramError::UnsupportedChannels { channels: 3 })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn percentile_mode_ignores_single_sample_spikes() {
+        let mut samples = vec![0i16; 200];
+        samples[3] = 32767;
+        samples[150] = -32768;
+        let audio_data = AudioData {
+            samples,
+            channels: 1,
+            sample_rate: 200,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 2)
+            .with_mode(DownsampleStrategy::Percentile { low: 5.0, high: 95.0 });
+        let presentation = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+        let envelope = presentation.left_envelope.unwrap();
+        assert_eq!(envelope.high.len(), 2);
+        assert!((envelope.high[0] - 0.5).abs() < 0.001);
+        assert!((envelope.low[1] - 0.5).abs() < 0.001);
+        assert!((presentation.left_channel_points[0] - 0.5).abs() < 0.001);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn min_max_mode_keeps_transients_between_decimated_frames() {
+        let mut samples = vec![0i16; 200];
+        samples[3] = 30000;
+        samples[151] = -20000;
+        let audio_data = AudioData {
+            samples,
+            channels: 1,
+            sample_rate: 100,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let decimated = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 2)).unwrap();
+        assert!(decimated.left_channel_points.iter().all(|point| (point - 0.5).abs() < 0.001));
+
+        let rated_audio_data = RatedAudioData::new(&audio_data, 2).with_mode(DownsampleStrategy::MinMax);
+        let presentation = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+        assert_eq!(presentation.left_channel_points.len(), 4);
+        assert_eq!(presentation.left_channel_points[0], zero_one(30000.0));
+        assert_eq!(presentation.left_channel_points[3], zero_one(-20000.0));
+        let envelope = presentation.left_envelope.unwrap();
+        assert_eq!(envelope.high[0], zero_one(30000.0));
+        assert_eq!(envelope.low[0], zero_one(0.0));
+        assert_eq!(envelope.low[3], zero_one(-20000.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rms_mode_reports_bucket_energy_as_symmetric_band() {
+        let audio_data = AudioData {
+            samples: vec![1000, 0, -1000, 0, 300, 400, -300, -400],
+            channels: 2,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 2).with_mode(DownsampleStrategy::Rms);
+        let presentation = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+        assert_eq!(presentation.left_channel_points, vec![zero_one(1000.0), zero_one(300.0)]);
+        assert_eq!(presentation.right_channel_points, vec![zero_one(0.0), zero_one(400.0)]);
+        let envelope = presentation.left_envelope.unwrap();
+        assert_eq!(envelope.low, vec![zero_one(-1000.0), zero_one(-300.0)]);
+        assert_eq!(envelope.high, presentation.left_channel_points);
+    }
+
+    #[test]
+    fn append_concatenates_points_and_marks_boundary() {
+        let segment = |points: Vec<f32>| StereoAudioPresentation {
+            left_channel_points: points.clone(),
+            right_channel_points: points,
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 100,
+            source_channels: 2,
+            source_frames: 20,
+            samples_per_point: 10,
+        };
+        let mut first = segment(vec![0.1, 0.2]);
+        let mut second = segment(vec![0.3]);
+        second.annotations.push(Annotation::synthetic(0, 1, "gap"));
+        first.append(second).unwrap();
+        assert_eq!(first.left_channel_points, vec![0.1, 0.2, 0.3]);
+        assert_eq!(first.source_frames, 40);
+        assert_eq!(first.annotations[0].kind, AnnotationKind::Boundary);
+        assert_eq!(first.annotations[0].start_point, 2);
+        assert_eq!(first.annotations[1].start_point, 2);
+        assert_eq!(first.annotations[1].end_point, 3);
+
+        let mut mismatched = segment(vec![0.4]);
+        mismatched.samples_per_point = 20;
+        assert!(matches!(first.append(mismatched), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn resample_points_matches_redecoding_for_decimation() {
+        let audio_data = AudioData {
+            samples: (0..32).map(|i| i * 100).collect(),
+            channels: 1,
+            sample_rate: 16,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let fine = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 8)).unwrap();
+        let coarse = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 2)).unwrap();
+        let resample = |presentation: &StereoAudioPresentation, rate, strategy| {
+            presentation.resample_points(rate, strategy, NormalizationMode::ZeroToOne)
+        };
+        let resampled = resample(&fine, 2, DownsampleStrategy::Decimate).unwrap();
+        assert_eq!(resampled.left_channel_points, coarse.left_channel_points);
+        assert_eq!(resampled.samples_per_point, coarse.samples_per_point);
+        assert!(matches!(resample(&fine, 16, DownsampleStrategy::Decimate), Err(BramError::InvalidParameter(_))));
+        assert!(matches!(resample(&fine, 3, DownsampleStrategy::Decimate), Err(BramError::InvalidParameter(_))));
+
+        let enveloped = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 8).with_mode(DownsampleStrategy::MinMax)).unwrap();
+        let envelope = resample(&enveloped, 2, DownsampleStrategy::MinMax).unwrap().left_envelope.unwrap();
+        assert_eq!(envelope.low.len(), 4);
+        assert_eq!(envelope.high[0], enveloped.left_envelope.as_ref().unwrap().high[3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn resample_points_combines_buckets_per_strategy() {
+        let audio_data = AudioData {
+            samples: (0..64).map(|i| ((i * 2749) % 4001 - 2000) as i16).collect(),
+            channels: 1,
+            sample_rate: 16,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let strategies = [DownsampleStrategy::Peak, DownsampleStrategy::Rms, DownsampleStrategy::Average, DownsampleStrategy::MinMax];
+        for normalization in [NormalizationMode::MinusOneToOne, NormalizationMode::ZeroToOne] {
+            for strategy in strategies {
+                let rated = |rate| RatedAudioData::new(&audio_data, rate).with_mode(strategy).with_normalization(normalization);
+                let fine = StereoAudioPresentation::try_from(&rated(8)).unwrap();
+                let coarse = StereoAudioPresentation::try_from(&rated(2)).unwrap();
+                let resampled = fine.resample_points(2, strategy, normalization).unwrap();
+                assert_eq!(resampled.left_channel_points.len(), coarse.left_channel_points.len());
+                let (envelope, expected) = (resampled.left_envelope.unwrap(), coarse.left_envelope.unwrap());
+                let pairs = resampled.left_channel_points.iter().zip(&coarse.left_channel_points);
+                for (resampled, coarse) in pairs.chain(envelope.low.iter().zip(&expected.low)).chain(envelope.high.iter().zip(&expected.high)) {
+                    assert!((resampled - coarse).abs() < 1e-6, "{strategy:?} {normalization:?}: {resampled} != {coarse}");
+                }
+            }
+        }
+
+        let percentile = DownsampleStrategy::Percentile { low: 10.0, high: 90.0 };
+        let fine = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 8).with_mode(percentile)).unwrap();
+        assert!(matches!(fine.resample_points(2, percentile, NormalizationMode::ZeroToOne), Err(BramError::InvalidParameter(_))));
+        let decibels = NormalizationMode::Decibels { floor_db: -60.0 };
+        let fine = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 8).with_mode(DownsampleStrategy::Rms).with_normalization(decibels)).unwrap();
+        assert!(matches!(fine.resample_points(2, DownsampleStrategy::Rms, decibels), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn annotate_markers_maps_frames_to_points() {
+        let mut presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.5; 10],
+            right_channel_points: vec![0.5; 10],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 1000,
+            source_channels: 2,
+            source_frames: 1000,
+            samples_per_point: 100,
+        };
+        presentation.annotate_markers(&[
+            Marker { id: 1, position: 250, length: 0, label: "hit".to_string(), note: None },
+            Marker { id: 2, position: 400, length: 250, label: "verse".to_string(), note: None },
+        ]);
+        assert_eq!(presentation.annotations[0], Annotation::marker(2, 3, "hit"));
+        assert_eq!(presentation.annotations[1], Annotation::marker(4, 7, "verse"));
+        assert_eq!(presentation.annotations[1].kind, AnnotationKind::Marker);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn zero_presentation_rate_is_rejected() {
+        let audio_data = AudioData { samples: vec![0, 1, 2, 3], channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let result = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 0));
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+        let result = MultiChannelAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 0));
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+        let result = MonoAudioPresentation::new(&RatedAudioData::new(&audio_data, 8), Downmix::Average);
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn presentation_reports_source_info_and_point_rate() {
+        let audio_data = AudioData { samples: vec![0i16; 300], channels: 2, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        let presentation = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 4)).unwrap();
+        assert_eq!((presentation.source_sample_rate, presentation.source_channels), (100, 2));
+        assert_eq!(presentation.duration(), std::time::Duration::from_millis(1500));
+        assert_eq!(presentation.points_per_second(), 4.0);
+
+        let mono = AudioData { samples: vec![0i16; 4], channels: 1, ..audio_data };
+        let interpolated = StereoAudioPresentation::try_from(&RatedAudioData::new(&AudioData { sample_rate: 4, ..mono }, 8)).unwrap();
+        assert_eq!(interpolated.source_channels, 1);
+        assert_eq!(interpolated.points_per_second(), 8.0);
+    }
+
+    #[test]
+    fn time_at_follows_the_decimation_factor() {
+        let audio_data = AudioData { samples: (0..10).collect(), channels: 1, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let presentation = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 2)).unwrap();
+        assert_eq!(presentation.time_at(3), std::time::Duration::from_millis(1500));
+        let timed: Vec<_> = presentation.timed_points().collect();
+        assert_eq!(timed.len(), 5);
+        assert_eq!(timed[1], (std::time::Duration::from_millis(500), presentation.left_channel_points[1], presentation.right_channel_points[1]));
+
+        let interpolated = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 8)).unwrap();
+        assert_eq!(interpolated.time_at(3), std::time::Duration::from_millis(375));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rates_above_source_rate_are_interpolated() {
+        let audio_data = AudioData { samples: vec![0, 1000, 2000, 1000], channels: 1, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let rated = RatedAudioData::new(&audio_data, 8).with_normalization(NormalizationMode::MinusOneToOne);
+        let linear = StereoAudioPresentation::try_from(&rated).unwrap();
+        let expected: Vec<f32> = [0, 500, 1000, 1500, 2000, 1500, 1000, 1000].iter().map(|value| *value as f32 / 32768.0).collect();
+        assert_eq!(linear.left_channel_points, expected);
+        assert_eq!(linear.right_channel_points, expected);
+        assert_eq!((linear.source_frames, linear.samples_per_point), (4, 1));
+        assert!(linear.left_envelope.is_none());
+
+        let cubic = StereoAudioPresentation::try_from(&rated.with_interpolation(Interpolation::Cubic)).unwrap();
+        assert_eq!(cubic.left_channel_points.len(), 8);
+        assert_eq!(cubic.left_channel_points[1], 437.5 / 32768.0);
+        for (index, sample) in audio_data.samples.iter().enumerate() {
+            assert_eq!(cubic.left_channel_points[index * 2], *sample as f32 / 32768.0);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_presentation_matches_sequential_accumulation() {
+        let audio_data = AudioData {
+            samples: (0..300_007u32).map(|i| ((i * 7919) % 65536) as i16).collect(),
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        for mode in [DownsampleStrategy::Decimate, DownsampleStrategy::MinMax, DownsampleStrategy::Percentile { low: 10.0, high: 90.0 }] {
+            let rated_audio_data = RatedAudioData::new(&audio_data, 100).with_mode(mode);
+            let parallel = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+            let mut accumulator = PresentationAccumulator::new(1, 44100, 100, mode, Weighting::None, NormalizationMode::ZeroToOne).unwrap();
+            for block in audio_data.samples.chunks(1000) {
+                accumulator.push(block);
+            }
+            let sequential = accumulator.finish();
+            assert_eq!(parallel.left_channel_points, sequential.left_channel_points);
+            assert_eq!(parallel.left_envelope, sequential.left_envelope);
+            assert_eq!(parallel.source_frames, sequential.source_frames);
+
+            let mut batched = PresentationAccumulator::new(1, 44100, 100, mode, Weighting::None, NormalizationMode::ZeroToOne).unwrap();
+            batched.push(&audio_data.samples[..333]);
+            batched.push(&audio_data.samples[333..]);
+            assert_eq!(batched.finish().left_channel_points, sequential.left_channel_points);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn streamed_file_presentation_is_batched_in_parallel() {
+        let audio_data = AudioData {
+            samples: (0..2_200_003u32).map(|i| (i.wrapping_mul(7919) % 65536) as u16 as i16).collect(),
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let mode = DownsampleStrategy::Peak;
+        let bytes = crate::wav_writer::encode_wav(&audio_data);
+        let streamed = crate::presentation_from_stream(bytes.as_slice(), 100, mode, Weighting::None, NormalizationMode::ZeroToOne).unwrap();
+        let mut accumulator = PresentationAccumulator::new(1, 44100, 100, mode, Weighting::None, NormalizationMode::ZeroToOne).unwrap();
+        for block in audio_data.samples.chunks(1000) {
+            accumulator.push(block);
+        }
+        let sequential = accumulator.finish();
+        assert_eq!(streamed.left_channel_points, sequential.left_channel_points);
+        assert_eq!(streamed.left_envelope, sequential.left_envelope);
+        assert_eq!(streamed.source_frames, 2_200_003);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_presentation_rejects_zero_rate() {
+        let audio_data = AudioData { samples: vec![0i16; 1 << 17], channels: 1, sample_rate: 44100, bits_per_sample: 16, channel_mask: None };
+        let result = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio_data, 0));
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn peak_and_average_strategies_aggregate_buckets() {
+        let audio_data = AudioData {
+            samples: vec![100, -300, 200, 400],
+            channels: 1,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let points = |strategy| {
+            let rated_audio_data = RatedAudioData::new(&audio_data, 2).with_mode(strategy);
+            StereoAudioPresentation::try_from(&rated_audio_data).unwrap().left_channel_points
+        };
+        assert_eq!(points(DownsampleStrategy::Peak), vec![zero_one(300.0), zero_one(400.0)]);
+        assert_eq!(points(DownsampleStrategy::Average), vec![zero_one(-100.0), zero_one(300.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalization_modes_rescale_points() {
+        let audio_data = AudioData {
+            samples: vec![0, -16384, 32767, 3277],
+            channels: 1,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let points = |normalization| {
+            let rated_audio_data = RatedAudioData::new(&audio_data, 4).with_normalization(normalization);
+            StereoAudioPresentation::try_from(&rated_audio_data).unwrap().left_channel_points
+        };
+        assert_eq!(points(NormalizationMode::MinusOneToOne), vec![0.0, -0.5, 32767.0 / 32768.0, 3277.0 / 32768.0]);
+        let decibels = points(NormalizationMode::Decibels { floor_db: -40.0 });
+        assert_eq!(decibels[0], 0.0);
+        assert!((decibels[1] - (1.0 - 6.0206 / 40.0)).abs() < 1e-3);
+        assert!((decibels[2] - 1.0).abs() < 1e-3);
+        assert!((decibels[3] - 0.5).abs() < 1e-3);
+
+        let linear = points(NormalizationMode::Linear { center: 0.0, scale: 2.0 });
+        assert_eq!(linear[0], 0.0);
+        assert_eq!(linear[1], -1.0);
+
+        let rated_audio_data = RatedAudioData::new(&audio_data, 4)
+            .with_mode(DownsampleStrategy::MinMax)
+            .with_normalization(NormalizationMode::Linear { center: 10.0, scale: 1.0 });
+        let envelope = StereoAudioPresentation::try_from(&rated_audio_data).unwrap().left_envelope.unwrap();
+        assert_eq!(envelope.low, vec![10.0, 9.5, 10.0 + 32767.0 / 32768.0, 10.0 + 3277.0 / 32768.0]);
+        let multichannel = MultiChannelAudioPresentation::try_from(&rated_audio_data).unwrap();
+        assert_eq!(multichannel.channels[0][1], 9.5);
+
+        let rated_audio_data = RatedAudioData::new(&audio_data, 4).with_normalization(NormalizationMode::Decibels { floor_db: 0.0 });
+        assert!(matches!(StereoAudioPresentation::try_from(&rated_audio_data), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_point_count_returns_exactly_requested_points() {
+        let audio_data = AudioData {
+            samples: (0..10).map(|i| i * 1000).collect(),
+            channels: 1,
+            sample_rate: 10,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        for count in [1, 3, 6, 10, 25] {
+            let presentation = StereoAudioPresentation::with_point_count(&audio_data, count).unwrap();
+            assert_eq!(presentation.left_channel_points.len(), count);
+            assert_eq!(presentation.right_channel_points.len(), count);
+        }
+        let presentation = StereoAudioPresentation::with_point_count(&audio_data, 4).unwrap();
+        let expected: Vec<f32> = [0, 2000, 5000, 7000].iter().map(|s| zero_one(*s as f32)).collect();
+        assert_eq!(presentation.left_channel_points, expected);
+        assert!(matches!(StereoAudioPresentation::with_point_count(&audio_data, 0), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mono_presentation_applies_downmix() {
+        let audio_data = AudioData {
+            samples: vec![1000, 3000, -2000, 2000],
+            channels: 2,
+            sample_rate: 2,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 2).with_normalization(NormalizationMode::MinusOneToOne);
+        let points = |downmix| MonoAudioPresentation::new(&rated_audio_data, downmix).unwrap().points;
+        assert_eq!(points(Downmix::Average), vec![2000.0 / 32768.0, 0.0]);
+        assert_eq!(points(Downmix::Left), vec![1000.0 / 32768.0, -2000.0 / 32768.0]);
+        assert_eq!(points(Downmix::Right), vec![3000.0 / 32768.0, 2000.0 / 32768.0]);
+        assert!((points(Downmix::EnergyPreserving)[0] - 2000.0 * std::f32::consts::SQRT_2 / 32768.0).abs() < 1e-6);
+        let presentation = MonoAudioPresentation::new(&rated_audio_data, Downmix::Average).unwrap();
+        assert_eq!(presentation.source_frames, 2);
+        assert!(presentation.envelope.is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn percentile_mode_extremes_match_min_max() {
+        let audio_data = AudioData {
+            samples: vec![0, 100, -100, 200, 50, -300, 10, 20],
+            channels: 2,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 1)
+            .with_mode(DownsampleStrategy::Percentile { low: 0.0, high: 100.0 });
+        let presentation = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+        let left = presentation.left_envelope.unwrap();
+        let right = presentation.right_envelope.unwrap();
+        assert_eq!(left.low, vec![zero_one(-100.0)]);
+        assert_eq!(left.high, vec![zero_one(50.0)]);
+        assert_eq!(right.low, vec![zero_one(-300.0)]);
+        assert_eq!(right.high, vec![zero_one(200.0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn percentile_mode_rejects_invalid_range() {
+        let audio_data = AudioData {
+            samples: vec![0, 0],
+            channels: 1,
+            sample_rate: 2,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 1)
+            .with_mode(DownsampleStrategy::Percentile { low: 90.0, high: 10.0 });
+        let result = StereoAudioPresentation::try_from(&rated_audio_data);
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn multichannel_presentation_keeps_every_channel() {
+        let audio_data = AudioData {
+            samples: vec![0, 32767, -32768, 0, 0, 0, 16384, 0, 0, 0, 0, 0],
+            channels: 6,
+            sample_rate: 2,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData::new(&audio_data, 1);
+        let presentation = MultiChannelAudioPresentation::try_from(&rated_audio_data).unwrap();
+        assert_eq!(presentation.channels.len(), 6);
+        assert_eq!(presentation.labels, vec!["FL", "FR", "FC", "LFE", "BL", "BR"]);
+        assert_eq!(presentation.channels[1], vec![zero_one(32767.0)]);
+        assert_eq!(presentation.channels[2], vec![0.0]);
+        assert!(presentation.channels.iter().all(|points| points.len() == 1));
+    }
+
+    #[test]
+    fn multichannel_presentation_applies_downsample_strategy() {
+        let audio_data = AudioData {
+            samples: vec![0, 0, 0, 16384, 8192, 0, -32768, -8192, 0, 0, 4096, 0],
+            channels: 3,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let rated_audio_data = RatedAudioData {
+            mode: DownsampleStrategy::Peak,
+            normalization: NormalizationMode::MinusOneToOne,
+            ..RatedAudioData::new(&audio_data, 1)
+        };
+        let presentation = MultiChannelAudioPresentation::try_from(&rated_audio_data).unwrap();
+        assert_eq!(presentation.channels, vec![vec![1.0], vec![0.25], vec![0.0]]);
+    }
+
+    #[test]
+    fn channel_labels_follow_mask_then_fall_back_to_numbers() {
+        assert_eq!(channel_labels(2, Some(0x3)), vec!["FL", "FR"]);
+        assert_eq!(channel_labels(3, Some(0x600)), vec!["SL", "SR", "Ch3"]);
+        assert_eq!(channel_labels(1, None), vec!["M"]);
+        assert_eq!(channel_labels(3, None), vec!["Ch1", "Ch2", "Ch3"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_frame_gaps_interpolates_and_annotates() {
+        let mut presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.0, 9.0, 9.0, 0.75, 1.0],
+            right_channel_points: vec![0.2, 0.2, 0.2, 0.2, 0.2],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 20,
+            samples_per_point: 4,
+        };
+        presentation.fill_frame_gaps(&[4..10, 20..30]);
+        assert_eq!(presentation.left_channel_points, vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(presentation.right_channel_points.len(), 8);
+        assert_eq!(presentation.annotations.len(), 2);
+        assert_eq!(presentation.annotations[0].kind, AnnotationKind::Synthetic);
+        assert_eq!((presentation.annotations[0].start_point, presentation.annotations[0].end_point), (1, 3));
+        assert!(presentation.annotations[1].contains(7));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fill_frame_gaps_scales_frames_of_interpolated_presentations() {
+        let mut presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.0, 0.25, 0.5, 0.75],
+            right_channel_points: vec![0.2, 0.2, 0.2, 0.2],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 2,
+            source_channels: 2,
+            source_frames: 2,
+            samples_per_point: 1,
+        };
+        presentation.fill_frame_gaps(&[2..3, 3..4]);
+        assert_eq!(presentation.left_channel_points.len(), 8);
+        assert_eq!((presentation.annotations[0].start_point, presentation.annotations[0].end_point), (4, 6));
+        assert_eq!((presentation.annotations[1].start_point, presentation.annotations[1].end_point), (6, 8));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn integer_presentation_scales_to_full_byte_range() {
+        let audio_data = AudioData {
+            samples: vec![-32768, 32767, 0, 0, 32767, -32768, 255, -256],
+            channels: 2,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let presentation = IntegerAudioPresentation::from_audio(&audio_data, 2).unwrap();
+        assert_eq!(presentation.left_channel_points, vec![0, 255]);
+        assert_eq!(presentation.right_channel_points, vec![255, 0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn integer_presentation_uses_raw_sample_bits() {
+        let audio_data = AudioData {
+            samples: vec![-1, 0, 255, 256, -32513, 32511],
+            channels: 1,
+            sample_rate: 6,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        let presentation = IntegerAudioPresentation::from_audio(&audio_data, 6).unwrap();
+        assert_eq!(presentation.left_channel_points, vec![127, 128, 128, 129, 0, 254]);
+        assert_eq!(presentation.left_channel_points, presentation.right_channel_points);
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn integer_presentation_rejects_invalid_input() {
+        let audio_data = AudioData {
+            samples: vec![0; 6],
+            channels: 3,
+            sample_rate: 2,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        assert!(matches!(IntegerAudioPresentation::from_audio(&audio_data, 1), Err(BramError::UnsupportedChannels { channels: 3 })));
+        let mono = AudioData { channels: 1, ..audio_data };
+        assert!(matches!(IntegerAudioPresentation::from_audio(&mono, 0), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn runs_coalesce_equal_quantized_points() {
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.5, 0.501, 0.499, 1.0, 0.5, 0.5],
+            right_channel_points: vec![0.5, 0.5, 0.5, 0.0, 0.5, 0.6],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        };
+        let runs: Vec<Run> = presentation.runs(9).collect();
+        assert_eq!(runs, vec![
+            Run { start: 0, length: 3, left_level: 4, right_level: 4 },
+            Run { start: 3, length: 1, left_level: 8, right_level: 0 },
+            Run { start: 4, length: 1, left_level: 4, right_level: 4 },
+            Run { start: 5, length: 1, left_level: 4, right_level: 5 },
+        ]);
+        assert_eq!(runs.iter().map(|run| run.length).sum::<usize>(), 6);
+    }
+
+    #[test]
+    fn accumulator_gives_same_result_for_any_block_split() {
+        let samples: Vec<i16> = (0..200).map(|i| ((i * 7919) % 2000 - 1000) as i16).collect();
+        let mode = DownsampleStrategy::Percentile { low: 10.0, high: 90.0 };
+        let mut whole = PresentationAccumulator::new(2, 30, 4, mode, Weighting::K, NormalizationMode::ZeroToOne).unwrap();
+        whole.push(&samples);
+        let whole = whole.finish();
+
+        let mut blocks = PresentationAccumulator::new(2, 30, 4, mode, Weighting::K, NormalizationMode::ZeroToOne).unwrap();
+        for block in samples.chunks(14) {
+            blocks.push(block);
+        }
+        let blocks = blocks.finish();
+        assert_eq!(blocks.left_channel_points, whole.left_channel_points);
+        assert_eq!(blocks.right_envelope, whole.right_envelope);
+        assert_eq!(whole.left_channel_points.len(), 15);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn presentation_and_audio_data_round_trip_through_serde() {
+        let audio_data = AudioData {
+            samples: vec![0, 0, 32767, -32768, -32768, 32767, 16384, -16384],
+            channels: 2,
+            sample_rate: 10,
+            bits_per_sample: 16,
+            channel_mask: Some(3),
+        };
+        let json = serde_json::to_string(&audio_data).unwrap();
+        let decoded: AudioData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.samples, audio_data.samples);
+        assert_eq!(decoded.channel_mask, Some(3));
+
+        let rated_audio_data = RatedAudioData {audio_data: audio_data.convert(), sample_rate: 5, mode: DownsampleStrategy::Decimate, weighting: Weighting::None, normalization: NormalizationMode::ZeroToOne, interpolation: Interpolation::Linear};
+        let presentation = StereoAudioPresentation::try_from(&rated_audio_data).unwrap();
+        let json = serde_json::to_string(&presentation).unwrap();
+        let decoded: StereoAudioPresentation = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.left_channel_points, presentation.left_channel_points);
+        assert_eq!(decoded.source_sample_rate, 10);
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+
+pub fn load_presentations<P: AsRef<Path> + Sync>(paths: &[P], rate: u32) -> Vec<Result<StereoAudioPresentation, BramError>> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        paths.par_iter().map(|path| crate::load_presentation(path, rate)).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+        let workers = thread::available_parallelism().map_or(1, |workers| workers.get()).min(paths.len());
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut loaded = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(path) = paths.get(index) else {
+                                return loaded;
+                            };
+                            loaded.push((index, crate::load_presentation(path, rate)));
+                        }
+                    })
+                })
+                .collect();
+            handles.into_iter().flat_map(|handle| handle.join().expect("presentation worker panicked")).collect()
+        });
+        results.sort_unstable_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::batch::load_presentations;
+    use crate::error::BramError;
+    use crate::wav_writer::write_wav;
+
+    #[test]
+    fn results_follow_input_order() {
+        let paths: Vec<String> = (0..6)
+            .map(|index| {
+                let path = std::env::temp_dir().join(format!("bram_batch_{index}.wav"));
+                let audio = AudioData { samples: vec![index as i16 * 1000; 8], channels: 1, sample_rate: 8, bits_per_sample: 16, channel_mask: None };
+                write_wav(&audio, &path).unwrap();
+                path.to_str().unwrap().to_string()
+            })
+            .collect();
+        let mut inputs: Vec<&str> = paths.iter().map(String::as_str).collect();
+        inputs.insert(2, "missing_batch_input.wav");
+
+        let results = load_presentations(&inputs, 4);
+        assert_eq!(results.len(), 7);
+        assert!(matches!(results[2], Err(BramError::Io(_))));
+        for (path, result) in inputs.iter().zip(&results).filter(|(path, _)| !path.starts_with("missing")) {
+            assert_eq!(result.as_ref().unwrap().left_channel_points, crate::load_presentation(path, 4).unwrap().left_channel_points);
+        }
+        assert!(load_presentations::<&str>(&[], 4).is_empty());
+        for path in paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
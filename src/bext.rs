@@ -0,0 +1,168 @@
+use std::time::Duration;
+use crate::error::BramError;
+use crate::wav_binary::WavBinary;
+
+const TIME_REFERENCE_OFFSET: usize = 338;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+pub(crate) fn read_time_reference(bext: &[u8]) -> Option<u64> {
+    let bytes = bext.get(TIME_REFERENCE_OFFSET..TIME_REFERENCE_OFFSET + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+pub(crate) fn split_at_timecodes(wav: &WavBinary, timecodes: &[Duration]) -> Result<Vec<Vec<u8>>, BramError> {
+    let chunks = wav.chunk_list();
+    let payload = |id: &[u8; 4]| chunks
+        .iter()
+        .find(|(chunk_id, _, _)| chunk_id == id)
+        .map(|(_, offset, size)| &wav.data[*offset..*offset + *size]);
+
+    let fmt = payload(b"fmt ")
+        .filter(|fmt| fmt.len() >= 14)
+        .ok_or(BramError::MissingChunk { id: *b"fmt " })?;
+    let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]) as u64;
+    let block_align = u16::from_le_bytes([fmt[12], fmt[13]]) as usize;
+    if sample_rate == 0 || block_align == 0 {
+        return Err(BramError::Decode("invalid format chunk".to_string()));
+    }
+    let start = payload(b"bext")
+        .and_then(read_time_reference)
+        .ok_or(BramError::MissingChunk { id: *b"bext" })?;
+    let audio = payload(b"data")
+        .ok_or(BramError::MissingChunk { id: *b"data" })?;
+    let total_frames = (audio.len() / block_align) as u64;
+
+    let day = SECONDS_PER_DAY * sample_rate;
+    let mut cuts: Vec<u64> = timecodes
+        .iter()
+        .map(|timecode| (timecode.as_nanos() * sample_rate as u128 / 1_000_000_000) as u64)
+        .map(|absolute| (absolute % day + day - start % day) % day)
+        .filter(|&relative| relative > 0 && relative < total_frames)
+        .collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+
+    let mut boundaries = vec![0];
+    boundaries.extend(cuts);
+    boundaries.push(total_frames);
+
+    Ok(boundaries
+        .windows(2)
+        .map(|part| {
+            let (first, last) = (part[0] as usize * block_align, part[1] as usize * block_align);
+            let mut body = Vec::new();
+            for (id, offset, size) in &chunks {
+                let mut chunk = wav.data[*offset..*offset + *size].to_vec();
+                match id {
+                    b"data" => chunk = audio[first..last].to_vec(),
+                    b"bext" if chunk.len() >= TIME_REFERENCE_OFFSET + 8 => {
+                        let reference = (start + part[0]) % day;
+                        chunk[TIME_REFERENCE_OFFSET..TIME_REFERENCE_OFFSET + 8]
+                            .copy_from_slice(&reference.to_le_bytes());
+                    }
+                    _ => {}
+                }
+                body.extend_from_slice(id);
+                body.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+                body.extend_from_slice(&chunk);
+                if chunk.len() % 2 == 1 {
+                    body.push(0);
+                }
+            }
+            let mut file = Vec::with_capacity(body.len() + 12);
+            file.extend_from_slice(b"RIFF");
+            file.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+            file.extend_from_slice(b"WAVE");
+            file.extend_from_slice(&body);
+            file
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod bext_tests {
+    use std::time::Duration;
+    use crate::bext::{read_time_reference, split_at_timecodes};
+    use crate::wav_binary::WavBinary;
+
+    fn wav_with_bext(time_reference: u64, frames: u16) -> WavBinary {
+        let mut bext = vec![0u8; 602];
+        bext[338..346].copy_from_slice(&time_reference.to_le_bytes());
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x00, 0x01, 0x00]);
+        data.extend_from_slice(&10u32.to_le_bytes());
+        data.extend_from_slice(&20u32.to_le_bytes());
+        data.extend_from_slice(&[0x02, 0x00, 0x10, 0x00]);
+        data.extend_from_slice(b"bext");
+        data.extend_from_slice(&(bext.len() as u32).to_le_bytes());
+        data.extend_from_slice(&bext);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&(frames as u32 * 2).to_le_bytes());
+        for frame in 0..frames {
+            data.extend_from_slice(&(frame as i16).to_le_bytes());
+        }
+        WavBinary { data }
+    }
+
+    #[test]
+    fn read_time_reference_works() {
+        let mut bext = vec![0u8; 602];
+        bext[338..346].copy_from_slice(&123456u64.to_le_bytes());
+        assert_eq!(read_time_reference(&bext), Some(123456));
+    }
+
+    #[test]
+    fn read_time_reference_returns_none_if_too_short() {
+        assert_eq!(read_time_reference(&[0u8; 100]), None);
+    }
+
+    #[test]
+    fn split_at_timecodes_cuts_parts_and_updates_time_reference() {
+        let wav = wav_with_bext(1000, 100);
+        let parts = split_at_timecodes(&wav, &[Duration::from_secs(103)]).unwrap();
+        assert_eq!(parts.len(), 2);
+
+        let first = WavBinary { data: parts[0].clone() };
+        let second = WavBinary { data: parts[1].clone() };
+        assert!(first.check());
+        let chunk = |wav: &WavBinary, id: &[u8; 4]| wav.chunk_list().into_iter().find(|c| &c.0 == id).unwrap();
+        assert_eq!(chunk(&first, b"data").2, 60);
+        assert_eq!(chunk(&second, b"data").2, 140);
+
+        let (_, offset, size) = chunk(&second, b"bext");
+        assert_eq!(read_time_reference(&second.data[offset..offset + size]), Some(1030));
+        assert_eq!(&second.data[chunk(&second, b"data").1..][..2], &30i16.to_le_bytes());
+    }
+
+    #[test]
+    fn split_at_timecodes_handles_midnight_crossing() {
+        let day = 86_400 * 10;
+        let wav = wav_with_bext(day - 50, 100);
+        let parts = split_at_timecodes(&wav, &[Duration::from_secs(0)]).unwrap();
+        assert_eq!(parts.len(), 2);
+
+        let second = WavBinary { data: parts[1].clone() };
+        let (_, offset, size) = second.chunk_list().into_iter().find(|c| &c.0 == b"bext").unwrap();
+        assert_eq!(read_time_reference(&second.data[offset..offset + size]), Some(0));
+    }
+
+    #[test]
+    fn split_at_timecodes_ignores_cuts_outside_recording() {
+        let wav = wav_with_bext(1000, 100);
+        let parts = split_at_timecodes(&wav, &[Duration::from_secs(50), Duration::from_secs(500)]).unwrap();
+        assert_eq!(parts.len(), 1);
+    }
+
+    #[test]
+    fn split_at_timecodes_fails_without_bext() {
+        let mut wav = wav_with_bext(0, 10);
+        let pos = wav.data.windows(4).position(|w| w == b"bext").unwrap();
+        wav.data[pos..pos + 4].copy_from_slice(b"junk");
+        assert!(split_at_timecodes(&wav, &[Duration::from_secs(1)]).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use std::env;
+use std::process::ExitCode;
+use bram_audio_parser::{load_presentation, render_braille};
+
+const POINTS_PER_SECOND: u32 = 100;
+const DEFAULT_WIDTH: usize = 80;
+const ROWS: usize = 4;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (path, width) = match args.as_slice() {
+        [command, path] if command == "show" => (path, DEFAULT_WIDTH),
+        [command, path, flag, width] if command == "show" && flag == "--width" => match width.parse() {
+            Ok(width) => (path, width),
+            Err(_) => return usage(),
+        },
+        _ => return usage(),
+    };
+    match load_presentation(path, POINTS_PER_SECOND) {
+        Ok(presentation) => {
+            println!("{}", render_braille(&presentation, width, ROWS));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("usage: bram-waveform show <file> [--width <columns>]");
+    ExitCode::from(2)
+}
@@ -0,0 +1,135 @@
+use std::env;
+use std::io;
+use std::process::ExitCode;
+use bram_audio_parser::commands;
+use bram_audio_parser::{BramError, SvgOptions};
+
+const DEFAULT_RATE: u32 = 100;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Format {
+    Json,
+    Csv,
+    Svg,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.as_slice() {
+        [command, path] if command == "probe" => run_probe(path),
+        [command, path] if command == "stats" => run_stats(path),
+        [command, path, options @ ..] if command == "waveform" => match waveform_options(options) {
+            Some((rate, format)) => run_waveform(path, rate, format),
+            None => return usage(),
+        },
+        _ => return usage(),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}: {}", args[1], error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn waveform_options(options: &[String]) -> Option<(u32, Format)> {
+    let mut rate = DEFAULT_RATE;
+    let mut format = Format::Json;
+    for pair in options.chunks(2) {
+        match pair {
+            [flag, value] if flag == "--rate" => rate = value.parse().ok()?,
+            [flag, value] if flag == "--format" => {
+                format = match value.as_str() {
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    "svg" => Format::Svg,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some((rate, format))
+}
+
+fn run_probe(path: &str) -> Result<(), BramError> {
+    let report = commands::inspect(path)?;
+    println!("channels: {}", report.channels);
+    println!("sample rate: {} Hz", report.sample_rate);
+    println!("bits per sample: {}", report.bits_per_sample);
+    println!("frames: {}", report.frames);
+    println!("duration: {:.3} s", report.duration.as_secs_f64());
+    for chunk in &report.chunks {
+        println!("chunk '{}' at {}: {} bytes", chunk.id, chunk.offset, chunk.size);
+    }
+    for warning in &report.warnings {
+        println!("warning: {:?}", warning);
+    }
+    Ok(())
+}
+
+fn run_waveform(path: &str, rate: u32, format: Format) -> Result<(), BramError> {
+    let presentation = commands::peaks(path, rate)?.presentation;
+    match format {
+        Format::Json => println!("{}", presentation.to_json()),
+        Format::Csv => presentation.export_csv(io::stdout().lock())?,
+        Format::Svg => println!("{}", presentation.render_svg(&SvgOptions::default())),
+    }
+    Ok(())
+}
+
+fn run_stats(path: &str) -> Result<(), BramError> {
+    let stats = commands::stats(path)?.stats;
+    let db = |value: f64| 20.0 * value.log10();
+    println!("peak: {:.2} dBFS", db(stats.peak));
+    println!("rms: {:.2} dBFS", db(stats.rms));
+    println!("crest factor: {:.2}", stats.crest_factor);
+    println!("zero crossing rate: {:.4}", stats.zero_crossing_rate);
+    for (channel, channel_stats) in stats.channels.iter().enumerate() {
+        println!(
+            "channel {}: peak {:.2} dBFS, rms {:.2} dBFS, crest {:.2}, zcr {:.4}",
+            channel,
+            db(channel_stats.peak),
+            db(channel_stats.rms),
+            channel_stats.crest_factor,
+            channel_stats.zero_crossing_rate,
+        );
+    }
+    Ok(())
+}
+
+fn usage() -> ExitCode {
+    eprintln!("usage: bram probe <file>");
+    eprintln!("       bram waveform <file> [--rate <points per second>] [--format json|csv|svg]");
+    eprintln!("       bram stats <file>");
+    ExitCode::from(2)
+}
+
+#[cfg(test)]
+mod bram_tests {
+    use crate::{waveform_options, Format, DEFAULT_RATE};
+
+    fn options(args: &[&str]) -> Option<(u32, Format)> {
+        waveform_options(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn waveform_options_default_to_json_at_default_rate() {
+        assert_eq!(options(&[]), Some((DEFAULT_RATE, Format::Json)));
+    }
+
+    #[test]
+    fn waveform_options_parse_rate_and_format_in_any_order() {
+        assert_eq!(options(&["--rate", "20", "--format", "csv"]), Some((20, Format::Csv)));
+        assert_eq!(options(&["--format", "svg", "--rate", "5"]), Some((5, Format::Svg)));
+    }
+
+    #[test]
+    fn waveform_options_reject_invalid_input() {
+        assert_eq!(options(&["--rate", "fast"]), None);
+        assert_eq!(options(&["--format", "png"]), None);
+        assert_eq!(options(&["--rate"]), None);
+        assert_eq!(options(&["--verbose", "1"]), None);
+    }
+}
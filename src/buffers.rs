@@ -0,0 +1,192 @@
+use std::fs::File;
+use std::io::Read;
+use std::mem;
+use std::path::Path;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::{DownsampleStrategy, NormalizationMode, PresentationAccumulator, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::sample::Sample;
+use crate::wav_binary::{self, HeaderDiagnosis, WavBinary};
+use crate::weighting::Weighting;
+
+#[derive(Debug, Clone)]
+pub struct DecodeBuffer<S: Sample = i16> {
+    bytes: Vec<u8>,
+    audio: AudioData<S>,
+}
+
+impl<S: Sample> Default for DecodeBuffer<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Sample> DecodeBuffer<S> {
+    pub fn new() -> Self {
+        DecodeBuffer {
+            bytes: Vec::new(),
+            audio: AudioData { samples: Vec::new(), channels: 0, sample_rate: 0, bits_per_sample: 0, channel_mask: None },
+        }
+    }
+
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<&AudioData<S>, BramError> {
+        self.clear();
+        if let Err(error) = self.decode(path.as_ref()) {
+            self.clear();
+            return Err(error);
+        }
+        Ok(&self.audio)
+    }
+
+    fn decode(&mut self, path: &Path) -> Result<(), BramError> {
+        File::open(path)?.read_to_end(&mut self.bytes)?;
+        let diagnosis = wav_binary::diagnose(&self.bytes);
+        if diagnosis != HeaderDiagnosis::Valid {
+            return Err(BramError::Header(diagnosis));
+        }
+        let wav = WavBinary { data: mem::take(&mut self.bytes) };
+        if wav.is_big_endian() {
+            self.bytes = wav.to_little_endian().into_owned().data;
+        } else {
+            self.bytes = wav.data;
+        }
+        let format = AudioData::decode_wav_bytes_into(&self.bytes, &mut self.audio.samples)?;
+        self.audio.channels = format.channels;
+        self.audio.sample_rate = format.sample_rate;
+        self.audio.bits_per_sample = format.bits_per_sample;
+        self.audio.channel_mask = format.channel_mask;
+        Ok(())
+    }
+
+    pub fn audio(&self) -> &AudioData<S> {
+        &self.audio
+    }
+
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.audio.samples.clear();
+        self.audio.channels = 0;
+        self.audio.sample_rate = 0;
+        self.audio.bits_per_sample = 0;
+        self.audio.channel_mask = None;
+    }
+}
+
+#[derive(Debug)]
+pub struct PresentationBuffer {
+    presentation: StereoAudioPresentation,
+}
+
+impl Default for PresentationBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PresentationBuffer {
+    pub fn new() -> Self {
+        PresentationBuffer { presentation: empty_presentation() }
+    }
+
+    pub fn build<S: Sample>(&mut self, audio: &AudioData<S>, rate: u32, strategy: DownsampleStrategy) -> Result<&StereoAudioPresentation, BramError> {
+        let mut accumulator = PresentationAccumulator::new(
+            audio.channels,
+            audio.sample_rate,
+            rate,
+            strategy,
+            Weighting::None,
+            NormalizationMode::ZeroToOne,
+        )?;
+        accumulator.recycle(mem::replace(&mut self.presentation, empty_presentation()));
+        accumulator.push(&audio.samples);
+        self.presentation = accumulator.finish();
+        Ok(&self.presentation)
+    }
+
+    pub fn presentation(&self) -> &StereoAudioPresentation {
+        &self.presentation
+    }
+
+    pub fn clear(&mut self) {
+        let presentation = &mut self.presentation;
+        presentation.left_channel_points.clear();
+        presentation.right_channel_points.clear();
+        presentation.left_envelope = None;
+        presentation.right_envelope = None;
+        presentation.source_sample_rate = 0;
+        presentation.source_channels = 0;
+        presentation.source_frames = 0;
+        presentation.samples_per_point = 0;
+    }
+}
+
+fn empty_presentation() -> StereoAudioPresentation {
+    StereoAudioPresentation {
+        left_channel_points: Vec::new(),
+        right_channel_points: Vec::new(),
+        left_envelope: None,
+        right_envelope: None,
+        spectral_centroid: None,
+        annotations: Vec::new(),
+        source_sample_rate: 0,
+        source_channels: 0,
+        source_frames: 0,
+        samples_per_point: 0,
+    }
+}
+
+#[cfg(test)]
+mod buffers_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::DownsampleStrategy;
+    use crate::buffers::{DecodeBuffer, PresentationBuffer};
+    use crate::error::BramError;
+    use crate::load_presentation_with_mode;
+    use crate::wav_writer::write_wav;
+
+    fn write(name: &str, frames: usize, seed: usize) -> String {
+        let samples = (0..frames * 2).map(|index| ((index * seed) % 6000) as i16 - 3000).collect();
+        let audio = AudioData { samples, channels: 2, sample_rate: 1000, bits_per_sample: 16, channel_mask: None };
+        let path = std::env::temp_dir().join(name);
+        let path = path.to_str().unwrap().to_string();
+        write_wav(&audio, &path).unwrap();
+        path
+    }
+
+    #[test]
+    fn buffers_are_reused_across_files() {
+        let first = write("bram_buffers_first.wav", 4000, 37);
+        let second = write("bram_buffers_second.wav", 3000, 91);
+        let mut decode = DecodeBuffer::<i16>::new();
+        let mut presentation = PresentationBuffer::new();
+
+        let points = presentation.build(decode.load(&first).unwrap(), 20, DownsampleStrategy::Peak).unwrap().left_channel_points.as_ptr();
+        let samples = decode.audio().samples.as_ptr();
+
+        for path in [&second, &first] {
+            let audio = decode.load(path).unwrap();
+            assert_eq!(audio.samples.as_ptr(), samples);
+            let built = presentation.build(audio, 20, DownsampleStrategy::Peak).unwrap();
+            assert_eq!(built.left_channel_points.as_ptr(), points);
+            let expected = load_presentation_with_mode(path, 20, DownsampleStrategy::Peak).unwrap();
+            assert_eq!(built.left_channel_points, expected.left_channel_points);
+            assert_eq!(built.right_channel_points, expected.right_channel_points);
+            assert_eq!(built.left_envelope, expected.left_envelope);
+            assert_eq!(built.source_frames, expected.source_frames);
+        }
+        let _ = fs::remove_file(first);
+        let _ = fs::remove_file(second);
+    }
+
+    #[test]
+    fn failed_load_leaves_buffer_empty() {
+        let path = std::env::temp_dir().join("bram_buffers_invalid.wav");
+        fs::write(&path, b"RIFF\x04\x00\x00\x00WAVE").unwrap();
+        let mut decode = DecodeBuffer::<f32>::new();
+        assert!(matches!(decode.load(&path), Err(BramError::MissingChunk { .. })));
+        assert!(decode.audio().samples.is_empty());
+        assert!(matches!(decode.load("bram_buffers_missing.wav"), Err(BramError::Io(_))));
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::error::BramError;
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn check(&self) -> Result<(), BramError> {
+        if self.is_cancelled() {
+            return Err(BramError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+impl From<Arc<AtomicBool>> for CancellationToken {
+    fn from(cancelled: Arc<AtomicBool>) -> Self {
+        Self { cancelled }
+    }
+}
+
+#[cfg(test)]
+mod cancel_tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use crate::cancel::CancellationToken;
+    use crate::error::BramError;
+
+    #[test]
+    fn clones_share_cancellation_state() {
+        let token = CancellationToken::new();
+        let handle = token.clone();
+        assert!(token.check().is_ok());
+        handle.cancel();
+        assert!(token.is_cancelled());
+        assert!(matches!(token.check(), Err(BramError::Cancelled)));
+    }
+
+    #[test]
+    fn token_wraps_existing_atomic_bool() {
+        let flag = Arc::new(AtomicBool::new(false));
+        let token = CancellationToken::from(flag.clone());
+        flag.store(true, Ordering::Relaxed);
+        assert!(token.is_cancelled());
+    }
+}
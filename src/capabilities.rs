@@ -0,0 +1,74 @@
+use crate::decoder;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    pub version: &'static str,
+    pub features: Vec<&'static str>,
+    pub extensions: Vec<String>,
+}
+
+impl Capabilities {
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.contains(&feature)
+    }
+
+    pub fn supports_extension(&self, extension: &str) -> bool {
+        self.extensions.iter().any(|known| known.eq_ignore_ascii_case(extension))
+    }
+}
+
+pub fn capabilities() -> Capabilities {
+    let features = [
+        ("analysis", true),
+        ("adpcm", true),
+        ("aiff", true),
+        ("flac", cfg!(feature = "flac")),
+        ("mp3", cfg!(feature = "mp3")),
+        ("mmap", cfg!(feature = "mmap")),
+        ("ingest", cfg!(feature = "ingest")),
+        ("tokio", cfg!(feature = "tokio")),
+        ("http", cfg!(feature = "http")),
+        ("hound", cfg!(feature = "hound")),
+        ("tui", cfg!(feature = "tui")),
+        ("arrow", cfg!(feature = "arrow")),
+        ("serde", cfg!(feature = "serde")),
+        ("image", cfg!(feature = "image")),
+        ("plotters", cfg!(feature = "plotters")),
+        ("cpal", cfg!(feature = "cpal")),
+        ("pyo3", cfg!(feature = "pyo3")),
+        ("cli", cfg!(feature = "cli")),
+        ("experimental", cfg!(feature = "experimental")),
+    ];
+    let mut extensions: Vec<String> = ["wav", "aif", "aiff", "aifc"].iter().map(|ext| ext.to_string()).collect();
+    if cfg!(feature = "flac") {
+        extensions.push("flac".to_string());
+    }
+    if cfg!(feature = "mp3") {
+        extensions.push("mp3".to_string());
+    }
+    for extension in decoder::registered_extensions() {
+        if !extensions.iter().any(|known| known.eq_ignore_ascii_case(&extension)) {
+            extensions.push(extension);
+        }
+    }
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        features: features.iter().filter(|(_, enabled)| *enabled).map(|(name, _)| *name).collect(),
+        extensions,
+    }
+}
+
+#[cfg(test)]
+mod capabilities_tests {
+    use crate::capabilities::capabilities;
+
+    #[test]
+    fn capabilities_reflect_the_build() {
+        let capabilities = capabilities();
+        assert_eq!(capabilities.version, env!("CARGO_PKG_VERSION"));
+        assert!(capabilities.has_feature("analysis"));
+        assert_eq!(capabilities.has_feature("flac"), cfg!(feature = "flac"));
+        assert_eq!(capabilities.supports_extension("MP3"), cfg!(feature = "mp3"));
+        assert!(capabilities.supports_extension("WAV"));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::annotation::{Annotation, AnnotationKind};
+use crate::audio_presentation::StereoAudioPresentation;
+
+const SCHEMA_VERSION: u64 = 1;
+const ANNOTATIONS_VERSION: u64 = 1;
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const FLOAT_32: u8 = 0xfa;
+const FLOAT_64: u8 = 0xfb;
+
+impl StereoAudioPresentation {
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(64 + (self.left_channel_points.len() + self.right_channel_points.len()) * 5);
+        let fields = if self.annotations.is_empty() { 6 } else { 7 };
+        write_head(&mut out, MAJOR_MAP, fields);
+        write_text(&mut out, "version");
+        write_head(&mut out, MAJOR_UNSIGNED, SCHEMA_VERSION);
+        write_text(&mut out, "source_sample_rate");
+        write_head(&mut out, MAJOR_UNSIGNED, self.source_sample_rate as u64);
+        write_text(&mut out, "samples_per_point");
+        write_head(&mut out, MAJOR_UNSIGNED, self.samples_per_point as u64);
+        write_text(&mut out, "duration");
+        out.push(FLOAT_64);
+        out.extend_from_slice(&self.duration().as_secs_f64().to_be_bytes());
+        write_text(&mut out, "left");
+        write_points(&mut out, &self.left_channel_points);
+        write_text(&mut out, "right");
+        write_points(&mut out, &self.right_channel_points);
+        if !self.annotations.is_empty() {
+            write_text(&mut out, "annotations");
+            write_annotations(&mut out, &self.annotations);
+        }
+        out
+    }
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    match value {
+        0..=23 => out.push(major | value as u8),
+        24..=0xff => out.extend_from_slice(&[major | 24, value as u8]),
+        0x100..=0xffff => {
+            out.push(major | 25);
+            out.extend_from_slice(&(value as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(major | 26);
+            out.extend_from_slice(&(value as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(major | 27);
+            out.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+}
+
+fn write_text(out: &mut Vec<u8>, text: &str) {
+    write_head(out, MAJOR_TEXT, text.len() as u64);
+    out.extend_from_slice(text.as_bytes());
+}
+
+fn write_points(out: &mut Vec<u8>, points: &[f32]) {
+    write_head(out, MAJOR_ARRAY, points.len() as u64);
+    for point in points {
+        out.push(FLOAT_32);
+        out.extend_from_slice(&point.to_be_bytes());
+    }
+}
+
+fn write_annotations(out: &mut Vec<u8>, annotations: &[Annotation]) {
+    write_head(out, MAJOR_MAP, 2);
+    write_text(out, "version");
+    write_head(out, MAJOR_UNSIGNED, ANNOTATIONS_VERSION);
+    write_text(out, "items");
+    write_head(out, MAJOR_ARRAY, annotations.len() as u64);
+    for annotation in annotations {
+        write_head(out, MAJOR_MAP, 4);
+        write_text(out, "start_point");
+        write_head(out, MAJOR_UNSIGNED, annotation.start_point as u64);
+        write_text(out, "end_point");
+        write_head(out, MAJOR_UNSIGNED, annotation.end_point as u64);
+        write_text(out, "kind");
+        write_text(out, match annotation.kind {
+            AnnotationKind::Synthetic => "synthetic",
+            AnnotationKind::Boundary => "boundary",
+            AnnotationKind::Marker => "marker",
+        });
+        write_text(out, "label");
+        write_text(out, &annotation.label);
+    }
+}
+
+#[cfg(test)]
+mod cbor_tests {
+    use crate::annotation::Annotation;
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::cbor::write_head;
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.5],
+            right_channel_points: vec![1.0],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 8000,
+            source_channels: 2,
+            source_frames: 8000,
+            samples_per_point: 8000,
+        }
+    }
+
+    #[test]
+    fn write_head_uses_shortest_encoding() {
+        let encode = |value| {
+            let mut out = Vec::new();
+            write_head(&mut out, 0, value);
+            out
+        };
+        assert_eq!(encode(23), vec![0x17]);
+        assert_eq!(encode(24), vec![0x18, 24]);
+        assert_eq!(encode(1000), vec![0x19, 0x03, 0xe8]);
+        assert_eq!(encode(100_000), vec![0x1a, 0x00, 0x01, 0x86, 0xa0]);
+    }
+
+    #[test]
+    fn to_cbor_encodes_map_with_float_points() {
+        let bytes = presentation().to_cbor();
+        assert_eq!(bytes[0], 0xa6);
+        assert_eq!(&bytes[1..9], b"\x67version");
+        assert_eq!(bytes[9], 0x01);
+        assert!(bytes.ends_with(b"\x65right\x81\xfa\x3f\x80\x00\x00"));
+    }
+
+    #[test]
+    fn to_cbor_appends_versioned_annotations() {
+        let mut presentation = presentation();
+        presentation.annotations.push(Annotation::synthetic(0, 1, "gap"));
+        let bytes = presentation.to_cbor();
+        assert_eq!(bytes[0], 0xa7);
+        assert!(bytes.ends_with(b"\x64kind\x69synthetic\x65label\x63gap"));
+    }
+}
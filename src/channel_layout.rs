@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+use crate::audio_data::AudioData;
+use crate::sample::Sample;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Speaker {
+    FrontLeft,
+    FrontRight,
+    FrontCenter,
+    LowFrequency,
+    BackLeft,
+    BackRight,
+    FrontLeftOfCenter,
+    FrontRightOfCenter,
+    BackCenter,
+    SideLeft,
+    SideRight,
+    TopCenter,
+    TopFrontLeft,
+    TopFrontCenter,
+    TopFrontRight,
+    TopBackLeft,
+    TopBackCenter,
+    TopBackRight,
+}
+
+impl Speaker {
+    pub const ALL: [Speaker; 18] = [
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::LowFrequency,
+        Speaker::BackLeft,
+        Speaker::BackRight,
+        Speaker::FrontLeftOfCenter,
+        Speaker::FrontRightOfCenter,
+        Speaker::BackCenter,
+        Speaker::SideLeft,
+        Speaker::SideRight,
+        Speaker::TopCenter,
+        Speaker::TopFrontLeft,
+        Speaker::TopFrontCenter,
+        Speaker::TopFrontRight,
+        Speaker::TopBackLeft,
+        Speaker::TopBackCenter,
+        Speaker::TopBackRight,
+    ];
+
+    pub fn mask_bit(self) -> u32 {
+        1 << self as u32
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Speaker::FrontLeft => "FL",
+            Speaker::FrontRight => "FR",
+            Speaker::FrontCenter => "FC",
+            Speaker::LowFrequency => "LFE",
+            Speaker::BackLeft => "BL",
+            Speaker::BackRight => "BR",
+            Speaker::FrontLeftOfCenter => "FLC",
+            Speaker::FrontRightOfCenter => "FRC",
+            Speaker::BackCenter => "BC",
+            Speaker::SideLeft => "SL",
+            Speaker::SideRight => "SR",
+            Speaker::TopCenter => "TC",
+            Speaker::TopFrontLeft => "TFL",
+            Speaker::TopFrontCenter => "TFC",
+            Speaker::TopFrontRight => "TFR",
+            Speaker::TopBackLeft => "TBL",
+            Speaker::TopBackCenter => "TBC",
+            Speaker::TopBackRight => "TBR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChannelLayout {
+    pub speakers: Vec<Option<Speaker>>,
+}
+
+impl ChannelLayout {
+    pub fn from_mask(mask: u32, channels: u16) -> Self {
+        let mut speakers: Vec<Option<Speaker>> = Speaker::ALL
+            .iter()
+            .filter(|speaker| mask & speaker.mask_bit() != 0)
+            .map(|speaker| Some(*speaker))
+            .collect();
+        speakers.resize(channels as usize, None);
+        ChannelLayout { speakers }
+    }
+
+    pub fn default_for(channels: u16) -> Self {
+        use Speaker::*;
+        let speakers: &[Speaker] = match channels {
+            1 => &[FrontCenter],
+            2 => &[FrontLeft, FrontRight],
+            3 => &[FrontLeft, FrontRight, FrontCenter],
+            4 => &[FrontLeft, FrontRight, BackLeft, BackRight],
+            5 => &[FrontLeft, FrontRight, FrontCenter, BackLeft, BackRight],
+            6 => &[FrontLeft, FrontRight, FrontCenter, LowFrequency, BackLeft, BackRight],
+            7 => &[FrontLeft, FrontRight, FrontCenter, LowFrequency, BackCenter, SideLeft, SideRight],
+            8 => &[FrontLeft, FrontRight, FrontCenter, LowFrequency, BackLeft, BackRight, SideLeft, SideRight],
+            _ => &[],
+        };
+        let mut speakers: Vec<Option<Speaker>> = speakers.iter().map(|speaker| Some(*speaker)).collect();
+        speakers.resize(channels as usize, None);
+        ChannelLayout { speakers }
+    }
+
+    pub(crate) fn resolve(mask: Option<u32>, channels: u16) -> Self {
+        match mask {
+            Some(mask) if mask != 0 => ChannelLayout::from_mask(mask, channels),
+            _ => ChannelLayout::default_for(channels),
+        }
+    }
+
+    pub fn speaker(&self, channel: usize) -> Option<Speaker> {
+        self.speakers.get(channel).copied().flatten()
+    }
+
+    pub fn channel_of(&self, speaker: Speaker) -> Option<usize> {
+        self.speakers.iter().position(|assigned| *assigned == Some(speaker))
+    }
+
+    pub fn mask(&self) -> u32 {
+        self.speakers.iter().flatten().fold(0, |mask, speaker| mask | speaker.mask_bit())
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn channel_layout(&self) -> ChannelLayout {
+        ChannelLayout::resolve(self.channel_mask, self.channels)
+    }
+}
+
+#[cfg(test)]
+mod channel_layout_tests {
+    use crate::audio_data::AudioData;
+    use crate::channel_layout::{ChannelLayout, Speaker};
+
+    #[test]
+    fn mask_assigns_speakers_in_bit_order() {
+        let layout = ChannelLayout::from_mask(0x60F, 6);
+        assert_eq!(layout.speaker(2), Some(Speaker::FrontCenter));
+        assert_eq!(layout.speaker(3), Some(Speaker::LowFrequency));
+        assert_eq!(layout.speaker(4), Some(Speaker::SideLeft));
+        assert_eq!(layout.channel_of(Speaker::SideRight), Some(5));
+        assert_eq!(layout.mask(), 0x60F);
+    }
+
+    #[test]
+    fn mask_with_fewer_bits_than_channels_leaves_extra_channels_unassigned() {
+        let layout = ChannelLayout::from_mask(0x3, 3);
+        assert_eq!(layout.speakers, vec![Some(Speaker::FrontLeft), Some(Speaker::FrontRight), None]);
+        assert_eq!(ChannelLayout::from_mask(0x7, 2).speakers.len(), 2);
+    }
+
+    #[test]
+    fn audio_without_mask_uses_channel_count_defaults() {
+        let audio = AudioData { samples: vec![0; 12], channels: 6, sample_rate: 48000, bits_per_sample: 16, channel_mask: None };
+        let layout = audio.channel_layout();
+        assert_eq!(layout.channel_of(Speaker::LowFrequency), Some(3));
+        assert_eq!(layout.mask(), 0x3F);
+        assert_eq!(ChannelLayout::default_for(1).speaker(0), Some(Speaker::FrontCenter));
+        assert_eq!(ChannelLayout::default_for(12).speaker(0), None);
+
+        let masked = AudioData { channel_mask: Some(0x3), channels: 2, ..audio };
+        assert_eq!(masked.channel_layout().speaker(1), Some(Speaker::FrontRight));
+    }
+}
@@ -0,0 +1,350 @@
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::markers::{self, Marker};
+use crate::metadata::{self, WavMetadata};
+use crate::smpl::{self, LoopInfo};
+use crate::stats::AudioStats;
+use crate::warning::Warning;
+use crate::wav_binary::WavBinary;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkSummary {
+    pub id: String,
+    pub offset: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct InspectReport {
+    pub path: PathBuf,
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub frames: usize,
+    pub duration: Duration,
+    pub chunks: Vec<ChunkSummary>,
+    pub metadata: WavMetadata,
+    pub markers: Vec<Marker>,
+    pub loop_info: Option<LoopInfo>,
+    pub warnings: Vec<Warning>,
+}
+
+#[derive(Debug)]
+pub struct PeaksReport {
+    pub path: PathBuf,
+    pub rate: u32,
+    pub presentation: StereoAudioPresentation,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatsReport {
+    pub path: PathBuf,
+    pub stats: AudioStats,
+}
+
+#[derive(Debug)]
+pub struct BatchEntry {
+    pub path: PathBuf,
+    pub result: Result<PeaksReport, BramError>,
+}
+
+#[derive(Debug)]
+pub struct BatchReport {
+    pub entries: Vec<BatchEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareReport {
+    pub points_compared: usize,
+    pub length_mismatch: bool,
+    pub max_difference: f32,
+    pub mean_difference: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PunchListEntry {
+    pub frames: Range<usize>,
+    pub max_deviation: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RepairReport {
+    pub output: PathBuf,
+    pub fixes: Vec<Warning>,
+}
+
+pub fn inspect(path: impl AsRef<Path>) -> Result<InspectReport, BramError> {
+    let path = path.as_ref();
+    let (audio, chunks, metadata, markers, loop_info, warnings) = if let Some(audio) = crate::read_other_container(path) {
+        (audio?, Vec::new(), WavMetadata::default(), Vec::new(), None, Vec::new())
+    } else {
+        let wav = WavBinary::from_file(path)?;
+        let chunks = wav
+            .chunk_list()
+            .into_iter()
+            .map(|(id, offset, size)| ChunkSummary {
+                id: String::from_utf8_lossy(&id).into_owned(),
+                offset: offset - 8,
+                size,
+            })
+            .collect();
+        let (audio, warnings) = AudioData::try_from_lenient(&wav)?;
+        let (metadata, markers, loop_info) = (metadata::read_metadata(&wav), markers::read_markers(&wav), smpl::read_loop_info(&wav));
+        (audio, chunks, metadata, markers, loop_info, warnings)
+    };
+    Ok(InspectReport {
+        path: path.to_path_buf(),
+        channels: audio.channels,
+        sample_rate: audio.sample_rate,
+        bits_per_sample: audio.bits_per_sample,
+        frames: audio.frames(),
+        duration: audio.duration(),
+        chunks,
+        metadata,
+        markers,
+        loop_info,
+        warnings,
+    })
+}
+
+pub fn peaks(path: impl AsRef<Path>, rate: u32) -> Result<PeaksReport, BramError> {
+    let path = path.as_ref();
+    Ok(PeaksReport {
+        path: path.to_path_buf(),
+        rate,
+        presentation: crate::load_presentation(path, rate)?,
+    })
+}
+
+pub fn stats(path: impl AsRef<Path>) -> Result<StatsReport, BramError> {
+    let path = path.as_ref();
+    Ok(StatsReport {
+        path: path.to_path_buf(),
+        stats: crate::load_audio(path)?.stats(),
+    })
+}
+
+pub fn batch(paths: &[impl AsRef<Path>], rate: u32) -> BatchReport {
+    BatchReport {
+        entries: paths
+            .iter()
+            .map(|path| BatchEntry {
+                path: path.as_ref().to_path_buf(),
+                result: peaks(path, rate),
+            })
+            .collect(),
+    }
+}
+
+pub fn compare(first: impl AsRef<Path>, second: impl AsRef<Path>, rate: u32) -> Result<CompareReport, BramError> {
+    let first = crate::load_presentation(first, rate)?;
+    let second = crate::load_presentation(second, rate)?;
+    let first_points = first.left_channel_points.iter().chain(&first.right_channel_points);
+    let second_points = second.left_channel_points.iter().chain(&second.right_channel_points);
+    let differences: Vec<f32> = first_points
+        .zip(second_points)
+        .map(|(a, b)| (a - b).abs())
+        .collect();
+    let max_difference = differences.iter().cloned().fold(0.0, f32::max);
+    let mean_difference = if differences.is_empty() {
+        0.0
+    } else {
+        differences.iter().sum::<f32>() / differences.len() as f32
+    };
+    Ok(CompareReport {
+        points_compared: first.left_channel_points.len().min(second.left_channel_points.len()),
+        length_mismatch: first.left_channel_points.len() != second.left_channel_points.len(),
+        max_difference,
+        mean_difference,
+    })
+}
+
+pub fn punch_list(first: impl AsRef<Path>, second: impl AsRef<Path>, tolerance: f64) -> Result<Vec<PunchListEntry>, BramError> {
+    punch_list_for(&crate::load_audio(first)?, &crate::load_audio(second)?, tolerance)
+}
+
+fn punch_list_for(first: &AudioData, second: &AudioData, tolerance: f64) -> Result<Vec<PunchListEntry>, BramError> {
+    if first.channels != second.channels {
+        return Err(BramError::InvalidParameter("punch list needs both files to have the same channel count"));
+    }
+    let channels = first.channels.max(1) as usize;
+    let frames = first.frames().max(second.frames());
+    let sample = |audio: &AudioData, index: usize| audio.samples.get(index).map_or(0.0, |sample| *sample as f64 / 32768.0);
+    let mut entries: Vec<PunchListEntry> = Vec::new();
+    for frame in 0..frames {
+        let deviation = (frame * channels..(frame + 1) * channels)
+            .map(|index| (sample(first, index) - sample(second, index)).abs())
+            .fold(0.0, f64::max);
+        if deviation <= tolerance {
+            continue;
+        }
+        match entries.last_mut() {
+            Some(entry) if entry.frames.end == frame => {
+                entry.frames.end = frame + 1;
+                entry.max_deviation = entry.max_deviation.max(deviation);
+            }
+            _ => entries.push(PunchListEntry { frames: frame..frame + 1, max_deviation: deviation }),
+        }
+    }
+    Ok(entries)
+}
+
+pub fn repair(path: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<RepairReport, BramError> {
+    let output = output.as_ref();
+    let wav = WavBinary::from_file(path)?;
+    let (_, fixes) = AudioData::try_from_lenient(&wav)?;
+    let chunks = wav.chunk_list();
+    let block_align = chunks
+        .iter()
+        .find(|(id, _, size)| id == b"fmt " && *size >= 14)
+        .map(|(_, offset, _)| u16::from_le_bytes([wav.data[offset + 12], wav.data[offset + 13]]) as usize)
+        .filter(|block_align| *block_align > 0)
+        .ok_or(BramError::MissingChunk { id: *b"fmt " })?;
+
+    let skipped: Vec<usize> = fixes
+        .iter()
+        .filter_map(|fix| match fix {
+            Warning::SkippedChunk { offset, .. } => Some(offset + 8),
+            _ => None,
+        })
+        .collect();
+    let mut body = Vec::with_capacity(wav.data.len());
+    for (id, offset, size) in chunks {
+        if skipped.contains(&offset) {
+            continue;
+        }
+        let size = if &id == b"data" { size - size % block_align } else { size };
+        body.extend_from_slice(&id);
+        body.extend_from_slice(&(size as u32).to_le_bytes());
+        body.extend_from_slice(&wav.data[offset..offset + size]);
+        if size % 2 == 1 {
+            body.push(0);
+        }
+    }
+    let mut repaired = Vec::with_capacity(body.len() + 12);
+    repaired.extend_from_slice(b"RIFF");
+    repaired.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+    repaired.extend_from_slice(b"WAVE");
+    repaired.extend_from_slice(&body);
+    fs::write(output, repaired)?;
+
+    Ok(RepairReport {
+        output: output.to_path_buf(),
+        fixes,
+    })
+}
+
+#[cfg(test)]
+mod commands_tests {
+    use std::fs;
+    use crate::commands::{batch, compare, inspect, peaks, punch_list, repair, stats, PunchListEntry};
+    use crate::warning::Warning;
+
+    fn write_temp_wav(filename: &str, samples: &[i16], declared_data_size: Option<u32>) -> String {
+        let path = std::env::temp_dir().join(filename);
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x00, 0x02, 0x00]);
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x04, 0x00, 0x10, 0x00]);
+        data.extend_from_slice(b"data");
+        let data_size = declared_data_size.unwrap_or(samples.len() as u32 * 2);
+        data.extend_from_slice(&data_size.to_le_bytes());
+        for sample in samples {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        fs::write(&path, data).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn inspect_reports_format_and_chunks() {
+        let path = write_temp_wav("commands_inspect.wav", &[0, 0, 100, -100, 200, -200, 300, -300], None);
+        let report = inspect(&path).unwrap();
+        assert_eq!(report.channels, 2);
+        assert_eq!(report.sample_rate, 4);
+        assert_eq!(report.bits_per_sample, 16);
+        assert_eq!(report.frames, 4);
+        assert_eq!(report.duration.as_secs(), 1);
+        let ids: Vec<&str> = report.chunks.iter().map(|chunk| chunk.id.as_str()).collect();
+        assert_eq!(ids, vec!["fmt ", "data"]);
+        assert_eq!(report.chunks[0].offset, 12);
+        assert!(report.warnings.is_empty());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn peaks_and_batch_return_per_file_results() {
+        let path = write_temp_wav("commands_peaks.wav", &[0, 0, 100, -100, 200, -200, 300, -300], None);
+        let report = peaks(&path, 2).unwrap();
+        assert_eq!(report.presentation.left_channel_points.len(), 2);
+
+        let report = batch(&[&path, "./commands_missing.wav"], 2);
+        assert_eq!(report.entries.len(), 2);
+        assert!(report.entries[0].result.is_ok());
+        assert!(report.entries[1].result.is_err());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn stats_reports_whole_file_levels() {
+        let path = write_temp_wav("commands_stats.wav", &[0, 0, 16384, -16384, 0, 0, -16384, 16384], None);
+        let report = stats(&path).unwrap();
+        assert_eq!(report.stats.peak, 0.5);
+        assert_eq!(report.stats.channels.len(), 2);
+        assert!(stats("./commands_missing.wav").is_err());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn compare_identical_and_different_files() {
+        let first = write_temp_wav("commands_compare_a.wav", &[0, 0, 100, -100, 200, -200, 300, -300], None);
+        let second = write_temp_wav("commands_compare_b.wav", &[0, 0, 100, -100, 32767, 32767, 300, -300], None);
+        let same = compare(&first, &first, 4).unwrap();
+        assert_eq!(same.max_difference, 0.0);
+        assert!(!same.length_mismatch);
+        let different = compare(&first, &second, 4).unwrap();
+        assert!(different.max_difference > 0.4);
+        assert_eq!(different.points_compared, 4);
+        let _ = fs::remove_file(first);
+        let _ = fs::remove_file(second);
+    }
+
+    #[test]
+    fn punch_list_reports_differing_frame_ranges() {
+        let first = write_temp_wav("commands_punch_a.wav", &[0, 0, 100, -100, 200, -200, 300, -300, 0, 0], None);
+        let second = write_temp_wav("commands_punch_b.wav", &[0, 0, 100, 16384, 200, -200, 300, -300, 0, 0, 8192, 0], None);
+        let entries = punch_list(&first, &second, 0.001).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].frames, 1..2);
+        assert!((entries[0].max_deviation - 16484.0 / 32768.0).abs() < 1e-9);
+        assert_eq!(entries[1], PunchListEntry { frames: 5..6, max_deviation: 0.25 });
+        assert!(punch_list(&first, &first, 0.0).unwrap().is_empty());
+        let _ = fs::remove_file(first);
+        let _ = fs::remove_file(second);
+    }
+
+    #[test]
+    fn repair_fixes_oversized_data_chunk() {
+        let path = write_temp_wav("commands_repair_in.wav", &[0, 0, 100, -100, 200], Some(4096));
+        let output = std::env::temp_dir().join("commands_repair_out.wav");
+        let report = repair(&path, &output).unwrap();
+        assert!(report.fixes.iter().any(|fix| matches!(fix, Warning::ClampedChunkSize { .. })));
+
+        let repaired = inspect(&output).unwrap();
+        assert_eq!(repaired.frames, 2);
+        assert!(repaired.warnings.is_empty());
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(output);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::Path;
+use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+use crate::error::BramError;
+
+const MAGIC: &[u8; 4] = b"BRMW";
+const FORMAT_VERSION: u16 = 1;
+const HEADER_LEN: usize = 32;
+const FLAG_ENVELOPES: u8 = 1;
+
+impl StereoAudioPresentation {
+    pub fn to_compact(&self) -> Vec<u8> {
+        let count = self.left_channel_points.len().min(self.right_channel_points.len());
+        let envelopes = match (&self.left_envelope, &self.right_envelope) {
+            (Some(left), Some(right)) => Some([left, right]),
+            _ => None,
+        };
+        let series = if envelopes.is_some() { 6 } else { 2 };
+        let mut bytes = Vec::with_capacity(HEADER_LEN + count * series * 4);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.push(if envelopes.is_some() { FLAG_ENVELOPES } else { 0 });
+        bytes.push(0);
+        bytes.extend_from_slice(&self.source_sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&self.source_channels.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.extend_from_slice(&(self.samples_per_point as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.source_frames as u64).to_le_bytes());
+        bytes.extend_from_slice(&(count as u32).to_le_bytes());
+        let mut write_points = |points: &[f32]| {
+            for point in &points[..count] {
+                bytes.extend_from_slice(&point.to_le_bytes());
+            }
+        };
+        write_points(&self.left_channel_points);
+        write_points(&self.right_channel_points);
+        for envelope in envelopes.into_iter().flatten() {
+            write_points(&envelope.low);
+            write_points(&envelope.high);
+        }
+        bytes
+    }
+
+    pub fn from_compact(bytes: &[u8]) -> Result<Self, BramError> {
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+            return Err(BramError::Decode("not a compact presentation".to_string()));
+        }
+        let read_u16 = |offset: usize| u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+        let read_u32 = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let version = read_u16(4);
+        if version == 0 || version > FORMAT_VERSION {
+            return Err(BramError::Decode(format!("unsupported compact presentation version {}", version)));
+        }
+        let has_envelopes = bytes[6] & FLAG_ENVELOPES != 0;
+        let count = read_u32(28) as usize;
+        let series = if has_envelopes { 6 } else { 2 };
+        let data = bytes
+            .get(HEADER_LEN..HEADER_LEN + count * series * 4)
+            .ok_or(BramError::Truncated { offset: bytes.len(), chunk: *MAGIC })?;
+        let mut series = data
+            .chunks_exact(count.max(1) * 4)
+            .map(|chunk| chunk.chunks_exact(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect::<Vec<f32>>());
+        let mut next = || series.next().unwrap_or_default();
+        let left_channel_points = next();
+        let right_channel_points = next();
+        let (left_envelope, right_envelope) = if has_envelopes {
+            (Some(Envelope { low: next(), high: next() }), Some(Envelope { low: next(), high: next() }))
+        } else {
+            (None, None)
+        };
+        Ok(StereoAudioPresentation {
+            left_channel_points,
+            right_channel_points,
+            left_envelope,
+            right_envelope,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: read_u32(8),
+            source_channels: read_u16(12),
+            source_frames: u64::from_le_bytes(bytes[20..28].try_into().unwrap()) as usize,
+            samples_per_point: read_u32(16) as usize,
+        })
+    }
+}
+
+pub fn write_presentation(presentation: &StereoAudioPresentation, path: impl AsRef<Path>) -> Result<(), BramError> {
+    fs::write(path, presentation.to_compact())?;
+    Ok(())
+}
+
+pub fn read_presentation(path: impl AsRef<Path>) -> Result<StereoAudioPresentation, BramError> {
+    StereoAudioPresentation::from_compact(&fs::read(path)?)
+}
+
+#[cfg(test)]
+mod compact_tests {
+    use std::fs;
+    use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+    use crate::compact::{read_presentation, write_presentation};
+    use crate::error::BramError;
+
+    fn presentation(envelopes: bool) -> StereoAudioPresentation {
+        let envelope = |low: f32| Envelope { low: vec![low, 0.1, 0.2], high: vec![0.9, 0.8, 0.7] };
+        StereoAudioPresentation {
+            left_channel_points: vec![0.5, 0.25, 0.75],
+            right_channel_points: vec![0.0, 1.0, 0.5],
+            left_envelope: envelopes.then(|| envelope(0.0)),
+            right_envelope: envelopes.then(|| envelope(0.3)),
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 48000,
+            source_channels: 2,
+            source_frames: 1440,
+            samples_per_point: 480,
+        }
+    }
+
+    #[test]
+    fn compact_round_trip_keeps_points_and_source_info() {
+        for envelopes in [false, true] {
+            let original = presentation(envelopes);
+            let bytes = original.to_compact();
+            assert_eq!(&bytes[0..6], b"BRMW\x01\x00");
+            assert_eq!(bytes.len(), 32 + 3 * 4 * if envelopes { 6 } else { 2 });
+            let decoded = StereoAudioPresentation::from_compact(&bytes).unwrap();
+            assert_eq!(decoded.left_channel_points, original.left_channel_points);
+            assert_eq!(decoded.right_channel_points, original.right_channel_points);
+            assert_eq!(decoded.left_envelope, original.left_envelope);
+            assert_eq!(decoded.right_envelope, original.right_envelope);
+            assert_eq!((decoded.source_sample_rate, decoded.source_channels), (48000, 2));
+            assert_eq!((decoded.source_frames, decoded.samples_per_point), (1440, 480));
+        }
+    }
+
+    #[test]
+    fn unknown_versions_and_truncation_are_rejected() {
+        let mut bytes = presentation(false).to_compact();
+        assert!(matches!(StereoAudioPresentation::from_compact(&bytes[..bytes.len() - 1]), Err(BramError::Truncated { .. })));
+        bytes[4] = 2;
+        assert!(matches!(StereoAudioPresentation::from_compact(&bytes), Err(BramError::Decode(_))));
+        assert!(matches!(StereoAudioPresentation::from_compact(b"RIFF"), Err(BramError::Decode(_))));
+    }
+
+    #[test]
+    fn presentation_files_round_trip() {
+        let path = std::env::temp_dir().join("bram_compact.bin");
+        let path = path.to_str().unwrap();
+        write_presentation(&presentation(true), path).unwrap();
+        assert_eq!(read_presentation(path).unwrap().left_envelope, presentation(true).left_envelope);
+        let empty = StereoAudioPresentation { left_channel_points: Vec::new(), right_channel_points: Vec::new(), ..presentation(false) };
+        write_presentation(&empty, path).unwrap();
+        assert!(read_presentation(path).unwrap().left_channel_points.is_empty());
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,229 @@
+use std::ops::Range;
+use std::time::Duration;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompareConfig {
+    pub region: Duration,
+    pub threshold: f32,
+    pub max_spans: usize,
+}
+
+impl Default for CompareConfig {
+    fn default() -> Self {
+        Self {
+            region: Duration::from_millis(100),
+            threshold: 0.95,
+            max_spans: 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionSimilarity {
+    pub time: Range<Duration>,
+    pub similarity: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifferingSpan {
+    pub time: Range<Duration>,
+    pub max_difference: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub similarity: f32,
+    pub regions: Vec<RegionSimilarity>,
+    pub differing_spans: Vec<DifferingSpan>,
+}
+
+impl Comparison {
+    pub fn is_similar(&self) -> bool {
+        self.differing_spans.is_empty()
+    }
+}
+
+pub fn compare_audio(a: &AudioData, b: &AudioData, config: &CompareConfig) -> Result<Comparison, BramError> {
+    if a.sample_rate != b.sample_rate || a.channels != b.channels {
+        return Err(BramError::InvalidParameter("compared audio must share sample rate and channel count"));
+    }
+    let channels = a.channels.max(1) as usize;
+    let series = (0..channels)
+        .map(|channel| {
+            let values = |audio: &AudioData| -> Vec<f32> {
+                audio.samples.iter().skip(channel).step_by(channels).map(|s| *s as f32 / 32768.0).collect()
+            };
+            (values(a), values(b))
+        })
+        .collect::<Vec<_>>();
+    compare_series(&series, a.sample_rate as f64, Scale::Relative, config)
+}
+
+pub fn compare_presentations(
+    a: &StereoAudioPresentation,
+    b: &StereoAudioPresentation,
+    config: &CompareConfig,
+) -> Result<Comparison, BramError> {
+    if a.samples_per_point == 0 || a.source_sample_rate == 0 {
+        return Err(BramError::InvalidParameter("compared presentations must record their source rate"));
+    }
+    if a.samples_per_point != b.samples_per_point || a.source_sample_rate != b.source_sample_rate {
+        return Err(BramError::InvalidParameter("compared presentations must share a point rate"));
+    }
+    let series = [
+        (a.left_channel_points.clone(), b.left_channel_points.clone()),
+        (a.right_channel_points.clone(), b.right_channel_points.clone()),
+    ];
+    let values_per_second = a.source_sample_rate as f64 / a.samples_per_point as f64;
+    compare_series(&series, values_per_second, Scale::Absolute, config)
+}
+
+#[derive(Clone, Copy)]
+enum Scale {
+    Relative,
+    Absolute,
+}
+
+fn compare_series(
+    series: &[(Vec<f32>, Vec<f32>)],
+    values_per_second: f64,
+    scale: Scale,
+    config: &CompareConfig,
+) -> Result<Comparison, BramError> {
+    let region = (config.region.as_secs_f64() * values_per_second).round() as usize;
+    if region == 0 {
+        return Err(BramError::InvalidParameter("comparison region must cover at least one value"));
+    }
+    let length = series.iter().map(|(a, b)| a.len().max(b.len())).max().unwrap_or(0);
+    let time = |index: usize| Duration::from_secs_f64(index as f64 / values_per_second);
+    let value = |values: &[f32], index: usize| values.get(index).copied().unwrap_or(0.0);
+
+    let mut regions = Vec::new();
+    let mut region_differences = Vec::new();
+    for start in (0..length).step_by(region) {
+        let end = (start + region).min(length);
+        let mut similarity = 1.0f32;
+        let mut max_difference = 0.0f32;
+        for (a, b) in series {
+            let (mut difference_energy, mut a_energy, mut b_energy) = (0.0f32, 0.0f32, 0.0f32);
+            for index in start..end {
+                let (x, y) = (value(a, index), value(b, index));
+                difference_energy += (x - y).powi(2);
+                a_energy += x * x;
+                b_energy += y * y;
+                max_difference = max_difference.max((x - y).abs());
+            }
+            let reference = match scale {
+                Scale::Relative => a_energy.max(b_energy),
+                Scale::Absolute => (end - start) as f32,
+            };
+            if difference_energy > 0.0 {
+                similarity = similarity.min(1.0 - (difference_energy / reference).sqrt().min(1.0));
+            }
+        }
+        regions.push(RegionSimilarity { time: time(start)..time(end), similarity });
+        region_differences.push(max_difference);
+    }
+
+    let mut differing_spans: Vec<DifferingSpan> = Vec::new();
+    let mut previous_differed = false;
+    for (region, max_difference) in regions.iter().zip(&region_differences) {
+        let differs = region.similarity < config.threshold;
+        match differing_spans.last_mut() {
+            Some(span) if differs && previous_differed => {
+                span.time.end = region.time.end;
+                span.max_difference = span.max_difference.max(*max_difference);
+            }
+            _ if differs => differing_spans.push(DifferingSpan { time: region.time.clone(), max_difference: *max_difference }),
+            _ => {}
+        }
+        previous_differed = differs;
+    }
+    differing_spans.sort_by(|a, b| b.max_difference.total_cmp(&a.max_difference));
+    differing_spans.truncate(config.max_spans);
+
+    let similarity = regions.iter().map(|region| region.similarity).sum::<f32>() / regions.len().max(1) as f32;
+    Ok(Comparison {
+        similarity: if regions.is_empty() { 1.0 } else { similarity },
+        regions,
+        differing_spans,
+    })
+}
+
+#[cfg(test)]
+mod compare_tests {
+    use std::time::Duration;
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::compare::{compare_audio, compare_presentations, CompareConfig};
+    use crate::error::BramError;
+
+    fn tone(frames: usize) -> AudioData {
+        AudioData {
+            samples: (0..frames)
+                .flat_map(|i| {
+                    let value = (10000.0 * (i as f32 * 0.1).sin()) as i16;
+                    [value, value]
+                })
+                .collect(),
+            channels: 2,
+            sample_rate: 1000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn identical_audio_is_similar() {
+        let comparison = compare_audio(&tone(1000), &tone(1000), &CompareConfig::default()).unwrap();
+        assert_eq!(comparison.regions.len(), 10);
+        assert_eq!(comparison.similarity, 1.0);
+        assert!(comparison.is_similar());
+    }
+
+    #[test]
+    fn glitches_are_reported_as_largest_spans_first() {
+        let mut glitched = tone(1000);
+        for sample in &mut glitched.samples[2 * 250..2 * 350] {
+            *sample = 0;
+        }
+        glitched.samples[2 * 820] = 30000;
+        let comparison = compare_audio(&tone(1000), &glitched, &CompareConfig::default()).unwrap();
+        assert_eq!(comparison.differing_spans.len(), 2);
+        let span = &comparison.differing_spans[0];
+        assert_eq!(span.time, Duration::from_millis(800)..Duration::from_millis(900));
+        let span = &comparison.differing_spans[1];
+        assert_eq!(span.time, Duration::from_millis(200)..Duration::from_millis(400));
+        assert!(comparison.regions[0].similarity == 1.0 && comparison.regions[3].similarity < 0.5);
+    }
+
+    #[test]
+    fn presentations_compare_point_by_point() {
+        let presentation = |points: Vec<f32>| StereoAudioPresentation {
+            left_channel_points: points.clone(),
+            right_channel_points: points,
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 1000,
+            source_channels: 2,
+            source_frames: 10000,
+            samples_per_point: 100,
+        };
+        let a = presentation(vec![0.5; 10]);
+        let mut points = vec![0.5; 10];
+        points[7] = 0.9;
+        let comparison = compare_presentations(&a, &presentation(points), &CompareConfig { region: Duration::from_millis(500), ..CompareConfig::default() }).unwrap();
+        assert_eq!(comparison.regions.len(), 2);
+        assert_eq!(comparison.regions[0].similarity, 1.0);
+        assert_eq!(comparison.differing_spans[0].time, Duration::from_millis(500)..Duration::from_secs(1));
+
+        let mut coarser = presentation(vec![0.5; 10]);
+        coarser.samples_per_point = 200;
+        assert!(matches!(compare_presentations(&a, &coarser, &CompareConfig::default()), Err(BramError::InvalidParameter(_))));
+    }
+}
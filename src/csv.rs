@@ -0,0 +1,77 @@
+use std::io::Write;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::format::{format_duration, format_float, DurationFormat};
+
+const TIME_DECIMALS: usize = 6;
+const POINT_DECIMALS: usize = 6;
+
+impl StereoAudioPresentation {
+    pub fn export_csv(&self, writer: impl Write) -> Result<(), BramError> {
+        self.export_csv_with_duration_format(writer, DurationFormat::Seconds)
+    }
+
+    pub fn export_csv_with_duration_format(&self, mut writer: impl Write, duration_format: DurationFormat) -> Result<(), BramError> {
+        writeln!(writer, "time,left,right")?;
+        for (index, (left, right)) in self.left_channel_points.iter().zip(&self.right_channel_points).enumerate() {
+            let time = match duration_format {
+                DurationFormat::Seconds => format_float(self.point_time(index), TIME_DECIMALS),
+                style => format_duration((index * self.samples_per_point) as u64, self.source_sample_rate, style),
+            };
+            writeln!(
+                writer,
+                "{},{},{}",
+                time,
+                format_float(*left as f64, POINT_DECIMALS),
+                format_float(*right as f64, POINT_DECIMALS),
+            )?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::format::DurationFormat;
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.0, 0.5, 1.0],
+            right_channel_points: vec![1.0, 0.25, 0.5],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 8000,
+            source_channels: 2,
+            source_frames: 12000,
+            samples_per_point: 4000,
+        }
+    }
+
+    #[test]
+    fn export_csv_writes_time_left_right_rows() {
+        let mut out = Vec::new();
+        presentation().export_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "time,left,right\n\
+             0.000000,0.000000,1.000000\n\
+             0.500000,0.500000,0.250000\n\
+             1.000000,1.000000,0.500000\n"
+        );
+    }
+
+    #[test]
+    fn export_csv_formats_time_column() {
+        let time_column = |duration_format| {
+            let mut out = Vec::new();
+            presentation().export_csv_with_duration_format(&mut out, duration_format).unwrap();
+            String::from_utf8(out).unwrap().lines().skip(1).map(|row| row.split(',').next().unwrap().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(time_column(DurationFormat::Timecode), vec!["00:00:00.000", "00:00:00.500", "00:00:01.000"]);
+        assert_eq!(time_column(DurationFormat::Samples), vec!["0", "4000", "8000"]);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::annotation::{Annotation, AnnotationKind};
+use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+
+const DAT_VERSION: i32 = 2;
+const DAT_CHANNELS: i32 = 2;
+const FLAG_16_BIT: u32 = 0;
+const ANNOTATIONS_MAGIC: &[u8; 4] = b"ANNO";
+const ANNOTATIONS_VERSION: u32 = 1;
+
+impl StereoAudioPresentation {
+    pub fn to_dat(&self) -> Vec<u8> {
+        let len = self.left_channel_points.len().min(self.right_channel_points.len());
+        let mut bytes = Vec::with_capacity(24 + len * 8);
+        bytes.extend_from_slice(&DAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&FLAG_16_BIT.to_le_bytes());
+        bytes.extend_from_slice(&(self.source_sample_rate as i32).to_le_bytes());
+        bytes.extend_from_slice(&(self.samples_per_point as i32).to_le_bytes());
+        bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        bytes.extend_from_slice(&DAT_CHANNELS.to_le_bytes());
+        let channels = [
+            (&self.left_channel_points, &self.left_envelope),
+            (&self.right_channel_points, &self.right_envelope),
+        ];
+        for index in 0..len {
+            for (points, envelope) in channels {
+                let (low, high) = bounds(points, envelope.as_ref(), index);
+                bytes.extend_from_slice(&to_i16(low).to_le_bytes());
+                bytes.extend_from_slice(&to_i16(high).to_le_bytes());
+            }
+        }
+        if !self.annotations.is_empty() {
+            write_annotations(&mut bytes, &self.annotations);
+        }
+        bytes
+    }
+}
+
+fn write_annotations(bytes: &mut Vec<u8>, annotations: &[Annotation]) {
+    bytes.extend_from_slice(ANNOTATIONS_MAGIC);
+    bytes.extend_from_slice(&ANNOTATIONS_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(annotations.len() as u32).to_le_bytes());
+    for annotation in annotations {
+        bytes.extend_from_slice(&(annotation.start_point as u32).to_le_bytes());
+        bytes.extend_from_slice(&(annotation.end_point as u32).to_le_bytes());
+        bytes.push(match annotation.kind {
+            AnnotationKind::Synthetic => 0,
+            AnnotationKind::Boundary => 1,
+            AnnotationKind::Marker => 2,
+        });
+        bytes.extend_from_slice(&(annotation.label.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(annotation.label.as_bytes());
+    }
+}
+
+fn bounds(points: &[f32], envelope: Option<&Envelope>, index: usize) -> (f32, f32) {
+    envelope
+        .and_then(|envelope| Some((*envelope.low.get(index)?, *envelope.high.get(index)?)))
+        .unwrap_or((points[index], points[index]))
+}
+
+fn to_i16(point: f32) -> i16 {
+    ((point as f64 * 2.0 - 1.0) * 32768.0).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16
+}
+
+#[cfg(test)]
+mod dat_tests {
+    use crate::annotation::Annotation;
+    use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+
+    #[test]
+    fn to_dat_writes_v2_header_and_min_max_pairs() {
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.0, 1.0],
+            right_channel_points: vec![0.5, 0.5],
+            left_envelope: None,
+            right_envelope: Some(Envelope { low: vec![0.0, 0.25], high: vec![1.0, 0.75] }),
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 44100,
+            source_channels: 2,
+            source_frames: 882,
+            samples_per_point: 441,
+        };
+        let bytes = presentation.to_dat();
+        let word = |index: usize| i32::from_le_bytes(bytes[index * 4..index * 4 + 4].try_into().unwrap());
+        assert_eq!([word(0), word(1), word(2), word(3), word(4), word(5)], [2, 0, 44100, 441, 2, 2]);
+        let pairs: Vec<i16> = bytes[24..].chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]])).collect();
+        assert_eq!(pairs, vec![-32768, -32768, -32768, 32767, 32767, 32767, -16384, 16384]);
+    }
+
+    #[test]
+    fn to_dat_appends_annotations_block_after_pairs() {
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.5],
+            right_channel_points: vec![0.5],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: vec![Annotation::synthetic(3, 7, "gap"), Annotation::boundary(9, "")],
+            source_sample_rate: 8000,
+            source_channels: 2,
+            source_frames: 80,
+            samples_per_point: 80,
+        };
+        let bytes = presentation.to_dat();
+        let block = &bytes[24 + 8..];
+        let word = |index: usize| u32::from_le_bytes(block[index..index + 4].try_into().unwrap());
+        assert_eq!(&block[..4], b"ANNO");
+        assert_eq!([word(4), word(8)], [1, 2]);
+        assert_eq!([word(12), word(16)], [3, 7]);
+        assert_eq!(block[20], 0);
+        assert_eq!(word(21), 3);
+        assert_eq!(&block[25..28], b"gap");
+        assert_eq!([word(28), word(32)], [9, 9]);
+        assert_eq!(block[36], 1);
+        assert_eq!(word(37), 0);
+        assert_eq!(block.len(), 41);
+    }
+}
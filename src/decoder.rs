@@ -0,0 +1,82 @@
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+
+static DECODERS: RwLock<Vec<Arc<dyn Decoder>>> = RwLock::new(Vec::new());
+
+pub trait Decoder: Send + Sync {
+    fn extensions(&self) -> &[&str];
+    fn decode(&self, data: &[u8]) -> Result<AudioData, BramError>;
+}
+
+pub fn register_decoder(decoder: impl Decoder + 'static) {
+    DECODERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Arc::new(decoder));
+}
+
+pub(crate) fn find_decoder(path: impl AsRef<Path>) -> Option<Arc<dyn Decoder>> {
+    let extension = path.as_ref().extension()?;
+    DECODERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .rev()
+        .find(|decoder| decoder.extensions().iter().any(|ext| extension.eq_ignore_ascii_case(ext)))
+        .cloned()
+}
+
+pub(crate) fn registered_extensions() -> Vec<String> {
+    DECODERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .flat_map(|decoder| decoder.extensions().iter().map(|ext| ext.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod decoder_tests {
+    use std::fs;
+    use crate::error::BramError;
+    use crate::audio_data::AudioData;
+    use crate::decoder::{find_decoder, register_decoder, Decoder};
+
+    struct RawMonoDecoder;
+
+    impl Decoder for RawMonoDecoder {
+        fn extensions(&self) -> &[&str] {
+            &["rawmono"]
+        }
+
+        fn decode(&self, data: &[u8]) -> Result<AudioData, BramError> {
+            Ok(AudioData {
+                samples: data.chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]])).collect(),
+                channels: 1,
+                sample_rate: 4,
+                bits_per_sample: 16,
+                channel_mask: None,
+            })
+        }
+    }
+
+    #[test]
+    fn registered_decoder_feeds_load_presentation() {
+        register_decoder(RawMonoDecoder);
+        assert!(find_decoder("take.RAWMONO").is_some());
+        assert!(find_decoder("take.unknown").is_none());
+
+        let path = std::env::temp_dir().join("decoder_test.rawmono");
+        let mut data = Vec::new();
+        for sample in [-32768i16, 0, 32767, 0] {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        fs::write(&path, data).unwrap();
+        let presentation = crate::load_presentation(path.to_str().unwrap(), 2).unwrap();
+        assert_eq!(presentation.left_channel_points, vec![0.0, (32767.0 / 32768.0 + 1.0) / 2.0]);
+        assert_eq!(presentation.left_channel_points, presentation.right_channel_points);
+        let _ = fs::remove_file(path);
+    }
+}
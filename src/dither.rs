@@ -0,0 +1,84 @@
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dither {
+    #[default]
+    None,
+    Tpdf,
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn convert_bit_depth(&self, target_bits: u16, dither: Dither) -> Result<AudioData<S>, BramError> {
+        if !matches!(target_bits, 8 | 16 | 24 | 32) {
+            return Err(BramError::InvalidParameter("bit depth must be 8, 16, 24 or 32"));
+        }
+        let step = 2f64.powi(1 - target_bits as i32);
+        let mut state = 0x2545_F491u32;
+        let mut uniform = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f64 / 4294967296.0
+        };
+        let samples = self
+            .samples
+            .iter()
+            .map(|sample| {
+                let noise = match dither {
+                    Dither::None => 0.0,
+                    Dither::Tpdf => (uniform() + uniform() - 1.0) * step,
+                };
+                let quantized = ((sample.to_f64() + noise) / step).round() * step;
+                S::from_f64(quantized.clamp(-1.0, 1.0 - step))
+            })
+            .collect();
+        Ok(AudioData {
+            samples,
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: target_bits,
+            channel_mask: self.channel_mask,
+        })
+    }
+}
+
+#[cfg(test)]
+mod dither_tests {
+    use crate::audio_data::AudioData;
+    use crate::dither::Dither;
+    use crate::error::BramError;
+    use crate::wav_writer::encode_wav;
+
+    fn audio(samples: Vec<i32>) -> AudioData<i32> {
+        AudioData { samples, channels: 1, sample_rate: 48000, bits_per_sample: 24, channel_mask: None }
+    }
+
+    #[test]
+    fn conversion_without_dither_rounds_to_target_grid() {
+        let converted = audio(vec![0x0001_8000, 0x0001_7F00, i32::MAX, i32::MIN]).convert_bit_depth(16, Dither::None).unwrap();
+        assert_eq!(converted.samples, vec![0x0002_0000, 0x0001_0000, 0x7FFF_0000, i32::MIN]);
+        assert_eq!(converted.bits_per_sample, 16);
+        let encoded = encode_wav(&converted);
+        assert_eq!(u16::from_le_bytes([encoded[34], encoded[35]]), 16);
+    }
+
+    #[test]
+    fn tpdf_dither_stays_within_one_step_and_decorrelates_error() {
+        let quiet = audio(vec![0x0000_4000; 4096]);
+        let dithered = quiet.convert_bit_depth(16, Dither::Tpdf).unwrap();
+        assert!(dithered.samples.iter().all(|sample| sample % 0x1_0000 == 0));
+        assert!(dithered.samples.iter().all(|sample| (-0x1_0000..=0x2_0000).contains(sample)));
+        assert!(dithered.samples.iter().any(|sample| *sample != 0));
+        let mean = dithered.samples.iter().map(|sample| *sample as f64).sum::<f64>() / 4096.0;
+        assert!((mean - 0x4000 as f64).abs() < 0x1000 as f64);
+        assert!(quiet.convert_bit_depth(16, Dither::None).unwrap().samples.iter().all(|sample| *sample == 0));
+    }
+
+    #[test]
+    fn unsupported_bit_depth_is_rejected() {
+        let result = audio(vec![0]).convert_bit_depth(12, Dither::None);
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,293 @@
+use std::f64::consts::FRAC_PI_2;
+use std::time::Duration;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::resample::ResampleQuality;
+use crate::sample::Sample;
+
+const EXPONENTIAL_STEEPNESS: f64 = 4.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FadeCurve {
+    #[default]
+    Linear,
+    Exponential,
+    EqualPower,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MixHeadroom {
+    #[default]
+    Scale,
+    Clip,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MixInput<'a, S: Sample = i16> {
+    pub audio: &'a AudioData<S>,
+    pub gain_db: f64,
+}
+
+impl<'a, S: Sample> MixInput<'a, S> {
+    pub fn new(audio: &'a AudioData<S>, gain_db: f64) -> Self {
+        Self { audio, gain_db }
+    }
+}
+
+impl FadeCurve {
+    fn gain(self, position: f64) -> f64 {
+        match self {
+            FadeCurve::Linear => position,
+            FadeCurve::Exponential => (EXPONENTIAL_STEEPNESS * position).exp_m1() / EXPONENTIAL_STEEPNESS.exp_m1(),
+            FadeCurve::EqualPower => (position * FRAC_PI_2).sin(),
+        }
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn apply_fade_in(&mut self, duration: Duration, curve: FadeCurve) {
+        let fade_frames = self.frame_at(duration);
+        let channels = self.channels.max(1) as usize;
+        for (frame, values) in self.samples.chunks_exact_mut(channels).take(fade_frames).enumerate() {
+            scale_frame(values, curve.gain(frame as f64 / fade_frames as f64));
+        }
+    }
+
+    pub fn apply_fade_out(&mut self, duration: Duration, curve: FadeCurve) {
+        let fade_frames = self.frame_at(duration);
+        let channels = self.channels.max(1) as usize;
+        for (frame, values) in self.samples.chunks_exact_mut(channels).rev().take(fade_frames).enumerate() {
+            scale_frame(values, curve.gain(frame as f64 / fade_frames as f64));
+        }
+    }
+
+    pub fn apply_gain_envelope(&mut self, points: &[(Duration, f32)]) -> Result<(), BramError> {
+        if points.is_empty() {
+            return Err(BramError::InvalidParameter("gain envelope needs at least one point"));
+        }
+        if points.iter().any(|(_, gain)| !gain.is_finite() || *gain < 0.0) {
+            return Err(BramError::InvalidParameter("gain envelope values must be finite and non-negative"));
+        }
+        if points.windows(2).any(|pair| pair[1].0 < pair[0].0) {
+            return Err(BramError::InvalidParameter("gain envelope points must be sorted by time"));
+        }
+        let channels = self.channels.max(1) as usize;
+        let sample_rate = self.sample_rate.max(1) as f64;
+        let mut segment = 0;
+        for (frame, values) in self.samples.chunks_exact_mut(channels).enumerate() {
+            let time = frame as f64 / sample_rate;
+            while segment + 1 < points.len() && points[segment + 1].0.as_secs_f64() <= time {
+                segment += 1;
+            }
+            let (start, start_gain) = points[segment];
+            let gain = match points.get(segment + 1) {
+                Some((end, end_gain)) if time > start.as_secs_f64() => {
+                    let position = (time - start.as_secs_f64()) / (end.as_secs_f64() - start.as_secs_f64());
+                    start_gain as f64 + (*end_gain as f64 - start_gain as f64) * position
+                }
+                _ => start_gain as f64,
+            };
+            scale_frame(values, gain);
+        }
+        Ok(())
+    }
+
+    pub fn concat(parts: &[AudioData<S>]) -> Result<AudioData<S>, BramError> {
+        let Some(first) = parts.first() else {
+            return Err(BramError::InvalidParameter("concatenation needs at least one part"));
+        };
+        let mut samples = Vec::with_capacity(parts.iter().map(|part| part.samples.len()).sum());
+        for part in parts {
+            samples.extend(part.conformed_to(first)?.samples);
+        }
+        Ok(AudioData {
+            samples,
+            channels: first.channels,
+            sample_rate: first.sample_rate,
+            bits_per_sample: first.bits_per_sample,
+            channel_mask: first.channel_mask,
+        })
+    }
+
+    pub fn mix(inputs: &[MixInput<'_, S>], headroom: MixHeadroom) -> Result<AudioData<S>, BramError> {
+        let Some(first) = inputs.first().map(|input| input.audio) else {
+            return Err(BramError::InvalidParameter("mixing needs at least one input"));
+        };
+        let mut sums: Vec<f64> = Vec::new();
+        for input in inputs {
+            let gain = 10f64.powf(input.gain_db / 20.0);
+            let conformed = input.audio.conformed_to(first)?;
+            if sums.len() < conformed.samples.len() {
+                sums.resize(conformed.samples.len(), 0.0);
+            }
+            for (sum, sample) in sums.iter_mut().zip(&conformed.samples) {
+                *sum += sample.to_f64() * gain;
+            }
+        }
+        let peak = sums.iter().fold(0.0f64, |peak, sum| peak.max(sum.abs()));
+        let scale = match headroom {
+            MixHeadroom::Scale if peak > 1.0 => 1.0 / peak,
+            _ => 1.0,
+        };
+        Ok(AudioData {
+            samples: sums.iter().map(|sum| S::from_f64((sum * scale).clamp(-1.0, 1.0))).collect(),
+            channels: first.channels,
+            sample_rate: first.sample_rate,
+            bits_per_sample: first.bits_per_sample,
+            channel_mask: first.channel_mask,
+        })
+    }
+
+    fn conformed_to(&self, reference: &AudioData<S>) -> Result<AudioData<S>, BramError> {
+        let resampled = if self.sample_rate == reference.sample_rate {
+            self.clone()
+        } else {
+            self.resample(reference.sample_rate, ResampleQuality::default())?
+        };
+        let channels = reference.channels.max(1) as usize;
+        match resampled.channels.max(1) as usize {
+            count if count == channels => Ok(resampled),
+            1 => Ok(AudioData {
+                samples: resampled.samples.iter().flat_map(|sample| std::iter::repeat_n(*sample, channels)).collect(),
+                channels: reference.channels,
+                ..resampled
+            }),
+            _ => Err(BramError::InvalidParameter("combined audio must share a channel count or be mono")),
+        }
+    }
+
+    pub fn slice(&self, start: Duration, end: Duration) -> AudioData<S> {
+        let channels = self.channels.max(1) as usize;
+        let end = self.frame_at(end);
+        let start = self.frame_at(start).min(end);
+        AudioData {
+            samples: self.samples[start * channels..end * channels].to_vec(),
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        }
+    }
+
+    fn frame_at(&self, time: Duration) -> usize {
+        ((time.as_secs_f64() * self.sample_rate as f64).round() as usize).min(self.frames())
+    }
+}
+
+fn scale_frame<S: Sample>(values: &mut [S], gain: f64) {
+    for value in values {
+        *value = S::from_f64(value.to_f64() * gain);
+    }
+}
+
+#[cfg(test)]
+mod edit_tests {
+    use std::time::Duration;
+    use crate::audio_data::AudioData;
+    use crate::edit::{FadeCurve, MixHeadroom, MixInput};
+    use crate::error::BramError;
+
+    fn constant(frames: usize) -> AudioData<f32> {
+        AudioData { samples: vec![1.0; frames * 2], channels: 2, sample_rate: 4, bits_per_sample: 32, channel_mask: None }
+    }
+
+    #[test]
+    fn linear_fades_ramp_both_channels() {
+        let mut audio = constant(6);
+        audio.apply_fade_in(Duration::from_secs(1), FadeCurve::Linear);
+        assert_eq!(audio.samples, vec![0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0, 1.0]);
+
+        let mut audio = constant(6);
+        audio.apply_fade_out(Duration::from_secs(1), FadeCurve::Linear);
+        assert_eq!(audio.samples, vec![1.0, 1.0, 1.0, 1.0, 0.75, 0.75, 0.5, 0.5, 0.25, 0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn curves_share_endpoints_but_differ_in_shape() {
+        for curve in [FadeCurve::Linear, FadeCurve::Exponential, FadeCurve::EqualPower] {
+            assert_eq!(curve.gain(0.0), 0.0);
+            assert!((curve.gain(1.0) - 1.0).abs() < 1e-12);
+        }
+        assert!(FadeCurve::Exponential.gain(0.5) < FadeCurve::Linear.gain(0.5));
+        assert!((FadeCurve::EqualPower.gain(0.5) - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gain_envelope_interpolates_and_holds_endpoints() {
+        let mut audio = constant(10);
+        let envelope = [(Duration::from_millis(500), 1.0), (Duration::from_millis(1500), 0.0), (Duration::from_secs(2), 0.5)];
+        audio.apply_gain_envelope(&envelope).unwrap();
+        let left: Vec<f32> = audio.samples.iter().step_by(2).copied().collect();
+        assert_eq!(left, vec![1.0, 1.0, 1.0, 0.75, 0.5, 0.25, 0.0, 0.25, 0.5, 0.5]);
+        assert_eq!(audio.samples[7], 0.75);
+
+        let mut ducked = constant(2);
+        ducked.apply_gain_envelope(&[(Duration::ZERO, 0.5)]).unwrap();
+        assert_eq!(ducked.samples, vec![0.5; 4]);
+    }
+
+    #[test]
+    fn gain_envelope_rejects_invalid_points() {
+        let mut audio = constant(4);
+        assert!(matches!(audio.apply_gain_envelope(&[]), Err(BramError::InvalidParameter(_))));
+        assert!(matches!(audio.apply_gain_envelope(&[(Duration::ZERO, f32::NAN)]), Err(BramError::InvalidParameter(_))));
+        let unsorted = [(Duration::from_secs(1), 1.0), (Duration::ZERO, 0.0)];
+        assert!(matches!(audio.apply_gain_envelope(&unsorted), Err(BramError::InvalidParameter(_))));
+        assert_eq!(audio.samples, vec![1.0; 8]);
+    }
+
+    #[test]
+    fn slice_keeps_whole_interleaved_frames() {
+        let audio = AudioData { samples: (0..12).collect::<Vec<i16>>(), channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: Some(3) };
+        let sliced = audio.slice(Duration::from_millis(250), Duration::from_millis(760));
+        assert_eq!(sliced.samples, vec![2, 3, 4, 5]);
+        assert_eq!((sliced.channels, sliced.sample_rate, sliced.channel_mask), (2, 4, Some(3)));
+        assert_eq!(audio.slice(Duration::from_secs(1), Duration::from_secs(9)).samples, vec![8, 9, 10, 11]);
+        assert!(audio.slice(Duration::from_secs(2), Duration::from_secs(1)).samples.is_empty());
+    }
+
+    #[test]
+    fn concat_upmixes_mono_and_resamples_to_first_rate() {
+        let stereo = AudioData { samples: vec![1i16, 2, 3, 4], channels: 2, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        let mono = AudioData { samples: vec![5i16, 6], channels: 1, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        let joined = AudioData::concat(&[stereo.clone(), mono]).unwrap();
+        assert_eq!(joined.samples, vec![1, 2, 3, 4, 5, 5, 6, 6]);
+
+        let slower = AudioData { samples: vec![0i16; 100], channels: 2, sample_rate: 50, bits_per_sample: 16, channel_mask: None };
+        let joined = AudioData::concat(&[stereo.clone(), slower]).unwrap();
+        assert_eq!((joined.frames(), joined.sample_rate), (102, 100));
+
+        let surround = AudioData { samples: vec![0i16; 6], channels: 3, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        assert!(matches!(AudioData::concat(&[stereo, surround]), Err(BramError::InvalidParameter(_))));
+        assert!(matches!(AudioData::<i16>::concat(&[]), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn mix_sums_inputs_with_gain_and_pads_shorter_ones() {
+        let long = AudioData { samples: vec![0.25f32, 0.25, 0.25, 0.25], channels: 2, sample_rate: 100, bits_per_sample: 32, channel_mask: None };
+        let short = AudioData { samples: vec![0.5f32], channels: 1, sample_rate: 100, bits_per_sample: 32, channel_mask: None };
+        let mixed = AudioData::mix(&[MixInput::new(&long, 0.0), MixInput::new(&short, -6.0206)], MixHeadroom::Scale).unwrap();
+        assert_eq!(mixed.channels, 2);
+        assert!(mixed.samples[..2].iter().all(|sample| (sample - 0.5).abs() < 1e-4));
+        assert_eq!(&mixed.samples[2..], &[0.25, 0.25]);
+    }
+
+    #[test]
+    fn mix_headroom_scales_or_clips_overloads() {
+        let loud = AudioData { samples: vec![24576i16, 8192], channels: 1, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        let inputs = [MixInput::new(&loud, 0.0), MixInput::new(&loud, 0.0)];
+        let scaled = AudioData::mix(&inputs, MixHeadroom::Scale).unwrap();
+        assert_eq!(scaled.samples, vec![32767, 10923]);
+        let clipped = AudioData::mix(&inputs, MixHeadroom::Clip).unwrap();
+        assert_eq!(clipped.samples, vec![32767, 16384]);
+        assert!(matches!(AudioData::<i16>::mix(&[], MixHeadroom::Scale), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn fade_longer_than_audio_covers_whole_clip() {
+        let mut audio = constant(2);
+        audio.apply_fade_in(Duration::from_secs(10), FadeCurve::EqualPower);
+        assert_eq!(audio.samples[0], 0.0);
+        assert!((audio.samples[2] - 0.5f32.sqrt()).abs() < 1e-6);
+    }
+}
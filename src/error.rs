@@ -0,0 +1,125 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{error, fmt};
+#[cfg(feature = "std")]
+use std::io::{self, ErrorKind};
+use crate::wav_binary::HeaderDiagnosis;
+
+#[derive(Debug)]
+pub enum BramError {
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    NotAWavFile,
+    InvalidHeader,
+    Header(HeaderDiagnosis),
+    MissingChunk { id: [u8; 4] },
+    IncompleteChunk { id: [u8; 4], offset: usize },
+    UnsupportedFormat { tag: u16, bits: u16 },
+    UnsupportedChannels { channels: u16 },
+    InconsistentFormat { byte_rate: u32, expected: u64 },
+    Truncated { offset: usize, chunk: [u8; 4] },
+    InvalidParameter(&'static str),
+    Decode(String),
+    NonFiniteSamples { positions: Vec<usize> },
+    Cancelled,
+}
+
+impl fmt::Display for BramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            BramError::Io(error) => write!(f, "{}", error),
+            BramError::NotAWavFile => write!(f, "not a wav file"),
+            BramError::InvalidHeader => write!(f, "invalid file header"),
+            BramError::Header(diagnosis) => write!(f, "invalid wav header: {}", diagnosis),
+            BramError::MissingChunk { id } => write!(f, "no '{}' chunk found", String::from_utf8_lossy(id)),
+            BramError::IncompleteChunk { id, offset } => {
+                write!(f, "incomplete '{}' chunk at offset {}", String::from_utf8_lossy(id), offset)
+            }
+            BramError::UnsupportedFormat { tag, bits } => {
+                write!(f, "unsupported format 0x{:04X} with {} bits per sample", tag, bits)
+            }
+            BramError::UnsupportedChannels { channels } => write!(f, "unsupported channel count {}", channels),
+            BramError::InconsistentFormat { byte_rate, expected } => {
+                write!(f, "fmt chunk declares byte rate {} but sample rate and block align imply {}", byte_rate, expected)
+            }
+            BramError::Truncated { offset, chunk } => {
+                write!(f, "unexpected end of file at offset {} while reading '{}' chunk", offset, String::from_utf8_lossy(chunk))
+            }
+            BramError::InvalidParameter(reason) => write!(f, "invalid parameter: {}", reason),
+            BramError::Decode(reason) => write!(f, "{}", reason),
+            BramError::NonFiniteSamples { positions } => match positions.first() {
+                Some(first) => write!(f, "{} non-finite samples, first at index {}", positions.len(), first),
+                None => write!(f, "non-finite samples"),
+            },
+            BramError::Cancelled => write!(f, "operation cancelled"),
+        }
+    }
+}
+
+impl error::Error for BramError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            BramError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for BramError {
+    fn from(error: io::Error) -> Self {
+        BramError::Io(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<BramError> for io::Error {
+    fn from(error: BramError) -> Self {
+        let kind = match &error {
+            BramError::Io(error) => return io::Error::new(error.kind(), error.to_string()),
+            BramError::NotAWavFile | BramError::InvalidParameter(_) => ErrorKind::InvalidInput,
+            BramError::Cancelled => ErrorKind::Interrupted,
+            _ => ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    #[cfg(feature = "std")]
+    use std::io;
+    #[cfg(feature = "std")]
+    use std::io::ErrorKind;
+    use crate::error::BramError;
+
+    #[test]
+    fn display_names_the_missing_chunk() {
+        assert_eq!(BramError::MissingChunk { id: *b"fmt " }.to_string(), "no 'fmt ' chunk found");
+        assert_eq!(
+            BramError::UnsupportedFormat { tag: 0x55, bits: 0 }.to_string(),
+            "unsupported format 0x0055 with 0 bits per sample"
+        );
+    }
+
+    #[test]
+    fn display_reports_offset_and_chunk() {
+        assert_eq!(
+            BramError::Truncated { offset: 1_048_576, chunk: *b"data" }.to_string(),
+            "unexpected end of file at offset 1048576 while reading 'data' chunk"
+        );
+        assert_eq!(BramError::IncompleteChunk { id: *b"fmt ", offset: 12 }.to_string(), "incomplete 'fmt ' chunk at offset 12");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_to_and_from_io_error() {
+        let error = BramError::from(io::Error::new(ErrorKind::NotFound, "gone"));
+        assert!(matches!(error, BramError::Io(ref inner) if inner.kind() == ErrorKind::NotFound));
+        assert_eq!(io::Error::from(error).kind(), ErrorKind::NotFound);
+        assert_eq!(io::Error::from(BramError::NotAWavFile).kind(), ErrorKind::InvalidInput);
+        assert_eq!(io::Error::from(BramError::Truncated { offset: 44, chunk: *b"data" }).kind(), ErrorKind::InvalidData);
+    }
+}
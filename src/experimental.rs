@@ -0,0 +1,38 @@
+//! Subsystems whose API is not covered by semver yet.
+//!
+//! Everything here is only compiled with the `experimental` feature and may change
+//! or disappear in any minor release. Once an item has shipped unchanged through a
+//! minor release and has tests covering its edge cases, it is promoted: it moves to
+//! the crate root, and the old path here stays as a deprecated re-export for one
+//! minor release before removal.
+
+use std::path::Path;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::presentation_builder::Presentation;
+use crate::spectrum::SpectrumConfig;
+use crate::weighting::{Weighting, WeightingFilter};
+
+pub use crate::loudness::{Loudness, LoudnessAlignment};
+#[cfg(feature = "cpal")]
+pub use crate::playback::{play, PlaybackHandle};
+pub use crate::spectrogram::SpectrogramPresentation;
+
+pub fn apply_weighting(audio: &AudioData, weighting: Weighting) -> AudioData {
+    match WeightingFilter::new(weighting, audio.sample_rate, audio.channels) {
+        Some(mut filter) => AudioData {
+            samples: filter.process(&audio.samples),
+            ..audio.clone()
+        },
+        None => audio.clone(),
+    }
+}
+
+pub fn load_presentation_loudness_aligned(path: impl AsRef<Path>, rate: u32, target_lufs: f64) -> Result<StereoAudioPresentation, BramError> {
+    Presentation::builder(path).rate(rate).loudness(LoudnessAlignment::TargetLufs(target_lufs)).build_stereo()
+}
+
+pub fn load_spectrogram(path: impl AsRef<Path>, rate: u32, config: &SpectrumConfig) -> Result<SpectrogramPresentation, BramError> {
+    SpectrogramPresentation::new(&crate::load_audio(path)?, rate, config)
+}
@@ -0,0 +1,181 @@
+use std::io::Write;
+use std::sync::{Arc, PoisonError, RwLock};
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::format::DurationFormat;
+
+static EXPORTERS: RwLock<Vec<Arc<dyn PresentationExporter>>> = RwLock::new(Vec::new());
+
+pub trait PresentationExporter: Send + Sync {
+    fn name(&self) -> &str;
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JsonExporter {
+    pub duration_format: DurationFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CsvExporter {
+    pub duration_format: DurationFormat,
+}
+
+pub struct DatExporter;
+pub struct CborExporter;
+pub struct CompactExporter;
+
+impl PresentationExporter for JsonExporter {
+    fn name(&self) -> &str {
+        "json"
+    }
+
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+        writer.write_all(presentation.to_json_with_duration_format(self.duration_format).as_bytes())?;
+        Ok(())
+    }
+}
+
+impl PresentationExporter for CsvExporter {
+    fn name(&self) -> &str {
+        "csv"
+    }
+
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+        presentation.export_csv_with_duration_format(writer, self.duration_format)
+    }
+}
+
+impl PresentationExporter for DatExporter {
+    fn name(&self) -> &str {
+        "dat"
+    }
+
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+        writer.write_all(&presentation.to_dat())?;
+        Ok(())
+    }
+}
+
+impl PresentationExporter for CborExporter {
+    fn name(&self) -> &str {
+        "cbor"
+    }
+
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+        writer.write_all(&presentation.to_cbor())?;
+        Ok(())
+    }
+}
+
+impl PresentationExporter for CompactExporter {
+    fn name(&self) -> &str {
+        "bin"
+    }
+
+    fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+        writer.write_all(&presentation.to_compact())?;
+        Ok(())
+    }
+}
+
+pub fn register_exporter(exporter: impl PresentationExporter + 'static) {
+    EXPORTERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Arc::new(exporter));
+}
+
+pub fn find_exporter(name: &str) -> Option<Arc<dyn PresentationExporter>> {
+    exporters()
+        .into_iter()
+        .rev()
+        .find(|exporter| exporter.name().eq_ignore_ascii_case(name))
+}
+
+pub fn exporter_names() -> Vec<String> {
+    let mut names: Vec<String> = exporters().iter().map(|exporter| exporter.name().to_ascii_lowercase()).collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn exporters() -> Vec<Arc<dyn PresentationExporter>> {
+    let mut exporters: Vec<Arc<dyn PresentationExporter>> =
+        vec![Arc::new(JsonExporter::default()), Arc::new(CsvExporter::default()), Arc::new(DatExporter), Arc::new(CborExporter), Arc::new(CompactExporter)];
+    exporters.extend(EXPORTERS.read().unwrap_or_else(PoisonError::into_inner).iter().cloned());
+    exporters
+}
+
+#[cfg(test)]
+mod exporter_tests {
+    use std::io::Write;
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::error::BramError;
+    use crate::exporter::{exporter_names, find_exporter, register_exporter, CsvExporter, JsonExporter, PresentationExporter};
+    use crate::format::DurationFormat;
+
+    struct PointCountExporter;
+
+    impl PresentationExporter for PointCountExporter {
+        fn name(&self) -> &str {
+            "count"
+        }
+
+        fn write(&self, presentation: &StereoAudioPresentation, writer: &mut dyn Write) -> Result<(), BramError> {
+            write!(writer, "{}", presentation.left_channel_points.len())?;
+            Ok(())
+        }
+    }
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.0, 1.0],
+            right_channel_points: vec![1.0, 0.0],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 2,
+            source_channels: 2,
+            source_frames: 2,
+            samples_per_point: 1,
+        }
+    }
+
+    #[test]
+    fn built_in_exporters_match_presentation_methods() {
+        let mut out = Vec::new();
+        find_exporter("JSON").unwrap().write(&presentation(), &mut out).unwrap();
+        assert_eq!(out, presentation().to_json().into_bytes());
+        let mut out = Vec::new();
+        find_exporter("cbor").unwrap().write(&presentation(), &mut out).unwrap();
+        assert_eq!(out, presentation().to_cbor());
+        let mut out = Vec::new();
+        find_exporter("bin").unwrap().write(&presentation(), &mut out).unwrap();
+        assert_eq!(out, presentation().to_compact());
+        assert!(find_exporter("svg-unknown").is_none());
+    }
+
+    #[test]
+    fn exporters_apply_duration_format() {
+        let mut out = Vec::new();
+        CsvExporter { duration_format: DurationFormat::Samples }.write(&presentation(), &mut out).unwrap();
+        let mut expected = Vec::new();
+        presentation().export_csv_with_duration_format(&mut expected, DurationFormat::Samples).unwrap();
+        assert_eq!(out, expected);
+        let mut out = Vec::new();
+        JsonExporter { duration_format: DurationFormat::Timecode }.write(&presentation(), &mut out).unwrap();
+        assert_eq!(out, presentation().to_json_with_duration_format(DurationFormat::Timecode).into_bytes());
+    }
+
+    #[test]
+    fn registered_exporter_is_discoverable_by_name() {
+        register_exporter(PointCountExporter);
+        assert!(exporter_names().contains(&"count".to_string()));
+        assert!(exporter_names().contains(&"dat".to_string()));
+        let mut out = Vec::new();
+        find_exporter("count").unwrap().write(&presentation(), &mut out).unwrap();
+        assert_eq!(out, b"2");
+    }
+}
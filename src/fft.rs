@@ -0,0 +1,107 @@
+use std::f32::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct Complex {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex {
+    pub(crate) fn norm_sqr(self) -> f32 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+pub(crate) fn fft(buffer: &mut [Complex]) {
+    let n = buffer.len();
+    if n <= 1 {
+        return;
+    }
+    debug_assert!(n.is_power_of_two());
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let a = buffer[start + k];
+                let b = buffer[start + k + len / 2];
+                let t = Complex {
+                    re: b.re * cos - b.im * sin,
+                    im: b.re * sin + b.im * cos,
+                };
+                buffer[start + k] = Complex { re: a.re + t.re, im: a.im + t.im };
+                buffer[start + k + len / 2] = Complex { re: a.re - t.re, im: a.im - t.im };
+            }
+        }
+        len <<= 1;
+    }
+}
+
+pub(crate) fn hann_window(size: usize) -> Vec<f32> {
+    if size <= 1 {
+        return vec![1.0; size];
+    }
+    (0..size)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / (size - 1) as f32).cos())
+        .collect()
+}
+
+pub(crate) fn power_spectrum(frame: &[f32], window: &[f32]) -> Vec<f32> {
+    let size = frame.len().max(window.len()).next_power_of_two();
+    let mut buffer = vec![Complex::default(); size];
+    for (slot, (sample, weight)) in buffer.iter_mut().zip(frame.iter().zip(window)) {
+        slot.re = sample * weight;
+    }
+    fft(&mut buffer);
+    buffer[..size / 2 + 1].iter().map(|bin| bin.norm_sqr()).collect()
+}
+
+#[cfg(test)]
+mod fft_tests {
+    use crate::fft::{fft, hann_window, power_spectrum, Complex};
+
+    #[test]
+    fn fft_of_impulse_is_flat() {
+        let mut buffer = vec![Complex::default(); 8];
+        buffer[0].re = 1.0;
+        fft(&mut buffer);
+        assert!(buffer.iter().all(|bin| (bin.re - 1.0).abs() < 1e-6 && bin.im.abs() < 1e-6));
+    }
+
+    #[test]
+    fn power_spectrum_peaks_at_sine_bin() {
+        let frame: Vec<f32> = (0..64)
+            .map(|i| (2.0 * std::f32::consts::PI * 8.0 * i as f32 / 64.0).sin())
+            .collect();
+        let spectrum = power_spectrum(&frame, &[1.0; 64]);
+        assert_eq!(spectrum.len(), 33);
+        let peak = spectrum
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(bin, _)| bin);
+        assert_eq!(peak, Some(8));
+        assert!((spectrum[8] - 1024.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn hann_window_is_zero_at_edges() {
+        let window = hann_window(5);
+        assert_eq!(window[0], 0.0);
+        assert_eq!(window[2], 1.0);
+        assert!(window[4].abs() < 1e-6);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::analysis::{frames, mono_samples};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::fft;
+use crate::resample::ResampleQuality;
+
+const FINGERPRINT_SAMPLE_RATE: u32 = 5512;
+const FRAME_SIZE: usize = 2048;
+const HOP_SIZE: usize = 256;
+const LOWEST_FREQUENCY: f32 = 300.0;
+const HIGHEST_FREQUENCY: f32 = 2000.0;
+const MAX_ALIGNMENT_OFFSET: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    pub hashes: Vec<u32>,
+}
+
+impl Fingerprint {
+    pub fn similarity(&self, other: &Fingerprint) -> f32 {
+        let compare = |a: &[u32], b: &[u32]| {
+            let overlap = a.len().min(b.len());
+            let differing: u32 = a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum();
+            (overlap > 0).then(|| 1.0 - differing as f32 / (overlap * 32) as f32)
+        };
+        (0..=MAX_ALIGNMENT_OFFSET)
+            .flat_map(|offset| {
+                let shifted_self = self.hashes.get(offset..).and_then(|hashes| compare(hashes, &other.hashes));
+                let shifted_other = other.hashes.get(offset..).and_then(|hashes| compare(&self.hashes, hashes));
+                [shifted_self, shifted_other]
+            })
+            .flatten()
+            .fold(0.0, f32::max)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.hashes.iter().flat_map(|hash| hash.to_le_bytes()).collect()
+    }
+}
+
+pub fn fingerprint(audio: &AudioData) -> Result<Fingerprint, BramError> {
+    let resampled = audio.resample(FINGERPRINT_SAMPLE_RATE, ResampleQuality::Sinc)?;
+    let samples = mono_samples(&resampled);
+    let window = fft::hann_window(FRAME_SIZE);
+    let edges: Vec<usize> = (0..=33)
+        .map(|band| {
+            let frequency = LOWEST_FREQUENCY * (HIGHEST_FREQUENCY / LOWEST_FREQUENCY).powf(band as f32 / 33.0);
+            (frequency * FRAME_SIZE as f32 / FINGERPRINT_SAMPLE_RATE as f32).round() as usize
+        })
+        .collect();
+    let energies: Vec<Vec<f32>> = frames(&samples, FRAME_SIZE, HOP_SIZE)
+        .map(|frame| {
+            let spectrum = fft::power_spectrum(frame, &window);
+            edges.windows(2).map(|band| spectrum[band[0]..band[1].max(band[0] + 1)].iter().sum()).collect()
+        })
+        .collect();
+    let hashes = energies
+        .windows(2)
+        .map(|pair| {
+            (0..32).fold(0u32, |hash, band| {
+                let current = pair[1][band] - pair[1][band + 1];
+                let previous = pair[0][band] - pair[0][band + 1];
+                hash | (((current - previous) > 0.0) as u32) << band
+            })
+        })
+        .collect();
+    Ok(Fingerprint { hashes })
+}
+
+#[cfg(test)]
+mod fingerprint_tests {
+    use crate::audio_data::AudioData;
+    use crate::fingerprint::fingerprint;
+
+    fn melody(seed: u32, sample_rate: u32) -> AudioData {
+        let mut state = seed.wrapping_mul(2654435761).max(1);
+        let notes: Vec<[f32; 3]> = (0..12)
+            .map(|_| {
+                [(); 3].map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    250.0 + (state % 1600) as f32
+                })
+            })
+            .collect();
+        let note_length = sample_rate / 4;
+        AudioData {
+            samples: (0..note_length * notes.len() as u32)
+                .map(|i| {
+                    let time = i as f32 / sample_rate as f32;
+                    let value: f32 = notes[(i / note_length) as usize]
+                        .iter()
+                        .map(|frequency| (2.0 * std::f32::consts::PI * frequency * time).sin())
+                        .sum();
+                    (8000.0 * value) as i16
+                })
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn same_content_matches_across_gain_and_rate() {
+        let original = fingerprint(&melody(1, 11025)).unwrap();
+        assert!(!original.hashes.is_empty());
+        assert_eq!(original.to_bytes().len(), original.hashes.len() * 4);
+        assert_eq!(original.similarity(&original), 1.0);
+
+        let mut quieter = melody(1, 22050);
+        for sample in &mut quieter.samples {
+            *sample /= 3;
+        }
+        assert!(original.similarity(&fingerprint(&quieter).unwrap()) > 0.9);
+    }
+
+    #[test]
+    fn different_content_is_dissimilar() {
+        let first = fingerprint(&melody(1, 11025)).unwrap();
+        let second = fingerprint(&melody(2, 11025)).unwrap();
+        assert!(first.similarity(&second) < 0.75, "{}", first.similarity(&second));
+    }
+}
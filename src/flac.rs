@@ -0,0 +1,107 @@
+use std::io::Cursor;
+use std::path::Path;
+use claxon::FlacReader;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+
+pub(crate) fn has_flac_extension(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("flac"))
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<AudioData, BramError> {
+    let mut reader = FlacReader::new(Cursor::new(data))
+        .map_err(|error| BramError::Decode(error.to_string()))?;
+    let info = reader.streaminfo();
+    let bits_per_sample = info.bits_per_sample;
+    let mut samples = Vec::with_capacity(info.samples.unwrap_or(0) as usize * info.channels as usize);
+    for sample in reader.samples() {
+        let sample = sample.map_err(|error| BramError::Decode(error.to_string()))?;
+        let scaled = if bits_per_sample >= 16 {
+            sample >> (bits_per_sample - 16)
+        } else {
+            sample << (16 - bits_per_sample)
+        };
+        samples.push(scaled as i16);
+    }
+
+    Ok(AudioData {
+        samples,
+        channels: info.channels as u16,
+        sample_rate: info.sample_rate,
+        bits_per_sample: bits_per_sample as u16,
+        channel_mask: None,
+    })
+}
+
+#[cfg(test)]
+mod flac_tests {
+    use crate::flac::{has_flac_extension, parse};
+
+    fn crc8(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0u8, |mut crc, byte| {
+            crc ^= byte;
+            for _ in 0..8 {
+                crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+            }
+            crc
+        })
+    }
+
+    fn crc16(bytes: &[u8]) -> u16 {
+        bytes.iter().fold(0u16, |mut crc, byte| {
+            crc ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x8005 } else { crc << 1 };
+            }
+            crc
+        })
+    }
+
+    fn flac_bytes(sample_rate: u32, frames: &[[i16; 2]]) -> Vec<u8> {
+        let block_size = frames.len() as u64;
+        let mut data = Vec::new();
+        data.extend_from_slice(b"fLaC");
+        data.extend_from_slice(&[0x80, 0x00, 0x00, 0x22]);
+        data.extend_from_slice(&16u16.to_be_bytes());
+        data.extend_from_slice(&4096u16.to_be_bytes());
+        data.extend_from_slice(&[0; 6]);
+        let packed = ((sample_rate as u64) << 44) | (1 << 41) | (15 << 36) | block_size;
+        data.extend_from_slice(&packed.to_be_bytes());
+        data.extend_from_slice(&[0; 16]);
+
+        let mut frame = vec![0xFF, 0xF8, 0x60, 0x18, 0x00, (block_size - 1) as u8];
+        frame.push(crc8(&frame));
+        for channel in 0..2 {
+            frame.push(0x02);
+            for samples in frames {
+                frame.extend_from_slice(&samples[channel].to_be_bytes());
+            }
+        }
+        let crc = crc16(&frame);
+        frame.extend_from_slice(&crc.to_be_bytes());
+        data.extend_from_slice(&frame);
+        data
+    }
+
+    #[test]
+    fn parse_verbatim_stereo_flac() {
+        let bytes = flac_bytes(44100, &[[0, 0], [32767, -32768], [100, -100]]);
+        let audio = parse(&bytes).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.sample_rate, 44100);
+        assert_eq!(audio.samples, vec![0, 0, 32767, -32768, 100, -100]);
+    }
+
+    #[test]
+    fn parse_rejects_non_flac() {
+        assert!(parse(b"RIFF\x00\x00\x00\x00WAVE").is_err());
+    }
+
+    #[test]
+    fn has_flac_extension_is_case_insensitive() {
+        assert!(has_flac_extension("take.FLAC"));
+        assert!(!has_flac_extension("take.wav"));
+    }
+}
@@ -0,0 +1,81 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DurationFormat {
+    #[default]
+    Seconds,
+    Timecode,
+    Samples,
+}
+
+pub fn format_duration(frames: u64, sample_rate: u32, style: DurationFormat) -> String {
+    if style == DurationFormat::Samples || sample_rate == 0 {
+        return frames.to_string();
+    }
+    let millis = frames as u128 * 1000 / sample_rate as u128;
+    let (seconds, millis) = (millis / 1000, millis % 1000);
+    match style {
+        DurationFormat::Timecode => format!(
+            "{:02}:{:02}:{:02}.{:03}",
+            seconds / 3600,
+            (seconds / 60) % 60,
+            seconds % 60,
+            millis
+        ),
+        _ => format!("{}.{:03}", seconds, millis),
+    }
+}
+
+pub fn format_float(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return "null".to_string();
+    }
+    let formatted = format!("{:.*}", decimals, value);
+    if formatted.starts_with('-') && formatted[1..].bytes().all(|b| b == b'0' || b == b'.') {
+        formatted[1..].to_string()
+    } else {
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use crate::format::{format_duration, format_float, DurationFormat};
+
+    #[test]
+    fn format_duration_as_seconds() {
+        assert_eq!(format_duration(66150, 44100, DurationFormat::Seconds), "1.500");
+    }
+
+    #[test]
+    fn format_duration_as_timecode() {
+        let frames = (3600 + 2 * 60 + 3) * 48000 + 24000;
+        assert_eq!(format_duration(frames, 48000, DurationFormat::Timecode), "01:02:03.500");
+    }
+
+    #[test]
+    fn format_duration_as_samples() {
+        assert_eq!(format_duration(66150, 44100, DurationFormat::Samples), "66150");
+    }
+
+    #[test]
+    fn format_duration_with_zero_rate_falls_back_to_samples() {
+        assert_eq!(format_duration(10, 0, DurationFormat::Timecode), "10");
+    }
+
+    #[test]
+    fn format_float_uses_dot_separator() {
+        assert_eq!(format_float(0.5, 4), "0.5000");
+        assert_eq!(format_float(1234.5678, 2), "1234.57");
+    }
+
+    #[test]
+    fn format_float_removes_negative_zero() {
+        assert_eq!(format_float(-0.00001, 3), "0.000");
+        assert_eq!(format_float(-0.5, 1), "-0.5");
+    }
+
+    #[test]
+    fn format_float_maps_non_finite_to_null() {
+        assert_eq!(format_float(f64::NAN, 3), "null");
+        assert_eq!(format_float(f64::INFINITY, 3), "null");
+    }
+}
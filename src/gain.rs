@@ -0,0 +1,72 @@
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+
+impl<S: Sample> AudioData<S> {
+    pub fn normalize_peak(&self, target_dbfs: f64) -> Result<AudioData<S>, BramError> {
+        let peak = self.stats().peak;
+        self.normalized_to(peak, target_dbfs)
+    }
+
+    pub fn normalize_rms(&self, target_dbfs: f64) -> Result<AudioData<S>, BramError> {
+        let rms = self.stats().rms;
+        self.normalized_to(rms, target_dbfs)
+    }
+
+    fn normalized_to(&self, level: f64, target_dbfs: f64) -> Result<AudioData<S>, BramError> {
+        if !target_dbfs.is_finite() {
+            return Err(BramError::InvalidParameter("normalization target must be finite"));
+        }
+        if level == 0.0 {
+            return Ok(self.clone());
+        }
+        Ok(self.with_gain(10f64.powf(target_dbfs / 20.0) / level))
+    }
+
+    pub(crate) fn with_gain(&self, gain: f64) -> AudioData<S> {
+        AudioData {
+            samples: self.samples.iter().map(|sample| S::from_f64(sample.to_f64() * gain)).collect(),
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        }
+    }
+}
+
+#[cfg(test)]
+mod gain_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+
+    fn audio(samples: Vec<f32>) -> AudioData<f32> {
+        AudioData { samples, channels: 2, sample_rate: 8000, bits_per_sample: 32, channel_mask: None }
+    }
+
+    #[test]
+    fn normalize_peak_scales_loudest_sample_to_target() {
+        let normalized = audio(vec![0.25, -0.125, 0.0, 0.0625]).normalize_peak(-6.0).unwrap();
+        let expected = 10f32.powf(-6.0 / 20.0);
+        assert!((normalized.samples[0] - expected).abs() < 1e-6);
+        assert!((normalized.samples[1] + expected / 2.0).abs() < 1e-6);
+        assert_eq!(normalized.channels, 2);
+    }
+
+    #[test]
+    fn normalize_rms_reaches_target_level() {
+        let normalized = audio(vec![0.1, -0.1, 0.1, -0.1]).normalize_rms(-20.0).unwrap();
+        assert!((normalized.stats().rms - 0.1).abs() < 1e-6);
+        let louder = audio(vec![0.01, -0.01, 0.01, -0.01]).normalize_rms(-20.0).unwrap();
+        assert!((louder.samples[0] - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn integer_normalization_clamps_and_silence_is_unchanged() {
+        let samples = vec![16384i16, -32768];
+        let quiet = AudioData { samples, channels: 1, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+        assert_eq!(quiet.normalize_rms(6.0).unwrap().samples, vec![i16::MAX, i16::MIN]);
+        let silent = audio(vec![0.0; 4]);
+        assert_eq!(silent.normalize_peak(0.0).unwrap().samples, silent.samples);
+        assert!(matches!(silent.normalize_peak(f64::NAN), Err(BramError::InvalidParameter(_))));
+    }
+}
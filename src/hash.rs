@@ -0,0 +1,77 @@
+use crate::audio_data::AudioData;
+use crate::sample::Sample;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Fnv1a(FNV_OFFSET_BASIS)
+    }
+
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+        hasher.write(&self.channels.to_le_bytes());
+        hasher.write(&self.sample_rate.to_le_bytes());
+        for sample in &self.samples {
+            hasher.write(&sample.to_f64().to_bits().to_le_bytes());
+        }
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod hash_tests {
+    use crate::audio_data::AudioData;
+    use crate::wav_binary::WavBinary;
+
+    fn wav(extra_chunk: &[u8], samples: &[i16]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF\x00\x00\x00\x00WAVEfmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[1, 0, 1, 0]);
+        data.extend_from_slice(&8000u32.to_le_bytes());
+        data.extend_from_slice(&16000u32.to_le_bytes());
+        data.extend_from_slice(&[2, 0, 16, 0]);
+        data.extend_from_slice(extra_chunk);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&(samples.len() as u32 * 2).to_le_bytes());
+        for sample in samples {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn content_hash_ignores_metadata_chunks() {
+        let plain = AudioData::try_from(&WavBinary { data: wav(b"", &[1, 2, 3]) }).unwrap();
+        let tagged = AudioData::try_from(&WavBinary { data: wav(b"LIST\x04\x00\x00\x00INFO", &[1, 2, 3]) }).unwrap();
+        assert_eq!(plain.content_hash(), tagged.content_hash());
+
+        let different = AudioData::try_from(&WavBinary { data: wav(b"", &[1, 2, 4]) }).unwrap();
+        assert_ne!(plain.content_hash(), different.content_hash());
+        let resampled = AudioData { sample_rate: 16000, ..plain.clone() };
+        assert_ne!(plain.content_hash(), resampled.content_hash());
+    }
+
+    #[test]
+    fn content_hash_matches_across_sample_types() {
+        let audio = AudioData { samples: vec![0i16, 16384, -32768], channels: 1, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+        assert_eq!(audio.content_hash(), audio.convert::<i32>().content_hash());
+        assert_eq!(audio.content_hash(), audio.convert::<f32>().content_hash());
+    }
+}
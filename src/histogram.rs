@@ -0,0 +1,131 @@
+use std::ops::Range;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+
+const BLOCK_SECONDS: f64 = 3.0;
+const LOUDEST_BLOCK_FRACTION: f64 = 0.2;
+const NEAR_FULL_SCALE: f64 = 0.891_250_938;
+const SILENCE_DB: f64 = -120.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmplitudeHistogram {
+    pub counts: Vec<u64>,
+    pub samples: u64,
+    pub peak_db: f64,
+    pub rms_db: f64,
+    pub crest_factor_db: f64,
+    pub dynamic_range_db: f64,
+    pub near_full_scale_ratio: f64,
+}
+
+impl AmplitudeHistogram {
+    pub fn bin_range(&self, bin: usize) -> Range<f64> {
+        let width = 1.0 / self.counts.len().max(1) as f64;
+        bin as f64 * width..(bin + 1) as f64 * width
+    }
+
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        let target = (percentile.clamp(0.0, 100.0) / 100.0 * self.samples as f64).ceil().max(1.0) as u64;
+        let mut seen = 0u64;
+        for (bin, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return self.bin_range(bin).end;
+            }
+        }
+        0.0
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn histogram(&self, bins: usize) -> Result<AmplitudeHistogram, BramError> {
+        if bins == 0 {
+            return Err(BramError::InvalidParameter("histogram needs at least one bin"));
+        }
+        let channels = self.channels.max(1) as usize;
+        let mut counts = vec![0u64; bins];
+        let mut peak = 0.0f64;
+        let mut squares = 0.0f64;
+        let mut near_full_scale = 0u64;
+        for sample in &self.samples {
+            let amplitude = sample.to_f64().abs();
+            counts[((amplitude * bins as f64) as usize).min(bins - 1)] += 1;
+            peak = peak.max(amplitude);
+            squares += amplitude * amplitude;
+            if amplitude >= NEAR_FULL_SCALE {
+                near_full_scale += 1;
+            }
+        }
+        let samples = self.samples.len() as u64;
+        let rms = (squares / samples.max(1) as f64).sqrt();
+
+        let block_len = ((BLOCK_SECONDS * self.sample_rate as f64) as usize).max(1) * channels;
+        let mut block_powers: Vec<f64> = self
+            .samples
+            .chunks(block_len)
+            .map(|block| block.iter().map(|sample| sample.to_f64().powi(2)).sum::<f64>() / block.len() as f64)
+            .collect();
+        block_powers.sort_by(|a, b| b.total_cmp(a));
+        let loudest = &block_powers[..((block_powers.len() as f64 * LOUDEST_BLOCK_FRACTION).ceil() as usize).min(block_powers.len())];
+        let loud_rms = (loudest.iter().sum::<f64>() / loudest.len().max(1) as f64).sqrt();
+
+        Ok(AmplitudeHistogram {
+            counts,
+            samples,
+            peak_db: to_db(peak),
+            rms_db: to_db(rms),
+            crest_factor_db: if rms > 0.0 { to_db(peak) - to_db(rms) } else { 0.0 },
+            dynamic_range_db: if loud_rms > 0.0 { to_db(peak) - to_db(loud_rms) } else { 0.0 },
+            near_full_scale_ratio: near_full_scale as f64 / samples.max(1) as f64,
+        })
+    }
+}
+
+fn to_db(level: f64) -> f64 {
+    if level > 0.0 { (20.0 * level.log10()).max(SILENCE_DB) } else { SILENCE_DB }
+}
+
+#[cfg(test)]
+mod histogram_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+
+    fn audio(samples: Vec<f32>, sample_rate: u32) -> AudioData<f32> {
+        AudioData { samples, channels: 1, sample_rate, bits_per_sample: 32, channel_mask: None }
+    }
+
+    #[test]
+    fn histogram_counts_absolute_amplitudes() {
+        let histogram = audio(vec![0.0, 0.1, -0.3, 0.6, -1.0, 1.5], 8).histogram(4).unwrap();
+        assert_eq!(histogram.counts, vec![2, 1, 1, 2]);
+        assert_eq!(histogram.samples, 6);
+        assert_eq!(histogram.bin_range(1), 0.25..0.5);
+        assert_eq!(histogram.percentile(50.0), 0.5);
+        assert_eq!(histogram.percentile(100.0), 1.0);
+        assert!((histogram.near_full_scale_ratio - 2.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn squashed_master_has_less_dynamic_range() {
+        let sine = |i: usize| (2.0 * std::f32::consts::PI * 50.0 * i as f32 / 1000.0).sin();
+        let dynamic: Vec<f32> = (0..30_000).map(|i| sine(i) * if (i / 3000) % 2 == 0 { 0.9 } else { 0.05 }).collect();
+        let squashed: Vec<f32> = (0..30_000).map(|i| (sine(i) * 4.0).clamp(-0.9, 0.9)).collect();
+        let dynamic = audio(dynamic, 1000).histogram(32).unwrap();
+        let squashed = audio(squashed, 1000).histogram(32).unwrap();
+        assert!((dynamic.crest_factor_db - squashed.crest_factor_db) > 3.0);
+        assert!(squashed.crest_factor_db < 1.5);
+        assert!(dynamic.dynamic_range_db > squashed.dynamic_range_db + 2.0);
+        assert!(squashed.near_full_scale_ratio > 0.5);
+        assert!((squashed.peak_db - 20.0 * 0.9f64.log10()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn histogram_rejects_zero_bins_and_handles_silence() {
+        assert!(matches!(audio(vec![0.0], 8).histogram(0), Err(BramError::InvalidParameter(_))));
+        let silent = audio(Vec::new(), 8).histogram(8).unwrap();
+        assert_eq!(silent.samples, 0);
+        assert_eq!(silent.peak_db, -120.0);
+        assert_eq!((silent.crest_factor_db, silent.dynamic_range_db), (0.0, 0.0));
+    }
+}
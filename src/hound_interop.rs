@@ -0,0 +1,129 @@
+use std::io::{Read, Seek, Write};
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+
+impl<R: Read, S: Sample> TryFrom<WavReader<R>> for AudioData<S> {
+    type Error = BramError;
+
+    fn try_from(mut reader: WavReader<R>) -> Result<Self, Self::Error> {
+        let spec = reader.spec();
+        let samples = match spec.sample_format {
+            SampleFormat::Float => reader
+                .samples::<f32>()
+                .map(|sample| sample.map(|sample| S::from_f64(sample as f64)))
+                .collect::<Result<Vec<S>, _>>(),
+            SampleFormat::Int => {
+                let shift = 32 - spec.bits_per_sample.clamp(1, 32) as u32;
+                reader
+                    .samples::<i32>()
+                    .map(|sample| sample.map(|sample| S::from_i32(sample << shift)))
+                    .collect::<Result<Vec<S>, _>>()
+            }
+        }
+        .map_err(hound_error)?;
+        Ok(AudioData {
+            samples,
+            channels: spec.channels,
+            sample_rate: spec.sample_rate,
+            bits_per_sample: spec.bits_per_sample,
+            channel_mask: None,
+        })
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn hound_spec(&self) -> WavSpec {
+        WavSpec {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            bits_per_sample: S::BITS,
+            sample_format: if S::FLOAT { SampleFormat::Float } else { SampleFormat::Int },
+        }
+    }
+
+    pub fn write_to_hound<W: Write + Seek>(&self, writer: &mut WavWriter<W>) -> Result<(), BramError> {
+        let spec = writer.spec();
+        if spec.channels != self.channels {
+            return Err(BramError::InvalidParameter("hound writer channel count does not match the audio"));
+        }
+        for sample in &self.samples {
+            match spec.sample_format {
+                SampleFormat::Float => writer.write_sample(sample.to_f64() as f32),
+                SampleFormat::Int => {
+                    let scale = (1i64 << (spec.bits_per_sample.clamp(1, 32) - 1)) as f64;
+                    let value = (sample.to_f64() * scale).round().clamp(-scale, scale - 1.0) as i32;
+                    writer.write_sample(value)
+                }
+            }
+            .map_err(hound_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn hound_error(error: hound::Error) -> BramError {
+    match error {
+        hound::Error::IoError(error) => BramError::Io(error),
+        other => BramError::Decode(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod hound_interop_tests {
+    use std::io::Cursor;
+    use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::wav_binary::WavBinary;
+    use crate::wav_writer::encode_wav;
+
+    fn audio() -> AudioData {
+        AudioData { samples: vec![0, 1, -1, i16::MAX, i16::MIN, 12345], channels: 2, sample_rate: 22050, bits_per_sample: 16, channel_mask: None }
+    }
+
+    #[test]
+    fn hound_reader_matches_native_parser() {
+        let bytes = encode_wav(&audio());
+        let through_hound = AudioData::<i16>::try_from(WavReader::new(Cursor::new(&bytes)).unwrap()).unwrap();
+        let native = AudioData::try_from(&WavBinary::from_bytes(bytes.clone()).unwrap()).unwrap();
+        assert_eq!(through_hound.samples, native.samples);
+        assert_eq!((through_hound.channels, through_hound.sample_rate, through_hound.bits_per_sample), (2, 22050, 16));
+        let as_float = AudioData::<f32>::try_from(WavReader::new(Cursor::new(&bytes)).unwrap()).unwrap();
+        assert_eq!(as_float.samples, audio().convert::<f32>().samples);
+    }
+
+    #[test]
+    fn audio_writes_through_hound_writer() {
+        let round_trip = |bytes: Cursor<Vec<u8>>| AudioData::<i16>::try_from(WavReader::new(Cursor::new(bytes.into_inner())).unwrap()).unwrap();
+        let float = audio().convert::<f32>();
+        let mut bytes = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut bytes, float.hound_spec()).unwrap();
+        float.write_to_hound(&mut writer).unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(round_trip(bytes).samples, audio().samples);
+
+        let mut bytes = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut bytes, audio().hound_spec()).unwrap();
+        audio().write_to_hound(&mut writer).unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(round_trip(bytes).samples, audio().samples);
+
+        let spec = WavSpec { channels: 2, sample_rate: 22050, bits_per_sample: 24, sample_format: SampleFormat::Int };
+        let mut bytes = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut bytes, spec).unwrap();
+        audio().write_to_hound(&mut writer).unwrap();
+        writer.finalize().unwrap();
+        let reader = WavReader::new(Cursor::new(bytes.into_inner())).unwrap();
+        assert_eq!(reader.into_samples::<i32>().map(Result::unwrap).nth(3), Some(i16::MAX as i32 * 256));
+    }
+
+    #[test]
+    fn mismatched_writer_spec_is_rejected() {
+        let spec = WavSpec { channels: 1, sample_rate: 22050, bits_per_sample: 16, sample_format: SampleFormat::Int };
+        let mut bytes = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut bytes, spec).unwrap();
+        assert!(matches!(audio().write_to_hound(&mut writer), Err(BramError::InvalidParameter(_))));
+    }
+}
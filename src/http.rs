@@ -0,0 +1,165 @@
+use std::io::{self, Read, Seek, SeekFrom};
+use crate::audio_presentation::{DownsampleStrategy, NormalizationMode, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::probe::{self, WavInfo};
+use crate::weighting::Weighting;
+
+const RANGE_BLOCK: u64 = 64 * 1024;
+
+pub fn load_presentation_from_url(url: &str, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let response = ureq::get(url).call().map_err(io::Error::other)?;
+    let reader = response.into_body().into_reader();
+    crate::presentation_from_stream(reader, rate, DownsampleStrategy::Decimate, Weighting::None, NormalizationMode::ZeroToOne)
+}
+
+pub fn probe_url(url: &str) -> Result<WavInfo, BramError> {
+    probe::probe_reader(RangeReader::open(url)?)
+}
+
+struct RangeReader {
+    url: String,
+    len: u64,
+    position: u64,
+    block_start: u64,
+    block: Vec<u8>,
+}
+
+impl RangeReader {
+    fn open(url: &str) -> Result<Self, BramError> {
+        let mut reader = RangeReader { url: url.to_string(), len: 0, position: 0, block_start: 0, block: Vec::new() };
+        reader.len = reader.fetch(0)?;
+        Ok(reader)
+    }
+
+    fn fetch(&mut self, start: u64) -> io::Result<u64> {
+        let response = ureq::get(&self.url)
+            .header("Range", format!("bytes={}-{}", start, start + RANGE_BLOCK - 1))
+            .call()
+            .map_err(io::Error::other)?;
+        let total = response
+            .headers()
+            .get("Content-Range")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit('/').next())
+            .and_then(|total| total.parse().ok());
+        let Some(total) = total.filter(|_| response.status() == 206) else {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "server does not support range requests"));
+        };
+        self.block.clear();
+        response.into_body().into_reader().take(RANGE_BLOCK).read_to_end(&mut self.block)?;
+        self.block_start = start;
+        Ok(total)
+    }
+}
+
+impl Read for RangeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position >= self.len {
+            return Ok(0);
+        }
+        let block_end = self.block_start + self.block.len() as u64;
+        if self.position < self.block_start || self.position >= block_end {
+            self.fetch(self.position)?;
+        }
+        let offset = (self.position - self.block_start) as usize;
+        let count = buf.len().min(self.block.len() - offset);
+        buf[..count].copy_from_slice(&self.block[offset..offset + count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Seek for RangeReader {
+    fn seek(&mut self, position: SeekFrom) -> io::Result<u64> {
+        let target = match position {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = target.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek before start of resource"))?;
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+mod http_tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
+    use crate::error::BramError;
+    use crate::http::{load_presentation_from_url, probe_url};
+    use crate::wav_writer::encode_wav;
+
+    fn serve(body: Vec<u8>, ranges: bool, requests: usize) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/upload.wav", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut range = None;
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("range: bytes=") {
+                        range = Some(value.trim().to_string());
+                    }
+                }
+                recorded.lock().unwrap().push(range.clone());
+                let (status, content_range, slice) = match range.filter(|_| ranges) {
+                    Some(range) => {
+                        let (start, end) = range.split_once('-').unwrap();
+                        let start: usize = start.parse().unwrap();
+                        let end = (end.parse::<usize>().unwrap() + 1).min(body.len());
+                        let header = format!("Content-Range: bytes {}-{}/{}\r\n", start, end - 1, body.len());
+                        ("206 Partial Content", header, &body[start..end])
+                    }
+                    None => ("200 OK", String::new(), &body[..]),
+                };
+                let head = format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n", status, content_range, slice.len());
+                stream.write_all(head.as_bytes()).unwrap();
+                stream.write_all(slice).unwrap();
+            }
+        });
+        (url, seen)
+    }
+
+    fn audio() -> AudioData {
+        let samples = (0..80_000).map(|index| ((index * 37) % 3000) as i16 - 1500).collect();
+        AudioData { samples, channels: 2, sample_rate: 8000, bits_per_sample: 16, channel_mask: None }
+    }
+
+    #[test]
+    fn presentation_streams_from_url() {
+        let (url, seen) = serve(encode_wav(&audio()), false, 1);
+        let presentation = load_presentation_from_url(&url, 10).unwrap();
+        let expected = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio(), 10)).unwrap();
+        assert_eq!(presentation.left_channel_points, expected.left_channel_points);
+        assert_eq!(presentation.right_channel_points, expected.right_channel_points);
+        assert_eq!(seen.lock().unwrap().as_slice(), &[None]);
+    }
+
+    #[test]
+    fn probe_reads_only_the_header_range() {
+        let bytes = encode_wav(&audio());
+        let (url, seen) = serve(bytes.clone(), true, 1);
+        let info = probe_url(&url).unwrap();
+        assert_eq!((info.channels, info.sample_rate, info.frames), (2, 8000, 40_000));
+        assert_eq!(info.data_size, bytes.len() - 44);
+        assert_eq!(seen.lock().unwrap().as_slice(), &[Some("0-65535".to_string())]);
+    }
+
+    #[test]
+    fn probe_requires_range_support() {
+        let (url, _) = serve(encode_wav(&audio()), false, 1);
+        assert!(matches!(probe_url(&url), Err(BramError::Io(_))));
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use notify::event::{AccessKind, AccessMode, ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use crate::audio_data::AudioData;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::peaks_store::{content_hash, PeaksKey, PeaksStore};
+use crate::probe::WavInfo;
+use crate::warning::Warning;
+use crate::wav_binary::WavBinary;
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Clone)]
+pub enum IngestStep {
+    Probe,
+    Validate,
+    Presentation { rate: u32 },
+    WritePeaks { store: Arc<dyn PeaksStore + Send + Sync>, rate: u32 },
+}
+
+impl fmt::Debug for IngestStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IngestStep::Probe => f.write_str("Probe"),
+            IngestStep::Validate => f.write_str("Validate"),
+            IngestStep::Presentation { rate } => f.debug_struct("Presentation").field("rate", rate).finish(),
+            IngestStep::WritePeaks { rate, .. } => f.debug_struct("WritePeaks").field("rate", rate).finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum IngestEvent {
+    Started(PathBuf),
+    Probed { path: PathBuf, info: WavInfo },
+    Validated { path: PathBuf, warnings: Vec<Warning> },
+    PresentationReady { path: PathBuf, presentation: StereoAudioPresentation },
+    PeaksWritten { path: PathBuf, key: PeaksKey },
+    Failed { path: PathBuf, step: IngestStep, error: BramError },
+    Finished(PathBuf),
+}
+
+pub struct Ingestor {
+    folder: PathBuf,
+    steps: Vec<IngestStep>,
+    debounce: Duration,
+}
+
+pub struct IngestHandle {
+    watcher: RecommendedWatcher,
+    worker: thread::JoinHandle<()>,
+}
+
+impl Ingestor {
+    pub fn new(folder: impl Into<PathBuf>) -> Self {
+        Self { folder: folder.into(), steps: Vec::new(), debounce: DEFAULT_DEBOUNCE }
+    }
+
+    pub fn step(mut self, step: IngestStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn process(&self, path: &Path, on_event: &mut impl FnMut(IngestEvent)) {
+        on_event(IngestEvent::Started(path.to_path_buf()));
+        for step in &self.steps {
+            let outcome = match step {
+                IngestStep::Probe => crate::probe(path).map(|info| IngestEvent::Probed { path: path.to_path_buf(), info }),
+                IngestStep::Validate => WavBinary::from_file(path)
+                    .and_then(|wav| AudioData::try_from_checked(&wav))
+                    .map(|(_, warnings)| IngestEvent::Validated { path: path.to_path_buf(), warnings }),
+                IngestStep::Presentation { rate } => crate::load_presentation(path, *rate)
+                    .map(|presentation| IngestEvent::PresentationReady { path: path.to_path_buf(), presentation }),
+                IngestStep::WritePeaks { store, rate } => write_peaks(path, store.as_ref(), *rate)
+                    .map(|key| IngestEvent::PeaksWritten { path: path.to_path_buf(), key }),
+            };
+            match outcome {
+                Ok(event) => on_event(event),
+                Err(error) => {
+                    on_event(IngestEvent::Failed { path: path.to_path_buf(), step: step.clone(), error });
+                    return;
+                }
+            }
+        }
+        on_event(IngestEvent::Finished(path.to_path_buf()));
+    }
+
+    pub fn watch(self, mut on_event: impl FnMut(IngestEvent) + Send + 'static) -> Result<IngestHandle, BramError> {
+        let (sender, receiver) = mpsc::channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+        watcher
+            .watch(&self.folder, RecursiveMode::NonRecursive)
+            .map_err(io::Error::other)?;
+
+        let worker = thread::spawn(move || {
+            let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+            loop {
+                match receiver.recv_timeout(self.debounce) {
+                    Ok(Ok(event)) => {
+                        let touched = matches!(
+                            event.kind,
+                            EventKind::Create(_)
+                                | EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Any | ModifyKind::Name(RenameMode::To))
+                                | EventKind::Access(AccessKind::Close(AccessMode::Write))
+                        );
+                        for path in event.paths.into_iter().filter(|path| is_wav(path)) {
+                            if touched {
+                                pending.insert(path, Instant::now());
+                            } else if matches!(event.kind, EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From))) {
+                                pending.remove(&path);
+                            }
+                        }
+                    }
+                    Ok(Err(_)) | Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                let mut settled: Vec<PathBuf> = pending
+                    .iter()
+                    .filter(|(_, touched)| touched.elapsed() >= self.debounce)
+                    .map(|(path, _)| path.clone())
+                    .collect();
+                settled.sort();
+                for path in settled {
+                    pending.remove(&path);
+                    self.process(&path, &mut on_event);
+                }
+            }
+        });
+
+        Ok(IngestHandle { watcher, worker })
+    }
+}
+
+impl IngestHandle {
+    pub fn stop(self) {
+        drop(self.watcher);
+        let _ = self.worker.join();
+    }
+}
+
+fn write_peaks(path: &Path, store: &dyn PeaksStore, rate: u32) -> Result<PeaksKey, BramError> {
+    let key = PeaksKey { content_hash: content_hash(&fs::read(path)?), rate };
+    store.put(&key, &crate::load_presentation(path, rate)?)?;
+    Ok(key)
+}
+
+fn is_wav(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+}
+
+#[cfg(test)]
+mod ingest_tests {
+    use std::fs;
+    use std::sync::mpsc;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use crate::ingest::{IngestEvent, IngestStep, Ingestor};
+    use crate::peaks_store::{FsPeaksStore, PeaksStore};
+
+    fn write_temp_wav(filename: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(filename);
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&44u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x00, 0x02, 0x00]);
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x04, 0x00, 0x10, 0x00]);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&8u32.to_le_bytes());
+        data.extend_from_slice(&[0x00, 0x00, 0xFF, 0x7F, 0x00, 0x80, 0x01, 0x00]);
+        fs::write(&path, data).unwrap();
+        path
+    }
+
+    #[test]
+    fn process_runs_steps_in_order() {
+        let path = write_temp_wav("ingest_process.wav");
+        let ingestor = Ingestor::new(std::env::temp_dir())
+            .step(IngestStep::Validate)
+            .step(IngestStep::Presentation { rate: 2 });
+        let mut events = Vec::new();
+        ingestor.process(&path, &mut |event| events.push(event));
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], IngestEvent::Started(_)));
+        assert!(matches!(&events[1], IngestEvent::Validated { warnings, .. } if warnings.is_empty()));
+        assert!(matches!(&events[2], IngestEvent::PresentationReady { presentation, .. } if presentation.left_channel_points.len() == 1));
+        assert!(matches!(events[3], IngestEvent::Finished(_)));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn process_stops_at_failing_step() {
+        let path = std::env::temp_dir().join("ingest_missing.wav");
+        let ingestor = Ingestor::new(std::env::temp_dir())
+            .step(IngestStep::Validate)
+            .step(IngestStep::Presentation { rate: 2 });
+        let mut events = Vec::new();
+        ingestor.process(&path, &mut |event| events.push(event));
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], IngestEvent::Failed { step: IngestStep::Validate, .. }));
+    }
+
+    #[test]
+    fn process_probes_and_writes_peaks() {
+        let path = write_temp_wav("ingest_peaks.wav");
+        let root = std::env::temp_dir().join("bram_ingest_peaks_store");
+        let _ = fs::remove_dir_all(&root);
+        let store = Arc::new(FsPeaksStore::new(&root));
+        let ingestor = Ingestor::new(std::env::temp_dir())
+            .step(IngestStep::Probe)
+            .step(IngestStep::WritePeaks { store: store.clone(), rate: 2 });
+        let mut events = Vec::new();
+        ingestor.process(&path, &mut |event| events.push(event));
+        assert_eq!(events.len(), 4);
+        assert!(matches!(&events[1], IngestEvent::Probed { info, .. } if info.channels == 2 && info.frames == 2));
+        let IngestEvent::PeaksWritten { key, .. } = &events[2] else { panic!("expected peaks to be written") };
+        assert_eq!(key.rate, 2);
+        assert_eq!(store.get(key).unwrap().unwrap().left_channel_points.len(), 1);
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn watch_processes_each_new_file_once() {
+        let folder = std::env::temp_dir().join("bram_ingest_watch");
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(&folder).unwrap();
+        let (sender, receiver) = mpsc::channel();
+        let handle = Ingestor::new(&folder)
+            .step(IngestStep::Validate)
+            .debounce(Duration::from_millis(50))
+            .watch(move |event| sender.send(event).unwrap())
+            .unwrap();
+
+        let source = write_temp_wav("ingest_watch_source.wav");
+        fs::copy(&source, folder.join("copied.wav")).unwrap();
+        fs::write(folder.join("notes.txt"), b"ignored").unwrap();
+        let mut events = Vec::new();
+        while let Ok(event) = receiver.recv_timeout(Duration::from_millis(500)) {
+            events.push(event);
+        }
+        handle.stop();
+        assert_eq!(events.len(), 3, "{events:?}");
+        assert!(matches!(&events[0], IngestEvent::Started(path) if path.ends_with("copied.wav")));
+        assert!(matches!(events[2], IngestEvent::Finished(_)));
+        let _ = fs::remove_file(source);
+        let _ = fs::remove_dir_all(folder);
+    }
+}
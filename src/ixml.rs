@@ -0,0 +1,114 @@
+use crate::audio_data::AudioData;
+use crate::wav_binary::WavBinary;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TrackGain {
+    pub channel: usize,
+    pub gain_db: f32,
+}
+
+pub(crate) fn read_track_gains(wav: &WavBinary) -> Vec<TrackGain> {
+    let Some((_, offset, size)) = wav.chunk_list().into_iter().find(|(id, _, _)| id == b"iXML") else {
+        return Vec::new();
+    };
+    let xml = String::from_utf8_lossy(&wav.data[offset..offset + size]);
+    let mut gains = Vec::new();
+    let mut rest = xml.as_ref();
+    while let Some(track) = element(rest, "TRACK") {
+        rest = &rest[rest.find("</TRACK>").map_or(rest.len(), |end| end + 8)..];
+        let channel = element(track, "INTERLEAVE_INDEX")
+            .or_else(|| element(track, "CHANNEL_INDEX"))
+            .and_then(|index| index.trim().parse::<usize>().ok())
+            .filter(|index| *index > 0);
+        let gain_db = element(track, "GAIN")
+            .or_else(|| element(track, "TRIM"))
+            .and_then(parse_db);
+        if let (Some(channel), Some(gain_db)) = (channel, gain_db) {
+            gains.push(TrackGain { channel: channel - 1, gain_db });
+        }
+    }
+    gains
+}
+
+pub(crate) fn apply_track_gains(audio: &mut AudioData, gains: &[TrackGain]) {
+    let channels = audio.channels.max(1) as usize;
+    for gain in gains.iter().filter(|gain| gain.channel < channels) {
+        let factor = 10f32.powf(gain.gain_db / 20.0);
+        for sample in audio.samples.iter_mut().skip(gain.channel).step_by(channels) {
+            *sample = (*sample as f32 * factor).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        }
+    }
+}
+
+fn element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&close)?;
+    Some(&xml[start..end])
+}
+
+fn parse_db(value: &str) -> Option<f32> {
+    let value = value.trim();
+    let value = value
+        .strip_suffix("dB")
+        .or_else(|| value.strip_suffix("db"))
+        .unwrap_or(value);
+    value.trim().parse::<f32>().ok().filter(|gain| gain.is_finite())
+}
+
+#[cfg(test)]
+mod ixml_tests {
+    use crate::audio_data::AudioData;
+    use crate::ixml::{apply_track_gains, read_track_gains, TrackGain};
+    use crate::wav_binary::WavBinary;
+
+    fn wav_with_ixml(xml: &str) -> WavBinary {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"iXML");
+        data.extend_from_slice(&(xml.len() as u32).to_le_bytes());
+        data.extend_from_slice(xml.as_bytes());
+        if xml.len() % 2 == 1 {
+            data.push(0);
+        }
+        WavBinary { data }
+    }
+
+    #[test]
+    fn read_track_gains_from_track_list() {
+        let wav = wav_with_ixml(
+            "<BWFXML><TRACK_LIST><TRACK><CHANNEL_INDEX>1</CHANNEL_INDEX><INTERLEAVE_INDEX>1</INTERLEAVE_INDEX>\
+             <NAME>Boom</NAME><GAIN>-6 dB</GAIN></TRACK><TRACK><INTERLEAVE_INDEX>2</INTERLEAVE_INDEX>\
+             <NAME>Lav</NAME></TRACK><TRACK><CHANNEL_INDEX>3</CHANNEL_INDEX><TRIM>2.5</TRIM></TRACK>\
+             </TRACK_LIST></BWFXML>",
+        );
+        assert_eq!(read_track_gains(&wav), vec![
+            TrackGain { channel: 0, gain_db: -6.0 },
+            TrackGain { channel: 2, gain_db: 2.5 },
+        ]);
+    }
+
+    #[test]
+    fn read_track_gains_without_ixml_is_empty() {
+        assert!(read_track_gains(&WavBinary { data: b"RIFF\x00\x00\x00\x00WAVE".to_vec() }).is_empty());
+    }
+
+    #[test]
+    fn apply_track_gains_scales_only_the_tagged_lane() {
+        let mut audio = AudioData {
+            samples: vec![1000, 1000, 30000, 30000],
+            channels: 2,
+            sample_rate: 2,
+            bits_per_sample: 16,
+            channel_mask: None,
+        };
+        apply_track_gains(&mut audio, &[
+            TrackGain { channel: 1, gain_db: 6.0 },
+            TrackGain { channel: 5, gain_db: -20.0 },
+        ]);
+        assert_eq!(audio.samples, vec![1000, 1995, 30000, 32767]);
+    }
+}
@@ -0,0 +1,460 @@
+use crate::annotation::{Annotation, AnnotationKind};
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::format::{format_duration, format_float, DurationFormat};
+
+const SCHEMA_VERSION: u32 = 1;
+const ANNOTATIONS_VERSION: u32 = 1;
+const POINT_DECIMALS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub(crate) fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(fields) => fields.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => Some(*value),
+            JsonValue::Null => Some(f64::NAN),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            JsonValue::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn parse(input: &str) -> Result<JsonValue, BramError> {
+    let mut parser = Parser { bytes: input.as_bytes(), pos: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos != parser.bytes.len() {
+        return Err(parser.error("trailing characters"));
+    }
+    Ok(value)
+}
+
+pub(crate) fn write_points(out: &mut String, points: &[f32]) {
+    out.push('[');
+    for (index, point) in points.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&format_float(*point as f64, POINT_DECIMALS));
+    }
+    out.push(']');
+}
+
+fn write_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_annotations(out: &mut String, annotations: &[Annotation]) {
+    out.push_str(&format!("{{\"version\":{},\"items\":[", ANNOTATIONS_VERSION));
+    for (index, annotation) in annotations.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&format!(
+            "{{\"start_point\":{},\"end_point\":{},\"kind\":\"{}\",\"label\":",
+            annotation.start_point,
+            annotation.end_point,
+            kind_name(annotation.kind),
+        ));
+        write_string(out, &annotation.label);
+        out.push('}');
+    }
+    out.push_str("]}");
+}
+
+fn read_annotations(value: Option<&JsonValue>) -> Result<Vec<Annotation>, BramError> {
+    let Some(value) = value else {
+        return Ok(Vec::new());
+    };
+    let invalid = || BramError::Decode("invalid presentation annotations".to_string());
+    if value.get("version").and_then(JsonValue::as_f64) != Some(ANNOTATIONS_VERSION as f64) {
+        return Err(BramError::Decode("unsupported annotations version".to_string()));
+    }
+    value
+        .get("items")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|item| {
+            let point = |key: &str| item.get(key).and_then(JsonValue::as_f64).filter(|point| *point >= 0.0);
+            Ok(Annotation {
+                start_point: point("start_point").ok_or_else(invalid)? as usize,
+                end_point: point("end_point").ok_or_else(invalid)? as usize,
+                kind: item.get("kind").and_then(JsonValue::as_str).and_then(kind_from_name).ok_or_else(invalid)?,
+                label: item.get("label").and_then(JsonValue::as_str).ok_or_else(invalid)?.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn kind_name(kind: AnnotationKind) -> &'static str {
+    match kind {
+        AnnotationKind::Synthetic => "synthetic",
+        AnnotationKind::Boundary => "boundary",
+        AnnotationKind::Marker => "marker",
+    }
+}
+
+fn kind_from_name(name: &str) -> Option<AnnotationKind> {
+    match name {
+        "synthetic" => Some(AnnotationKind::Synthetic),
+        "boundary" => Some(AnnotationKind::Boundary),
+        "marker" => Some(AnnotationKind::Marker),
+        _ => None,
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, reason: &str) -> BramError {
+        BramError::Decode(format!("invalid json at byte {}: {}", self.pos, reason))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.bytes.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), BramError> {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) != Some(&byte) {
+            return Err(self.error(&format!("expected '{}'", byte as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, BramError> {
+        if !self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            return Err(self.error("unknown literal"));
+        }
+        self.pos += word.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<JsonValue, BramError> {
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b'n') => self.literal("null", JsonValue::Null),
+            Some(b't') => self.literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.literal("false", JsonValue::Bool(false)),
+            Some(_) => self.number(),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> Result<JsonValue, BramError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<JsonValue, BramError> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array(values));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, BramError> {
+        if self.bytes.get(self.pos) != Some(&b'"') {
+            return Err(self.error("expected string"));
+        }
+        self.pos += 1;
+        let mut text = String::new();
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(text);
+                }
+                Some(b'\\') => {
+                    let escaped = match self.bytes.get(self.pos + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'u') => {
+                            let code = std::str::from_utf8(self.bytes.get(self.pos + 2..self.pos + 6).unwrap_or_default())
+                                .ok()
+                                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            self.pos += 4;
+                            code
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    text.push(escaped);
+                    self.pos += 2;
+                }
+                Some(_) => {
+                    let rest = std::str::from_utf8(&self.bytes[self.pos..]).map_err(|_| self.error("invalid utf-8"))?;
+                    let next = rest.chars().next().unwrap_or_default();
+                    text.push(next);
+                    self.pos += next.len_utf8();
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, BramError> {
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E')) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .map(JsonValue::Number)
+            .ok_or_else(|| self.error("invalid number"))
+    }
+}
+
+impl StereoAudioPresentation {
+    pub fn to_json(&self) -> String {
+        self.to_json_with_duration_format(DurationFormat::Seconds)
+    }
+
+    pub fn to_json_with_duration_format(&self, duration_format: DurationFormat) -> String {
+        let duration = match duration_format {
+            DurationFormat::Seconds => format_float(self.duration().as_secs_f64(), POINT_DECIMALS),
+            DurationFormat::Timecode => format!(
+                "\"{}\",\"duration_format\":\"timecode\"",
+                format_duration(self.source_frames as u64, self.source_sample_rate, DurationFormat::Timecode),
+            ),
+            DurationFormat::Samples => format!("{},\"duration_format\":\"samples\"", self.source_frames),
+        };
+        let mut out = String::with_capacity(32 + (self.left_channel_points.len() + self.right_channel_points.len()) * 9);
+        out.push_str(&format!(
+            "{{\"version\":{},\"source_sample_rate\":{},\"samples_per_point\":{},\"duration\":{},\"left\":",
+            SCHEMA_VERSION,
+            self.source_sample_rate,
+            self.samples_per_point,
+            duration,
+        ));
+        write_points(&mut out, &self.left_channel_points);
+        out.push_str(",\"right\":");
+        write_points(&mut out, &self.right_channel_points);
+        if !self.annotations.is_empty() {
+            out.push_str(",\"annotations\":");
+            write_annotations(&mut out, &self.annotations);
+        }
+        out.push('}');
+        out
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, BramError> {
+        let value = parse(json)?;
+        let number = |key: &str| value.get(key).and_then(JsonValue::as_f64);
+        if number("version") != Some(SCHEMA_VERSION as f64) {
+            return Err(BramError::Decode("unsupported presentation json version".to_string()));
+        }
+        let points = |key: &str| -> Result<Vec<f32>, BramError> {
+            value
+                .get(key)
+                .and_then(JsonValue::as_array)
+                .and_then(|values| values.iter().map(|point| point.as_f64().map(|point| point as f32)).collect())
+                .ok_or_else(|| BramError::Decode(format!("missing or invalid '{}' points", key)))
+        };
+        let source_sample_rate = number("source_sample_rate").unwrap_or(0.0) as u32;
+        let source_frames = match (value.get("duration"), value.get("duration_format").and_then(JsonValue::as_str)) {
+            (Some(JsonValue::String(timecode)), _) => {
+                let seconds = parse_timecode(timecode).ok_or_else(|| BramError::Decode("invalid duration timecode".to_string()))?;
+                (seconds * source_sample_rate as f64).round() as usize
+            }
+            (Some(duration), Some("samples")) => duration.as_f64().unwrap_or(0.0) as usize,
+            (duration, _) => (duration.and_then(JsonValue::as_f64).unwrap_or(0.0) * source_sample_rate as f64).round() as usize,
+        };
+        Ok(StereoAudioPresentation {
+            left_channel_points: points("left")?,
+            right_channel_points: points("right")?,
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: read_annotations(value.get("annotations"))?,
+            source_sample_rate,
+            source_channels: 0,
+            source_frames,
+            samples_per_point: number("samples_per_point").unwrap_or(0.0) as usize,
+        })
+    }
+}
+
+fn parse_timecode(timecode: &str) -> Option<f64> {
+    let mut parts = timecode.splitn(3, ':');
+    let hours: u64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    Some((hours * 3600 + minutes * 60) as f64 + seconds)
+}
+
+#[cfg(test)]
+mod json_tests {
+    use crate::annotation::Annotation;
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::format::DurationFormat;
+    use crate::json::{parse, JsonValue};
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.0, 0.5, 1.0],
+            right_channel_points: vec![0.25, 0.75, 0.125],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 44100,
+            source_channels: 2,
+            source_frames: 66150,
+            samples_per_point: 22050,
+        }
+    }
+
+    #[test]
+    fn to_json_uses_stable_schema() {
+        assert_eq!(
+            presentation().to_json(),
+            "{\"version\":1,\"source_sample_rate\":44100,\"samples_per_point\":22050,\"duration\":1.500000,\
+             \"left\":[0.000000,0.500000,1.000000],\"right\":[0.250000,0.750000,0.125000]}"
+        );
+    }
+
+    #[test]
+    fn from_json_round_trips() {
+        let decoded = StereoAudioPresentation::from_json(&presentation().to_json()).unwrap();
+        assert_eq!(decoded.left_channel_points, vec![0.0, 0.5, 1.0]);
+        assert_eq!(decoded.right_channel_points, vec![0.25, 0.75, 0.125]);
+        assert_eq!(decoded.source_sample_rate, 44100);
+        assert_eq!(decoded.source_frames, 66150);
+        assert_eq!(decoded.samples_per_point, 22050);
+    }
+
+    #[test]
+    fn duration_format_is_written_and_read_back() {
+        let timecode = presentation().to_json_with_duration_format(DurationFormat::Timecode);
+        assert!(timecode.contains("\"duration\":\"00:00:01.500\",\"duration_format\":\"timecode\""));
+        let samples = presentation().to_json_with_duration_format(DurationFormat::Samples);
+        assert!(samples.contains("\"duration\":66150,\"duration_format\":\"samples\""));
+        for json in [timecode, samples] {
+            assert_eq!(StereoAudioPresentation::from_json(&json).unwrap().source_frames, 66150);
+        }
+        assert!(StereoAudioPresentation::from_json(&presentation().to_json().replace("1.500000", "\"1:x\"")).is_err());
+    }
+
+    #[test]
+    fn annotations_round_trip_in_versioned_block() {
+        let mut original = presentation();
+        original.annotations.push(Annotation::synthetic(1, 2, "gap \"a\"\n"));
+        let json = original.to_json();
+        assert!(json.ends_with(
+            ",\"annotations\":{\"version\":1,\"items\":[{\"start_point\":1,\"end_point\":2,\"kind\":\"synthetic\",\"label\":\"gap \\\"a\\\"\\n\"}]}}"
+        ));
+        let decoded = StereoAudioPresentation::from_json(&json).unwrap();
+        assert_eq!(decoded.annotations, original.annotations);
+
+        let unknown = json.replace("\"items\"", "\"other\"");
+        assert!(StereoAudioPresentation::from_json(&unknown).is_err());
+    }
+
+    #[test]
+    fn from_json_rejects_missing_points_and_unknown_versions() {
+        assert!(StereoAudioPresentation::from_json("{\"version\":1,\"left\":[]}").is_err());
+        assert!(StereoAudioPresentation::from_json("{\"version\":2,\"left\":[],\"right\":[]}").is_err());
+        assert!(StereoAudioPresentation::from_json("[1,2").is_err());
+    }
+
+    #[test]
+    fn parse_handles_nested_values_and_escapes() {
+        let value = parse(" {\"a\": [1, -2.5e1, null, true], \"b\": \"x\\\"\\u0041\"} ").unwrap();
+        assert_eq!(value.get("a").and_then(JsonValue::as_array).map(<[JsonValue]>::len), Some(4));
+        assert_eq!(value.get("a").unwrap().as_array().unwrap()[1], JsonValue::Number(-25.0));
+        assert_eq!(value.get("b"), Some(&JsonValue::String("x\"A".to_string())));
+    }
+}
@@ -0,0 +1,179 @@
+use std::io::Write;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::format::{format_duration, format_float, DurationFormat};
+use crate::sample::Sample;
+
+const LEVEL_LOG_VERSION: u32 = 1;
+const LEVEL_DECIMALS: usize = 2;
+const LEVEL_FLOOR_DB: f64 = -120.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelLogEntry {
+    pub second: usize,
+    pub peak_dbfs: Vec<f64>,
+    pub rms_dbfs: Vec<f64>,
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn level_log(&self) -> Vec<LevelLogEntry> {
+        let channels = self.channels.max(1) as usize;
+        let frames_per_second = self.sample_rate.max(1) as usize;
+        self.samples
+            .chunks(frames_per_second * channels)
+            .enumerate()
+            .map(|(second, block)| {
+                let mut peaks = vec![0.0f64; channels];
+                let mut squares = vec![0.0f64; channels];
+                for frame in block.chunks_exact(channels) {
+                    for (channel, sample) in frame.iter().enumerate() {
+                        let value = sample.to_f64();
+                        peaks[channel] = peaks[channel].max(value.abs());
+                        squares[channel] += value * value;
+                    }
+                }
+                let frames = (block.len() / channels).max(1) as f64;
+                LevelLogEntry {
+                    second,
+                    peak_dbfs: peaks.iter().map(|peak| to_dbfs(*peak)).collect(),
+                    rms_dbfs: squares.iter().map(|sum| to_dbfs((sum / frames).sqrt())).collect(),
+                }
+            })
+            .collect()
+    }
+
+    pub fn export_level_log_csv(&self, writer: impl Write) -> Result<(), BramError> {
+        self.export_level_log_csv_with_duration_format(writer, DurationFormat::Seconds)
+    }
+
+    pub fn export_level_log_csv_with_duration_format(&self, mut writer: impl Write, duration_format: DurationFormat) -> Result<(), BramError> {
+        let mut header = String::from(if duration_format == DurationFormat::Seconds { "second" } else { "time" });
+        for channel in 1..=self.channels.max(1) {
+            header.push_str(&format!(",peak_dbfs_{},rms_dbfs_{}", channel, channel));
+        }
+        writeln!(writer, "{}", header)?;
+        for entry in self.level_log() {
+            let mut row = self.format_second(entry.second, duration_format);
+            for (peak, rms) in entry.peak_dbfs.iter().zip(&entry.rms_dbfs) {
+                row.push_str(&format!(",{},{}", format_float(*peak, LEVEL_DECIMALS), format_float(*rms, LEVEL_DECIMALS)));
+            }
+            writeln!(writer, "{}", row)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn level_log_json(&self) -> String {
+        self.level_log_json_with_duration_format(DurationFormat::Seconds)
+    }
+
+    pub fn level_log_json_with_duration_format(&self, duration_format: DurationFormat) -> String {
+        let mut out = format!(
+            "{{\"version\":{},\"sample_rate\":{},\"channels\":{},\"seconds\":[",
+            LEVEL_LOG_VERSION, self.sample_rate, self.channels,
+        );
+        for (index, entry) in self.level_log().iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            match duration_format {
+                DurationFormat::Seconds => out.push_str(&format!("{{\"second\":{},\"peak_dbfs\":", entry.second)),
+                DurationFormat::Timecode => out.push_str(&format!("{{\"time\":\"{}\",\"peak_dbfs\":", self.format_second(entry.second, duration_format))),
+                DurationFormat::Samples => out.push_str(&format!("{{\"time\":{},\"peak_dbfs\":", self.format_second(entry.second, duration_format))),
+            }
+            write_levels(&mut out, &entry.peak_dbfs);
+            out.push_str(",\"rms_dbfs\":");
+            write_levels(&mut out, &entry.rms_dbfs);
+            out.push('}');
+        }
+        out.push_str("]}");
+        out
+    }
+
+    fn format_second(&self, second: usize, duration_format: DurationFormat) -> String {
+        match duration_format {
+            DurationFormat::Seconds => second.to_string(),
+            style => format_duration(second as u64 * self.sample_rate as u64, self.sample_rate, style),
+        }
+    }
+}
+
+fn to_dbfs(level: f64) -> f64 {
+    (20.0 * level.log10()).max(LEVEL_FLOOR_DB)
+}
+
+fn write_levels(out: &mut String, levels: &[f64]) {
+    out.push('[');
+    for (index, level) in levels.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        out.push_str(&format_float(*level, LEVEL_DECIMALS));
+    }
+    out.push(']');
+}
+
+#[cfg(test)]
+mod level_log_tests {
+    use crate::audio_data::AudioData;
+    use crate::format::DurationFormat;
+
+    fn audio() -> AudioData {
+        let mut samples = Vec::new();
+        for _ in 0..4 {
+            samples.extend_from_slice(&[16384, 0]);
+        }
+        for _ in 0..2 {
+            samples.extend_from_slice(&[-3277, 3277]);
+        }
+        AudioData {
+            samples,
+            channels: 2,
+            sample_rate: 4,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn level_log_reports_peak_and_rms_per_second() {
+        let log = audio().level_log();
+        assert_eq!(log.len(), 2);
+        assert!((log[0].peak_dbfs[0] + 6.02).abs() < 0.01);
+        assert!((log[0].rms_dbfs[0] + 6.02).abs() < 0.01);
+        assert_eq!(log[0].peak_dbfs[1], -120.0);
+        assert_eq!(log[1].second, 1);
+        assert!((log[1].rms_dbfs[1] + 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn level_log_csv_and_json_layouts() {
+        let mut out = Vec::new();
+        audio().export_level_log_csv(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "second,peak_dbfs_1,rms_dbfs_1,peak_dbfs_2,rms_dbfs_2\n\
+             0,-6.02,-6.02,-120.00,-120.00\n\
+             1,-20.00,-20.00,-20.00,-20.00\n"
+        );
+        assert_eq!(
+            audio().level_log_json(),
+            "{\"version\":1,\"sample_rate\":4,\"channels\":2,\"seconds\":[\
+             {\"second\":0,\"peak_dbfs\":[-6.02,-120.00],\"rms_dbfs\":[-6.02,-120.00]},\
+             {\"second\":1,\"peak_dbfs\":[-20.00,-20.00],\"rms_dbfs\":[-20.00,-20.00]}]}"
+        );
+    }
+
+    #[test]
+    fn level_log_exports_apply_duration_format() {
+        let mut out = Vec::new();
+        audio().export_level_log_csv_with_duration_format(&mut out, DurationFormat::Timecode).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let times: Vec<&str> = csv.lines().map(|row| row.split(',').next().unwrap()).collect();
+        assert_eq!(times, vec!["time", "00:00:00.000", "00:00:01.000"]);
+        let json = audio().level_log_json_with_duration_format(DurationFormat::Samples);
+        assert!(json.contains("{\"time\":0,\"peak_dbfs\"") && json.contains("{\"time\":4,\"peak_dbfs\""));
+        let json = audio().level_log_json_with_duration_format(DurationFormat::Timecode);
+        assert!(json.contains("{\"time\":\"00:00:01.000\",\"peak_dbfs\""));
+    }
+}
@@ -1,16 +1,747 @@
-use std::io;
-use crate::audio_data::AudioData;
-use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
-use crate::wav_binary::WavBinary;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("either the `std` or the `alloc` feature must be enabled");
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::io::{BufReader, Read, Seek};
+#[cfg(feature = "std")]
+use std::ops::Range;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "std")]
+use std::time::Duration;
+#[cfg(feature = "std")]
+use crate::audio_data::SampleStream;
+#[cfg(feature = "std")]
+use crate::audio_presentation::PresentationAccumulator;
+use crate::audio_presentation::RatedAudioData;
+
+pub use crate::annotation::{Annotation, AnnotationKind};
+pub use crate::audio_data::{AudioData, HeaderInfo, NonFinitePolicy, SnapDirection, Truncation, UnavailableReason, WavFormat};
+#[allow(deprecated)]
+pub use crate::audio_presentation::DownsampleMode;
+pub use crate::audio_presentation::{ChannelPresentation, ChannelSpec, Downmix, DownsampleStrategy, Envelope, IntegerAudioPresentation, Interpolation, MonoAudioPresentation, MultiChannelAudioPresentation, NormalizationMode, PresentationOutcome, Run, StereoAudioPresentation};
+#[cfg(feature = "std")]
+pub use crate::buffers::{DecodeBuffer, PresentationBuffer};
+#[cfg(feature = "std")]
+pub use crate::cancel::CancellationToken;
+#[cfg(feature = "std")]
+pub use crate::batch::load_presentations;
+#[cfg(feature = "std")]
+pub use crate::capabilities::{capabilities, Capabilities};
+pub use crate::channel_layout::{ChannelLayout, Speaker};
+pub use crate::error::BramError;
+#[cfg(feature = "std")]
+pub use crate::decoder::{register_decoder, Decoder};
+#[cfg(feature = "std")]
+pub use crate::compact::{read_presentation, write_presentation};
+#[cfg(feature = "std")]
+pub use crate::dither::Dither;
+#[cfg(feature = "std")]
+pub use crate::edit::{FadeCurve, MixHeadroom, MixInput};
+#[cfg(feature = "std")]
+pub use crate::exporter::{exporter_names, find_exporter, register_exporter, CborExporter, CompactExporter, CsvExporter, DatExporter, JsonExporter, PresentationExporter};
+#[cfg(feature = "std")]
+pub use crate::format::{format_duration, format_float, DurationFormat};
+#[cfg(feature = "std")]
+pub use crate::histogram::AmplitudeHistogram;
+#[cfg(feature = "std")]
+pub use crate::level_log::LevelLogEntry;
+pub use crate::markers::Marker;
+#[cfg(feature = "std")]
+pub use crate::metadata::WavMetadata;
+#[cfg(feature = "std")]
+pub use crate::peaks::{read_peaks, write_peaks, Peaks};
+#[cfg(feature = "std")]
+pub use crate::peaks_store::{content_hash, FsPeaksStore, PeaksKey, PeaksStore};
+#[cfg(feature = "std")]
+pub use crate::presentation_cache::{FileIdentityKey, PresentationCache};
+#[cfg(feature = "std")]
+pub use crate::presentation_builder::{ChannelSelection, Presentation, PresentationBuilder};
+#[cfg(feature = "std")]
+pub use crate::point_stream::{PresentationPoint, PresentationPoints};
+#[cfg(feature = "std")]
+pub use crate::preview::PreviewOptions;
+#[cfg(feature = "std")]
+pub use crate::probe::WavInfo;
+#[cfg(feature = "std")]
+pub use crate::remix::{PanLaw, SurroundDownmix};
+#[cfg(feature = "std")]
+pub use crate::resample::ResampleQuality;
+pub use crate::sample::Sample;
+#[cfg(feature = "std")]
+pub use crate::scan::{scan_directory, ScanOptions, ScanReport, ScannedFile, SkippedDirectory};
+#[cfg(feature = "std")]
+pub use crate::segments::Segment;
+#[cfg(feature = "std")]
+pub use crate::smpl::{LoopInfo, LoopType, SampleLoop};
+pub use crate::streaming_presentation::StreamingPresentationBuilder;
+#[cfg(feature = "std")]
+pub use crate::stats::{AudioStats, ChannelStats};
+#[cfg(feature = "std")]
+pub use crate::svg::SvgOptions;
+#[cfg(feature = "std")]
+pub use crate::tail::WavTail;
+pub use crate::warning::Warning;
+pub use crate::wav_binary::{Chunk, Chunks, HeaderDiagnosis, WavBinary};
+#[cfg(feature = "std")]
+pub use crate::wav_writer::{encode_wav, encode_wav_with_source, write_wav, write_wav_with_source};
+pub use crate::weighting::Weighting;
+#[cfg(feature = "ingest")]
+pub use crate::ingest::{IngestEvent, IngestHandle, IngestStep, Ingestor};
+#[cfg(feature = "mmap")]
+pub use crate::mmap::MappedWav;
+#[cfg(feature = "tokio")]
+pub use crate::async_load::load_presentation_async;
+#[cfg(feature = "http")]
+pub use crate::http::{load_presentation_from_url, probe_url};
+#[cfg(feature = "tui")]
+pub use crate::tui::{render_blocks, render_braille};
+#[cfg(feature = "image")]
+pub use crate::png::PngStyle;
+#[cfg(feature = "plotters")]
+pub use crate::plot::WaveformChart;
 
 mod wav_binary;
+mod error;
+mod annotation;
 mod audio_data;
 mod audio_presentation;
+mod channel_layout;
+mod streaming_presentation;
+mod hash;
+mod warning;
+mod adpcm;
+#[cfg(feature = "std")]
+mod format;
+#[cfg(feature = "std")]
+mod bext;
+mod aiff;
+#[cfg(feature = "std")]
+mod peaks;
+#[cfg(feature = "std")]
+mod peaks_store;
+#[cfg(feature = "std")]
+mod presentation_cache;
+#[cfg(feature = "std")]
+mod presentation_builder;
+#[cfg(feature = "std")]
+mod decoder;
+#[cfg(feature = "std")]
+mod capabilities;
+mod weighting;
+#[cfg(not(any(feature = "std", test)))]
+mod math;
+#[cfg(feature = "std")]
+mod ixml;
+#[cfg(feature = "std")]
+mod metadata;
+mod markers;
+#[cfg(feature = "std")]
+mod smpl;
+#[cfg(feature = "std")]
+mod probe;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "std")]
+mod cancel;
+#[cfg(feature = "std")]
+mod fft;
+#[cfg(feature = "std")]
+mod wav_writer;
+#[cfg(feature = "std")]
+mod preview;
+#[cfg(feature = "std")]
+mod json;
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "std")]
+mod dat;
+#[cfg(feature = "std")]
+mod cbor;
+#[cfg(feature = "std")]
+mod compact;
+#[cfg(feature = "std")]
+mod exporter;
+mod sample;
+#[cfg(feature = "experimental")]
+mod loudness;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod histogram;
+#[cfg(feature = "std")]
+mod gain;
+#[cfg(feature = "std")]
+mod edit;
+#[cfg(feature = "std")]
+mod segments;
+#[cfg(feature = "std")]
+mod dither;
+#[cfg(feature = "experimental")]
+mod spectrogram;
+#[cfg(feature = "std")]
+mod svg;
+#[cfg(feature = "std")]
+mod tail;
+#[cfg(feature = "std")]
+mod buffers;
+#[cfg(feature = "std")]
+mod point_stream;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+mod scan;
+#[cfg(feature = "std")]
+mod resample;
+#[cfg(feature = "std")]
+mod remix;
+#[cfg(feature = "std")]
+mod level_log;
+#[cfg(feature = "std")]
+pub mod commands;
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "std")]
+pub mod compare;
+#[cfg(feature = "std")]
+pub mod fingerprint;
+#[cfg(feature = "std")]
+pub mod mfcc;
+#[cfg(feature = "std")]
+pub mod pitch;
+#[cfg(feature = "std")]
+pub mod spectrum;
+#[cfg(feature = "std")]
+pub mod tempo;
+#[cfg(feature = "ingest")]
+mod ingest;
+#[cfg(feature = "flac")]
+mod flac;
+#[cfg(feature = "mp3")]
+mod mp3;
+#[cfg(feature = "experimental")]
+pub mod experimental;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "tokio")]
+mod async_load;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "hound")]
+mod hound_interop;
+#[cfg(feature = "tui")]
+mod tui;
+#[cfg(feature = "image")]
+mod png;
+#[cfg(feature = "plotters")]
+mod plot;
+#[cfg(feature = "cpal")]
+mod playback;
+#[cfg(feature = "pyo3")]
+mod python;
 
-pub fn load_presentation(path: &str, rate: u32) -> io::Result<StereoAudioPresentation> {
-    let wavbin = WavBinary::from_file(path)?;
-    let audiodata = AudioData::try_from(&wavbin)?;
+#[cfg(feature = "std")]
+pub fn load_presentation(path: impl AsRef<Path>, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    Presentation::builder(path).rate(rate).build_stereo()
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_with_mode(path: impl AsRef<Path>, rate: u32, mode: DownsampleStrategy) -> Result<StereoAudioPresentation, BramError> {
+    load_presentation_streamed(path, rate, mode, Weighting::None, NormalizationMode::ZeroToOne)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_weighted(path: impl AsRef<Path>, rate: u32, weighting: Weighting) -> Result<StereoAudioPresentation, BramError> {
+    load_presentation_streamed(path, rate, DownsampleStrategy::Decimate, weighting, NormalizationMode::ZeroToOne)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_normalized(path: impl AsRef<Path>, rate: u32, normalization: NormalizationMode) -> Result<StereoAudioPresentation, BramError> {
+    load_presentation_streamed(path, rate, DownsampleStrategy::Decimate, Weighting::None, normalization)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_interpolated(path: impl AsRef<Path>, rate: u32, interpolation: Interpolation) -> Result<StereoAudioPresentation, BramError> {
+    Presentation::builder(path).rate(rate).interpolation(interpolation).build_stereo()
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_downmixed(path: impl AsRef<Path>, rate: u32, downmix: SurroundDownmix) -> Result<StereoAudioPresentation, BramError> {
+    Presentation::builder(path).rate(rate).surround_downmix(downmix).build_stereo()
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_with_track_gain(path: impl AsRef<Path>, rate: u32, apply_track_gain: bool) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    let audiodata = match read_other_container(path) {
+        Some(audiodata) => audiodata?,
+        None => {
+            let wavbin = WavBinary::from_file(path)?;
+            let mut audiodata = AudioData::try_from(&wavbin)?;
+            if apply_track_gain {
+                ixml::apply_track_gains(&mut audiodata, &ixml::read_track_gains(&wavbin));
+            }
+            audiodata
+        }
+    };
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    StereoAudioPresentation::try_from(&ratedaudiodata)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_with_points(path: impl AsRef<Path>, num_points: usize) -> Result<StereoAudioPresentation, BramError> {
+    StereoAudioPresentation::with_point_count(&load_audio(path)?, num_points)
+}
+
+#[cfg(feature = "std")]
+pub fn presentation_for_range(path: impl AsRef<Path>, range: Range<f64>, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    if !(range.start >= 0.0 && range.start <= range.end) {
+        return Err(BramError::InvalidParameter("time range must be non-negative and ordered"));
+    }
+    let frame_range = |sample_rate: u32| {
+        (range.start * sample_rate as f64).round() as usize..(range.end * sample_rate as f64).round() as usize
+    };
+    if let Some(audiodata) = read_other_container(path) {
+        let mut audiodata = audiodata?;
+        let frames = frame_range(audiodata.sample_rate);
+        let channels = audiodata.channels.max(1) as usize;
+        let end = (frames.end * channels).min(audiodata.samples.len());
+        audiodata.samples = audiodata.samples[(frames.start * channels).min(end)..end].to_vec();
+        return StereoAudioPresentation::try_from(&RatedAudioData::new(&audiodata, rate));
+    }
+    let mut stream = SampleStream::open(WavBinary::open_reader(path)?)?;
+    let frames = frame_range(stream.sample_rate);
+    if rate > stream.sample_rate {
+        let mut audiodata = AudioData {
+            samples: Vec::new(),
+            channels: stream.channels,
+            sample_rate: stream.sample_rate,
+            bits_per_sample: stream.bits_per_sample,
+            channel_mask: stream.channel_mask,
+        };
+        read_frames(&mut stream, frames, |block| audiodata.samples.extend_from_slice(block))?;
+        return StereoAudioPresentation::try_from(&RatedAudioData::new(&audiodata, rate));
+    }
+    let mut accumulator = PresentationAccumulator::new(
+        stream.channels,
+        stream.sample_rate,
+        rate,
+        DownsampleStrategy::Decimate,
+        Weighting::None,
+        NormalizationMode::ZeroToOne,
+    )?;
+    read_frames(&mut stream, frames, |block| accumulator.push(block))?;
+    Ok(accumulator.finish())
+}
+
+#[cfg(feature = "std")]
+fn read_frames<R: Read + Seek>(stream: &mut SampleStream<R>, frames: Range<usize>, mut push: impl FnMut(&[i16])) -> Result<(), BramError> {
+    stream.skip_frames(frames.start)?;
+    let mut remaining = frames.len() * stream.channels.max(1) as usize;
+    while remaining > 0
+        && let Some(mut block) = stream.next_block()?
+    {
+        block.truncate(remaining);
+        remaining -= block.len();
+        push(&block);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+pub fn decode_range(path: impl AsRef<Path>, offset: Duration, length: Duration) -> Result<AudioData, BramError> {
+    let path = path.as_ref();
+    let frame_range = |sample_rate: u32| {
+        let start = (offset.as_secs_f64() * sample_rate as f64).round() as usize;
+        start..start + (length.as_secs_f64() * sample_rate as f64).round() as usize
+    };
+    if let Some(audiodata) = read_other_container(path) {
+        return Ok(audiodata?.slice(offset, offset + length));
+    }
+    let mut stream = SampleStream::open(WavBinary::open_reader(path)?)?;
+    let frames = frame_range(stream.sample_rate);
+    stream.skip_frames(frames.start)?;
+    let mut remaining = frames.len() * stream.channels.max(1) as usize;
+    let mut samples = Vec::with_capacity(remaining);
+    while remaining > 0
+        && let Some(mut block) = stream.next_block()?
+    {
+        block.truncate(remaining);
+        remaining -= block.len();
+        samples.extend_from_slice(&block);
+    }
+    Ok(AudioData {
+        samples,
+        channels: stream.channels,
+        sample_rate: stream.sample_rate,
+        bits_per_sample: stream.bits_per_sample,
+        channel_mask: stream.channel_mask,
+    })
+}
+
+#[cfg(feature = "std")]
+pub fn load_integer_presentation(path: impl AsRef<Path>, rate: u32) -> Result<IntegerAudioPresentation, BramError> {
+    IntegerAudioPresentation::from_audio(&load_audio(path)?, rate)
+}
+
+#[cfg(feature = "std")]
+pub fn load_multichannel_presentation(path: impl AsRef<Path>, rate: u32) -> Result<MultiChannelAudioPresentation, BramError> {
+    let audiodata = load_audio(path)?;
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    MultiChannelAudioPresentation::try_from(&ratedaudiodata)
+}
+
+#[cfg(feature = "std")]
+pub fn load_mono_presentation(path: impl AsRef<Path>, rate: u32, downmix: Downmix) -> Result<MonoAudioPresentation, BramError> {
+    let audiodata = load_audio(path)?;
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    MonoAudioPresentation::new(&ratedaudiodata, downmix)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_points(path: impl AsRef<Path>, rate: u32, mode: DownsampleStrategy) -> Result<PresentationPoints<BufReader<fs::File>>, BramError> {
+    PresentationPoints::open(WavBinary::open_reader(path)?, rate, mode)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_from_reader(reader: impl Read, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    presentation_from_stream(reader, rate, DownsampleStrategy::Decimate, Weighting::None, NormalizationMode::ZeroToOne)
+}
+
+pub fn load_presentation_from_bytes(bytes: &[u8], rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let audiodata = if aiff::check(bytes) {
+        aiff::parse(bytes)?
+    } else {
+        AudioData::try_from(&WavBinary::from_bytes(bytes)?)?
+    };
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    StereoAudioPresentation::try_from(&ratedaudiodata)
+}
+
+#[cfg(feature = "mmap")]
+pub fn load_presentation_mmap(path: impl AsRef<Path>, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let audiodata = AudioData::try_from(&MappedWav::open(path)?)?;
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    StereoAudioPresentation::try_from(&ratedaudiodata)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_or_unavailable(path: impl AsRef<Path>, rate: u32) -> Result<PresentationOutcome, BramError> {
+    let path = path.as_ref();
+    let audiodata = match read_other_container(path) {
+        Some(audiodata) => audiodata?,
+        None => {
+            let wavbin = WavBinary::from_file(path)?;
+            if wavbin.check()
+                && let Ok(header) = AudioData::read_header_info(&wavbin.data)
+                && let Some(reason) = header.unavailable_reason()
+            {
+                return Ok(PresentationOutcome::Unavailable { header, reason });
+            }
+            AudioData::try_from(&wavbin)?
+        }
+    };
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    StereoAudioPresentation::try_from(&ratedaudiodata).map(PresentationOutcome::Available)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_lenient(path: impl AsRef<Path>, rate: u32) -> Result<(StereoAudioPresentation, Vec<Warning>), BramError> {
+    let path = path.as_ref();
+    let (audiodata, warnings) = match read_other_container(path) {
+        Some(audiodata) => (audiodata?, Vec::new()),
+        None => AudioData::try_from_lenient(&WavBinary::from_file(path)?)?,
+    };
     let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
     let presentation = StereoAudioPresentation::try_from(&ratedaudiodata)?;
+    Ok((presentation, warnings))
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_lenient_with_gap_fill(path: impl AsRef<Path>, rate: u32) -> Result<(StereoAudioPresentation, Vec<Warning>), BramError> {
+    let path = path.as_ref();
+    let (audiodata, warnings, gaps) = match read_other_container(path) {
+        Some(audiodata) => (audiodata?, Vec::new(), Vec::new()),
+        None => {
+            let wavbin = WavBinary::from_file(path)?;
+            let (audiodata, warnings) = AudioData::try_from_lenient(&wavbin)?;
+            let block_align = AudioData::read_header_info(&wavbin.data)?.block_align.max(1) as usize;
+            let frames = audiodata.samples.len() / audiodata.channels.max(1) as usize;
+            let gaps = warnings
+                .iter()
+                .filter_map(|warning| match warning {
+                    Warning::ClampedChunkSize { id, declared, available }
+                        if id == b"data" && *declared != u32::MAX as usize =>
+                    {
+                        Some(frames..frames + (declared - available) / block_align)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            (audiodata, warnings, gaps)
+        }
+    };
+    let ratedaudiodata = RatedAudioData::new(&audiodata, rate);
+    let mut presentation = StereoAudioPresentation::try_from(&ratedaudiodata)?;
+    presentation.fill_frame_gaps(&gaps);
+    Ok((presentation, warnings))
+}
+
+#[cfg(feature = "std")]
+pub fn export_preview(path: impl AsRef<Path>, output: impl AsRef<Path>, options: &PreviewOptions) -> Result<(), BramError> {
+    let audiodata = load_audio(path)?;
+    fs::write(output, wav_writer::encode_wav(&preview::preview_clip(&audiodata, options)))?;
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+pub fn split_at_timecodes(path: impl AsRef<Path>, timecodes: &[Duration], output_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, BramError> {
+    let path = path.as_ref();
+    let wavbin = WavBinary::from_file(path)?;
+    let parts = bext::split_at_timecodes(&wavbin, timecodes)?;
+    let stem = path
+        .file_stem()
+        .map_or("part".into(), |stem| stem.to_string_lossy());
+    let mut written = Vec::with_capacity(parts.len());
+    for (index, part) in parts.iter().enumerate() {
+        let target = output_dir.as_ref().join(format!("{}_{:03}.wav", stem, index + 1));
+        fs::write(&target, part)?;
+        written.push(target);
+    }
+    Ok(written)
+}
+
+#[cfg(feature = "std")]
+pub fn load_segments(path: impl AsRef<Path>) -> Result<Vec<Segment>, BramError> {
+    let wavbin = WavBinary::from_file(path)?;
+    Ok(AudioData::try_from(&wavbin)?.split_at_markers(&markers::read_markers(&wavbin)))
+}
+
+#[cfg(feature = "std")]
+pub fn export_segments(path: impl AsRef<Path>, output_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, BramError> {
+    let path = path.as_ref();
+    let stem = path.file_stem().map_or("segment".into(), |stem| stem.to_string_lossy());
+    let mut written = Vec::new();
+    for (index, segment) in load_segments(path)?.iter().enumerate() {
+        let target = output_dir.as_ref().join(format!("{}_{:03}.wav", stem, index + 1));
+        fs::write(&target, wav_writer::encode_wav(&segment.audio))?;
+        written.push(target);
+    }
+    Ok(written)
+}
+
+#[cfg(feature = "std")]
+pub fn probe(path: impl AsRef<Path>) -> Result<WavInfo, BramError> {
+    let path = path.as_ref();
+    if let Some(audiodata) = read_other_container(path) {
+        return Ok(probe::probe_decoded(&audiodata?));
+    }
+    probe::probe_reader(WavBinary::open_reader(path)?)
+}
+
+#[cfg(feature = "std")]
+pub fn load_metadata(path: impl AsRef<Path>) -> Result<WavMetadata, BramError> {
+    let path = path.as_ref();
+    if other_container_parser(path).is_some() {
+        return Ok(WavMetadata::default());
+    }
+    Ok(metadata::read_metadata(&WavBinary::from_file(path)?))
+}
+
+#[cfg(feature = "std")]
+pub fn load_markers(path: impl AsRef<Path>) -> Result<Vec<Marker>, BramError> {
+    let path = path.as_ref();
+    if other_container_parser(path).is_some() {
+        return Ok(Vec::new());
+    }
+    Ok(markers::read_markers(&WavBinary::from_file(path)?))
+}
+
+#[cfg(feature = "std")]
+pub fn load_loop_info(path: impl AsRef<Path>) -> Result<Option<LoopInfo>, BramError> {
+    let path = path.as_ref();
+    if other_container_parser(path).is_some() {
+        return Ok(None);
+    }
+    Ok(smpl::read_loop_info(&WavBinary::from_file(path)?))
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_with_markers(path: impl AsRef<Path>, rate: u32) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    let mut presentation = load_presentation(path, rate)?;
+    presentation.annotate_markers(&load_markers(path)?);
+    Ok(presentation)
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_cached(path: impl AsRef<Path>, rate: u32, store: &dyn PeaksStore) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    let key = PeaksKey { content_hash: content_hash(&fs::read(path)?), rate };
+    if let Some(presentation) = store.get(&key)? {
+        return Ok(presentation);
+    }
+    let presentation = load_presentation(path, rate)?;
+    store.put(&key, &presentation)?;
     Ok(presentation)
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "std")]
+pub fn load_presentation_cached_by_identity(path: impl AsRef<Path>, rate: u32, cache: &PresentationCache) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    let key = FileIdentityKey::for_file(path, rate, DownsampleStrategy::Decimate, NormalizationMode::ZeroToOne)?;
+    if let Some(presentation) = cache.get(&key)? {
+        return Ok(presentation);
+    }
+    let presentation = load_presentation(path, rate)?;
+    cache.put(&key, &presentation)?;
+    Ok(presentation)
+}
+
+#[cfg(feature = "std")]
+pub fn load_peaks(path: impl AsRef<Path>, frames_per_peak: u32) -> Result<Peaks, BramError> {
+    let path = path.as_ref();
+    let mut sidecar = path.as_os_str().to_owned();
+    sidecar.push(".reapeaks");
+    let source_len = fs::metadata(path)?.len();
+    if let Ok(peaks) = read_peaks(&sidecar)
+        && u64::from(peaks.source_len) == source_len
+        && peaks.frames_per_peak == frames_per_peak
+    {
+        return Ok(peaks);
+    }
+    let mut peaks = Peaks::from_audio(&load_audio(path)?, frames_per_peak)?;
+    if let Ok(source_len) = u32::try_from(source_len) {
+        peaks.source_len = source_len;
+        write_peaks(&peaks, &sidecar)?;
+    }
+    Ok(peaks)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn read_other_container(path: impl AsRef<Path>) -> Option<Result<AudioData, BramError>> {
+    let path = path.as_ref();
+    let parse = other_container_parser(path)?;
+    Some(fs::read(path).map_err(BramError::from).and_then(|data| parse(&data)))
+}
+
+#[cfg(feature = "std")]
+pub(crate) type ContainerParser = Box<dyn Fn(&[u8]) -> Result<AudioData, BramError> + Send>;
+
+#[cfg(feature = "std")]
+pub(crate) fn other_container_parser(path: impl AsRef<Path>) -> Option<ContainerParser> {
+    let path = path.as_ref();
+    if let Some(decoder) = decoder::find_decoder(path) {
+        return Some(Box::new(move |data| decoder.decode(data)));
+    }
+    if aiff::has_aiff_extension(path) {
+        return Some(Box::new(aiff::parse));
+    }
+    #[cfg(feature = "flac")]
+    if flac::has_flac_extension(path) {
+        return Some(Box::new(flac::parse));
+    }
+    #[cfg(feature = "mp3")]
+    if mp3::has_mp3_extension(path) {
+        return Some(Box::new(mp3::parse));
+    }
+    None
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn load_presentation_streamed(
+    path: impl AsRef<Path>,
+    rate: u32,
+    mode: DownsampleStrategy,
+    weighting: Weighting,
+    normalization: NormalizationMode,
+) -> Result<StereoAudioPresentation, BramError> {
+    let path = path.as_ref();
+    if let Some(audiodata) = read_other_container(path) {
+        let ratedaudiodata = RatedAudioData::new(&audiodata?, rate)
+            .with_mode(mode)
+            .with_weighting(weighting)
+            .with_normalization(normalization);
+        return StereoAudioPresentation::try_from(&ratedaudiodata);
+    }
+    presentation_from_stream(WavBinary::open_reader(path)?, rate, mode, weighting, normalization)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn presentation_from_stream(
+    reader: impl Read,
+    rate: u32,
+    mode: DownsampleStrategy,
+    weighting: Weighting,
+    normalization: NormalizationMode,
+) -> Result<StereoAudioPresentation, BramError> {
+    let mut stream = SampleStream::open(reader)?;
+    if rate > stream.sample_rate {
+        let ratedaudiodata = RatedAudioData::new(&stream.read_all()?, rate)
+            .with_mode(mode)
+            .with_weighting(weighting)
+            .with_normalization(normalization);
+        return StereoAudioPresentation::try_from(&ratedaudiodata);
+    }
+    let mut accumulator = PresentationAccumulator::new(stream.channels, stream.sample_rate, rate, mode, weighting, normalization)?;
+    while let Some(block) = stream.next_block_as::<f32>()? {
+        accumulator.push_block(&block);
+    }
+    Ok(accumulator.finish())
+}
+
+#[cfg(feature = "std")]
+pub fn load_audio_as<S: Sample>(path: impl AsRef<Path>) -> Result<AudioData<S>, BramError> {
+    load_audio_sanitized(path, NonFinitePolicy::Zero)
+}
+
+#[cfg(feature = "std")]
+pub fn load_audio_sanitized<S: Sample>(path: impl AsRef<Path>, policy: NonFinitePolicy) -> Result<AudioData<S>, BramError> {
+    let path = path.as_ref();
+    let mut audiodata = match read_other_container(path) {
+        Some(audiodata) => audiodata?.convert(),
+        None => AudioData::from_wav(&WavBinary::from_file(path)?)?,
+    };
+    audiodata.sanitize(policy)?;
+    Ok(audiodata)
+}
+
+#[cfg(feature = "std")]
+pub fn load_audio_recovering(path: impl AsRef<Path>) -> Result<(AudioData, Option<Truncation>), BramError> {
+    let path = path.as_ref();
+    if let Some(audiodata) = read_other_container(path) {
+        return Ok((audiodata?, None));
+    }
+    AudioData::try_from_truncated(&WavBinary::from_file(path)?)
+}
+
+#[cfg(feature = "std")]
+pub fn load_audio_with_warnings(path: impl AsRef<Path>) -> Result<(AudioData, Vec<Warning>), BramError> {
+    let path = path.as_ref();
+    if let Some(audiodata) = read_other_container(path) {
+        return Ok((audiodata?, Vec::new()));
+    }
+    AudioData::try_from_checked(&WavBinary::from_file(path)?)
+}
+
+#[cfg(feature = "std")]
+pub fn concat_files<P: AsRef<Path>>(paths: &[P]) -> Result<AudioData, BramError> {
+    let parts = paths.iter().map(load_audio).collect::<Result<Vec<_>, _>>()?;
+    AudioData::concat(&parts)
+}
+
+#[cfg(feature = "std")]
+pub fn load_audio(path: impl AsRef<Path>) -> Result<AudioData, BramError> {
+    let path = path.as_ref();
+    if let Some(audiodata) = read_other_container(path) {
+        return audiodata;
+    }
+    AudioData::from_reader(WavBinary::open_reader(path)?)
+}
@@ -0,0 +1,269 @@
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+use crate::weighting::k_weighting_filters;
+
+const BLOCK_SECONDS: f64 = 0.4;
+const BLOCK_OVERLAP: usize = 4;
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+const RELATIVE_GATE_LU: f64 = -10.0;
+const LOUDNESS_OFFSET: f64 = -0.691;
+const SURROUND_WEIGHT: f64 = 1.41;
+const SHORT_TERM_SECONDS: f64 = 3.0;
+const SHORT_TERM_HOP_SECONDS: f64 = 0.1;
+const RANGE_RELATIVE_GATE_LU: f64 = -20.0;
+const RANGE_LOW_PERCENTILE: f64 = 0.10;
+const RANGE_HIGH_PERCENTILE: f64 = 0.95;
+const TRUE_PEAK_OVERSAMPLING: usize = 4;
+const TRUE_PEAK_TAPS: isize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loudness {
+    pub integrated_lufs: f64,
+    pub loudness_range_lu: f64,
+    pub peak_dbfs: f64,
+    pub true_peak_dbtp: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LoudnessAlignment {
+    #[default]
+    None,
+    TargetLufs(f64),
+    GainDb(f64),
+}
+
+impl LoudnessAlignment {
+    pub(crate) fn gain<S: Sample>(self, audio: &AudioData<S>) -> Result<f64, BramError> {
+        let gain_db = match self {
+            LoudnessAlignment::None => return Ok(1.0),
+            LoudnessAlignment::GainDb(gain_db) => gain_db,
+            LoudnessAlignment::TargetLufs(target_lufs) => {
+                if !target_lufs.is_finite() {
+                    return Err(BramError::InvalidParameter("loudness target must be finite"));
+                }
+                let integrated = audio.loudness()?.integrated_lufs;
+                if !integrated.is_finite() {
+                    return Ok(1.0);
+                }
+                target_lufs - integrated
+            }
+        };
+        if !gain_db.is_finite() {
+            return Err(BramError::InvalidParameter("alignment gain must be finite"));
+        }
+        Ok(10f64.powf(gain_db / 20.0))
+    }
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn loudness(&self) -> Result<Loudness, BramError> {
+        self.loudness_of_range(0, self.frames())
+    }
+
+    pub fn loudness_of_range(&self, start: usize, end: usize) -> Result<Loudness, BramError> {
+        if start > end || end > self.frames() {
+            return Err(BramError::InvalidParameter("loudness range must lie within the audio"));
+        }
+        let channels = self.channels.max(1) as usize;
+        let samples = &self.samples[start * channels..end * channels];
+        let peak = samples.iter().fold(0.0f64, |peak, sample| peak.max(sample.to_f64().abs()));
+
+        let mut filters = vec![k_weighting_filters(self.sample_rate); channels];
+        let mut squares = vec![0.0f64; end - start];
+        for (frame, values) in samples.chunks_exact(channels).enumerate() {
+            for (channel, sample) in values.iter().enumerate() {
+                let filtered = filters[channel].iter_mut().fold(sample.to_f64(), |value, filter| filter.process(value));
+                squares[frame] += channel_weight(channel, channels) * filtered * filtered;
+            }
+        }
+
+        let true_peak = (0..channels).fold(peak, |true_peak, channel| {
+            let values: Vec<f64> = samples.iter().skip(channel).step_by(channels).map(|sample| sample.to_f64()).collect();
+            true_peak.max(oversampled_peak(&values))
+        });
+        Ok(Loudness {
+            integrated_lufs: gated_loudness(&squares, self.sample_rate),
+            loudness_range_lu: loudness_range(&squares, self.sample_rate),
+            peak_dbfs: 20.0 * peak.log10(),
+            true_peak_dbtp: 20.0 * true_peak.log10(),
+        })
+    }
+}
+
+fn channel_weight(channel: usize, channels: usize) -> f64 {
+    match (channels, channel) {
+        (6, 3) => 0.0,
+        (5, 3..=4) => SURROUND_WEIGHT,
+        (6, 4..=5) => SURROUND_WEIGHT,
+        _ => 1.0,
+    }
+}
+
+fn block_powers(squares: &[f64], block: usize, hop: usize) -> Vec<f64> {
+    let block = block.clamp(1, squares.len());
+    let mut sums = vec![0.0f64; squares.len() + 1];
+    for (index, square) in squares.iter().enumerate() {
+        sums[index + 1] = sums[index] + square;
+    }
+    (0..=squares.len() - block)
+        .step_by(hop.max(1))
+        .map(|start| (sums[start + block] - sums[start]) / block as f64)
+        .collect()
+}
+
+fn loudness(power: f64) -> f64 {
+    LOUDNESS_OFFSET + 10.0 * power.log10()
+}
+
+fn gated_loudness(squares: &[f64], sample_rate: u32) -> f64 {
+    if squares.is_empty() {
+        return f64::NEG_INFINITY;
+    }
+    let block = ((BLOCK_SECONDS * sample_rate as f64) as usize).clamp(1, squares.len());
+    let blocks = block_powers(squares, block, block / BLOCK_OVERLAP);
+    let gated_mean = |threshold: f64| {
+        let kept: Vec<f64> = blocks.iter().copied().filter(|power| loudness(*power) > threshold).collect();
+        (!kept.is_empty()).then(|| kept.iter().sum::<f64>() / kept.len() as f64)
+    };
+    let Some(absolute) = gated_mean(ABSOLUTE_GATE_LUFS) else {
+        return f64::NEG_INFINITY;
+    };
+    gated_mean(loudness(absolute) + RELATIVE_GATE_LU).map_or(f64::NEG_INFINITY, loudness)
+}
+
+fn loudness_range(squares: &[f64], sample_rate: u32) -> f64 {
+    if squares.is_empty() {
+        return 0.0;
+    }
+    let block = (SHORT_TERM_SECONDS * sample_rate as f64) as usize;
+    let hop = (SHORT_TERM_HOP_SECONDS * sample_rate as f64) as usize;
+    let powers: Vec<f64> = block_powers(squares, block, hop)
+        .into_iter()
+        .filter(|power| loudness(*power) > ABSOLUTE_GATE_LUFS)
+        .collect();
+    if powers.is_empty() {
+        return 0.0;
+    }
+    let threshold = loudness(powers.iter().sum::<f64>() / powers.len() as f64) + RANGE_RELATIVE_GATE_LU;
+    let mut levels: Vec<f64> = powers.into_iter().map(loudness).filter(|level| *level > threshold).collect();
+    levels.sort_by(f64::total_cmp);
+    let percentile = |fraction: f64| levels[((levels.len() - 1) as f64 * fraction).round() as usize];
+    percentile(RANGE_HIGH_PERCENTILE) - percentile(RANGE_LOW_PERCENTILE)
+}
+
+fn oversampled_peak(values: &[f64]) -> f64 {
+    let phases: Vec<Vec<f64>> = (1..TRUE_PEAK_OVERSAMPLING)
+        .map(|phase| {
+            let fraction = phase as f64 / TRUE_PEAK_OVERSAMPLING as f64;
+            (1 - TRUE_PEAK_TAPS..=TRUE_PEAK_TAPS)
+                .map(|tap| {
+                    let offset = tap as f64 - fraction;
+                    let window = 0.5 * (1.0 + (std::f64::consts::PI * offset / TRUE_PEAK_TAPS as f64).cos());
+                    sinc(offset) * window
+                })
+                .collect()
+        })
+        .collect();
+    let taps = 2 * TRUE_PEAK_TAPS as usize;
+    let mut padded = vec![0.0f64; TRUE_PEAK_TAPS as usize - 1];
+    padded.extend_from_slice(values);
+    padded.resize(padded.len() + TRUE_PEAK_TAPS as usize, 0.0);
+    padded.windows(taps).fold(0.0f64, |peak, window| {
+        phases.iter().fold(peak, |peak, coefficients| {
+            let interpolated: f64 = window.iter().zip(coefficients).map(|(value, coefficient)| value * coefficient).sum();
+            peak.max(interpolated.abs())
+        })
+    })
+}
+
+fn sinc(x: f64) -> f64 {
+    let x = std::f64::consts::PI * x;
+    x.sin() / x
+}
+
+#[cfg(test)]
+mod loudness_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::loudness::LoudnessAlignment;
+
+    fn stereo_tone(amplitude: f64, seconds: usize, silence: usize) -> AudioData {
+        let sample_rate = 48000;
+        let mut samples = vec![0i16; silence * sample_rate * 2];
+        for i in 0..seconds * sample_rate {
+            let value = (amplitude * 32767.0 * (2.0 * std::f64::consts::PI * 997.0 * i as f64 / sample_rate as f64).sin()) as i16;
+            samples.extend_from_slice(&[value, value]);
+        }
+        AudioData {
+            samples,
+            channels: 2,
+            sample_rate: sample_rate as u32,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn full_scale_reference_tone_matches_bs1770() {
+        let audio = stereo_tone(0.5, 2, 0);
+        let loudness = audio.loudness_of_range(0, audio.frames()).unwrap();
+        assert!((loudness.integrated_lufs + 6.02).abs() < 0.2, "{}", loudness.integrated_lufs);
+        assert!((loudness.peak_dbfs + 6.02).abs() < 0.05);
+    }
+
+    #[test]
+    fn range_measures_only_the_selection() {
+        let audio = stereo_tone(0.5, 2, 1);
+        let whole = audio.loudness_of_range(0, audio.frames()).unwrap();
+        let tone = audio.loudness_of_range(48000, audio.frames()).unwrap();
+        let silence = audio.loudness_of_range(0, 48000).unwrap();
+        assert!((whole.integrated_lufs - tone.integrated_lufs).abs() < 0.5);
+        assert_eq!(silence.integrated_lufs, f64::NEG_INFINITY);
+        assert_eq!(silence.peak_dbfs, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn loudness_range_reflects_level_changes() {
+        let steady = stereo_tone(0.5, 8, 0);
+        assert!(steady.loudness().unwrap().loudness_range_lu < 0.5);
+
+        let mut varying = stereo_tone(0.5, 4, 0);
+        varying.samples.extend(stereo_tone(0.05, 4, 0).samples);
+        let range = varying.loudness().unwrap().loudness_range_lu;
+        assert!((range - 20.0).abs() < 1.5, "{}", range);
+    }
+
+    #[test]
+    fn true_peak_catches_inter_sample_peaks() {
+        let samples: Vec<i16> = (0..4800).flat_map(|i| {
+            let value = (23170.0 * (std::f64::consts::PI * (i as f64 + 0.5) / 2.0).sin()) as i16;
+            [value, value]
+        }).collect();
+        let audio = AudioData { samples, channels: 2, sample_rate: 48000, bits_per_sample: 16, channel_mask: None };
+        let loudness = audio.loudness().unwrap();
+        assert!((loudness.peak_dbfs + 6.02).abs() < 0.05, "{}", loudness.peak_dbfs);
+        assert!(loudness.true_peak_dbtp > loudness.peak_dbfs + 2.5, "{}", loudness.true_peak_dbtp);
+    }
+
+    #[test]
+    fn alignment_gain_brings_tones_to_target() {
+        let target = LoudnessAlignment::TargetLufs(-23.0);
+        for amplitude in [0.05, 0.5] {
+            let tone = stereo_tone(amplitude, 2, 0);
+            let aligned = tone.convert::<f32>().with_gain(target.gain(&tone).unwrap());
+            assert!((aligned.loudness().unwrap().integrated_lufs + 23.0).abs() < 0.1);
+        }
+        assert_eq!(LoudnessAlignment::None.gain(&stereo_tone(0.5, 1, 0)).unwrap(), 1.0);
+        assert!((LoudnessAlignment::GainDb(-6.0).gain(&stereo_tone(0.5, 1, 0)).unwrap() - 0.501).abs() < 1e-3);
+        assert_eq!(target.gain(&stereo_tone(0.0, 1, 1)).unwrap(), 1.0);
+        assert!(matches!(LoudnessAlignment::TargetLufs(f64::NAN).gain(&stereo_tone(0.5, 1, 0)), Err(BramError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn range_outside_audio_is_rejected() {
+        let audio = stereo_tone(0.5, 1, 0);
+        assert!(matches!(audio.loudness_of_range(10, 5), Err(BramError::InvalidParameter(_))));
+        assert!(matches!(audio.loudness_of_range(0, 48001), Err(BramError::InvalidParameter(_))));
+    }
+}
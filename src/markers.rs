@@ -0,0 +1,141 @@
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use crate::wav_binary::WavBinary;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Marker {
+    pub id: u32,
+    pub position: usize,
+    pub length: usize,
+    pub label: String,
+    pub note: Option<String>,
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn read_markers(wav: &WavBinary) -> Vec<Marker> {
+    let chunks = wav.chunk_list();
+    let mut markers: Vec<Marker> = chunks
+        .iter()
+        .filter(|(id, _, _)| id == b"cue ")
+        .flat_map(|(_, offset, size)| {
+            let payload = &wav.data[*offset..*offset + *size];
+            let count = payload.get(0..4).map_or(0, read_u32) as usize;
+            payload
+                .get(4..)
+                .unwrap_or_default()
+                .chunks_exact(24)
+                .take(count)
+                .map(|point| Marker {
+                    id: read_u32(&point[0..4]),
+                    position: read_u32(&point[20..24]) as usize,
+                    length: 0,
+                    label: String::new(),
+                    note: None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    for (id, offset, size) in &chunks {
+        let payload = &wav.data[*offset..*offset + *size];
+        if id != b"LIST" || payload.get(0..4) != Some(b"adtl") {
+            continue;
+        }
+        let mut pos = 4;
+        while pos + 12 <= payload.len() {
+            let sub_id = &payload[pos..pos + 4];
+            let sub_size = read_u32(&payload[pos + 4..pos + 8]) as usize;
+            let body = &payload[pos + 8..(pos + 8 + sub_size).min(payload.len())];
+            let text = || read_text(body.get(4..).unwrap_or_default());
+            if let Some(cue_id) = body.get(0..4).map(read_u32)
+                && let Some(marker) = markers.iter_mut().find(|marker| marker.id == cue_id)
+            {
+                match sub_id {
+                    b"labl" => marker.label = text(),
+                    b"note" => marker.note = Some(text()),
+                    b"ltxt" if body.len() >= 8 => {
+                        marker.length = read_u32(&body[4..8]) as usize;
+                        if marker.label.is_empty() && body.len() > 20 {
+                            marker.label = read_text(&body[20..]);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            pos += 8 + sub_size + sub_size % 2;
+        }
+    }
+    markers.sort_by_key(|marker| marker.position);
+    markers
+}
+
+#[cfg(feature = "std")]
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(feature = "std")]
+fn read_text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod markers_tests {
+    use crate::markers::read_markers;
+    use crate::wav_binary::WavBinary;
+
+    fn chunk(id: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut bytes = id.to_vec();
+        bytes.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(payload);
+        if payload.len() % 2 == 1 {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    fn cue_point(id: u32, offset: u32) -> Vec<u8> {
+        let mut point = id.to_le_bytes().to_vec();
+        point.extend_from_slice(&0u32.to_le_bytes());
+        point.extend_from_slice(b"data");
+        point.extend_from_slice(&[0; 8]);
+        point.extend_from_slice(&offset.to_le_bytes());
+        point
+    }
+
+    #[test]
+    fn read_markers_joins_cue_points_with_adtl_labels() {
+        let mut cue = 2u32.to_le_bytes().to_vec();
+        cue.extend(cue_point(2, 4800));
+        cue.extend(cue_point(1, 1200));
+        let mut adtl = b"adtl".to_vec();
+        adtl.extend(chunk(b"labl", b"\x01\x00\x00\x00Verse\x00"));
+        adtl.extend(chunk(b"note", b"\x01\x00\x00\x00check levels\x00"));
+        let mut ltxt = 2u32.to_le_bytes().to_vec();
+        ltxt.extend_from_slice(&960u32.to_le_bytes());
+        ltxt.extend_from_slice(b"rgn \x00\x00\x00\x00\x00\x00\x00\x00");
+        adtl.extend(chunk(b"ltxt", &ltxt));
+
+        let mut data = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+        data.extend(chunk(b"cue ", &cue));
+        data.extend(chunk(b"LIST", &adtl));
+        data.extend(chunk(b"data", &[]));
+        let markers = read_markers(&WavBinary { data });
+
+        assert_eq!(markers.len(), 2);
+        assert_eq!((markers[0].id, markers[0].position), (1, 1200));
+        assert_eq!(markers[0].label, "Verse");
+        assert_eq!(markers[0].note.as_deref(), Some("check levels"));
+        assert_eq!((markers[1].position, markers[1].length), (4800, 960));
+        assert_eq!(markers[1].label, "");
+    }
+
+    #[test]
+    fn read_markers_without_cue_chunk_is_empty() {
+        let data = b"RIFF\x00\x00\x00\x00WAVEdata\x00\x00\x00\x00".to_vec();
+        assert!(read_markers(&WavBinary { data }).is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+pub(crate) trait FloatMath {
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powf(self, exponent: Self) -> Self;
+    fn powi(self, exponent: i32) -> Self;
+    fn log10(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self)
+    where
+        Self: Sized;
+}
+
+impl FloatMath for f64 {
+    fn round(self) -> Self {
+        libm::round(self)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        libm::pow(self, exponent)
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        libm::pow(self, exponent as f64)
+    }
+
+    fn log10(self) -> Self {
+        libm::log10(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        libm::hypot(self, other)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        libm::sincos(self)
+    }
+}
+
+impl FloatMath for f32 {
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        libm::powf(self, exponent)
+    }
+
+    fn powi(self, exponent: i32) -> Self {
+        libm::powf(self, exponent as f32)
+    }
+
+    fn log10(self) -> Self {
+        libm::log10f(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        libm::hypotf(self, other)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        libm::sincosf(self)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::wav_binary::WavBinary;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WavMetadata {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub comment: Option<String>,
+    pub created: Option<String>,
+    pub genre: Option<String>,
+    pub copyright: Option<String>,
+    pub software: Option<String>,
+    pub other: Vec<(String, String)>,
+}
+
+impl WavMetadata {
+    pub fn is_empty(&self) -> bool {
+        *self == WavMetadata::default()
+    }
+}
+
+pub(crate) fn read_metadata(wav: &WavBinary) -> WavMetadata {
+    let mut metadata = WavMetadata::default();
+    for (id, offset, size) in wav.chunk_list() {
+        let payload = &wav.data[offset..offset + size];
+        if &id != b"LIST" || payload.get(0..4) != Some(b"INFO") {
+            continue;
+        }
+        let mut pos = 4;
+        while pos + 8 <= payload.len() {
+            let tag = &payload[pos..pos + 4];
+            let tag_size = u32::from_le_bytes([payload[pos + 4], payload[pos + 5], payload[pos + 6], payload[pos + 7]]) as usize;
+            let end = (pos + 8 + tag_size).min(payload.len());
+            let value = read_text(&payload[pos + 8..end]);
+            let field = match tag {
+                b"INAM" => Some(&mut metadata.title),
+                b"IART" => Some(&mut metadata.artist),
+                b"ICMT" => Some(&mut metadata.comment),
+                b"ICRD" => Some(&mut metadata.created),
+                b"IGNR" => Some(&mut metadata.genre),
+                b"ICOP" => Some(&mut metadata.copyright),
+                b"ISFT" => Some(&mut metadata.software),
+                _ => None,
+            };
+            match field {
+                Some(field) => *field = Some(value),
+                None => metadata.other.push((String::from_utf8_lossy(tag).into_owned(), value)),
+            }
+            pos += 8 + tag_size + tag_size % 2;
+        }
+    }
+    metadata
+}
+
+fn read_text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|byte| *byte == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim_end().to_string()
+}
+
+#[cfg(test)]
+mod metadata_tests {
+    use crate::metadata::{read_metadata, WavMetadata};
+    use crate::wav_binary::WavBinary;
+
+    fn wav_with_info(tags: &[(&[u8; 4], &str)]) -> WavBinary {
+        let mut info = b"INFO".to_vec();
+        for (tag, value) in tags {
+            let mut text = value.as_bytes().to_vec();
+            text.push(0);
+            info.extend_from_slice(*tag);
+            info.extend_from_slice(&(text.len() as u32).to_le_bytes());
+            if text.len() % 2 == 1 {
+                text.push(0);
+            }
+            info.extend_from_slice(&text);
+        }
+        let mut data = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+        data.extend_from_slice(b"LIST");
+        data.extend_from_slice(&(info.len() as u32).to_le_bytes());
+        data.extend_from_slice(&info);
+        data.extend_from_slice(b"data\x00\x00\x00\x00");
+        WavBinary { data }
+    }
+
+    #[test]
+    fn read_metadata_parses_info_tags() {
+        let wav = wav_with_info(&[(b"INAM", "Take 3"), (b"IART", "Bram"), (b"ICRD", "2024-05-01"), (b"ITCH", "ed")]);
+        let metadata = read_metadata(&wav);
+        assert_eq!(metadata.title.as_deref(), Some("Take 3"));
+        assert_eq!(metadata.artist.as_deref(), Some("Bram"));
+        assert_eq!(metadata.created.as_deref(), Some("2024-05-01"));
+        assert_eq!(metadata.comment, None);
+        assert_eq!(metadata.other, vec![("ITCH".to_string(), "ed".to_string())]);
+    }
+
+    #[test]
+    fn read_metadata_without_list_chunk_is_empty() {
+        let wav = WavBinary { data: b"RIFF\x00\x00\x00\x00WAVEdata\x00\x00\x00\x00".to_vec() };
+        assert!(read_metadata(&wav).is_empty());
+        assert_eq!(read_metadata(&wav), WavMetadata::default());
+    }
+}
@@ -0,0 +1,114 @@
+use std::f32::consts::PI;
+use crate::analysis::{frames, mel_filterbank, mono_samples};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::fft;
+
+const LOG_FLOOR: f32 = 1e-10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MfccConfig {
+    pub frame_size: usize,
+    pub hop_size: usize,
+    pub mel_bands: usize,
+    pub coefficients: usize,
+}
+
+impl Default for MfccConfig {
+    fn default() -> Self {
+        Self {
+            frame_size: 512,
+            hop_size: 256,
+            mel_bands: 26,
+            coefficients: 13,
+        }
+    }
+}
+
+pub fn mfcc(audio: &AudioData, config: &MfccConfig) -> Result<Vec<Vec<f32>>, BramError> {
+    if !config.frame_size.is_power_of_two() || config.frame_size < 2 || config.hop_size == 0 {
+        return Err(BramError::InvalidParameter("frame size must be a power of two and hop size non-zero"));
+    }
+    if config.coefficients == 0 || config.coefficients > config.mel_bands {
+        return Err(BramError::InvalidParameter("coefficients must be between 1 and the number of mel bands"));
+    }
+    let samples = mono_samples(audio);
+    let filterbank = mel_filterbank(config.mel_bands, config.frame_size, audio.sample_rate);
+    let window = fft::hann_window(config.frame_size);
+    Ok(frames(&samples, config.frame_size, config.hop_size)
+        .map(|frame| {
+            let spectrum = fft::power_spectrum(frame, &window);
+            let log_energies: Vec<f32> = filterbank
+                .iter()
+                .map(|weights| {
+                    let energy: f32 = weights.iter().map(|(bin, weight)| spectrum[*bin] * weight).sum();
+                    (energy + LOG_FLOOR).ln()
+                })
+                .collect();
+            dct(&log_energies, config.coefficients)
+        })
+        .collect())
+}
+
+fn dct(input: &[f32], coefficients: usize) -> Vec<f32> {
+    let n = input.len() as f32;
+    (0..coefficients)
+        .map(|k| {
+            let scale = if k == 0 { (1.0 / n).sqrt() } else { (2.0 / n).sqrt() };
+            scale * input
+                .iter()
+                .enumerate()
+                .map(|(i, value)| value * (PI * k as f32 * (i as f32 + 0.5) / n).cos())
+                .sum::<f32>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod mfcc_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::mfcc::{dct, mfcc, MfccConfig};
+
+    fn tone(frequency: f32, sample_rate: u32) -> AudioData {
+        AudioData {
+            samples: (0..sample_rate)
+                .map(|i| (16383.0 * (2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32).sin()) as i16)
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn dct_of_constant_only_has_first_coefficient() {
+        let coefficients = dct(&[2.0; 8], 4);
+        assert!((coefficients[0] - 2.0 * 8f32.sqrt()).abs() < 1e-4);
+        assert!(coefficients[1..].iter().all(|c| c.abs() < 1e-4));
+    }
+
+    #[test]
+    fn mfcc_returns_one_vector_per_frame() {
+        let config = MfccConfig::default();
+        let frames = mfcc(&tone(440.0, 8000), &config).unwrap();
+        assert_eq!(frames.len(), (8000 - 512) / 256 + 1);
+        assert!(frames.iter().all(|frame| frame.len() == config.coefficients));
+    }
+
+    #[test]
+    fn mfcc_distinguishes_low_and_high_tones() {
+        let config = MfccConfig::default();
+        let low = mfcc(&tone(200.0, 8000), &config).unwrap();
+        let high = mfcc(&tone(3000.0, 8000), &config).unwrap();
+        assert!(low[4][1] > high[4][1]);
+    }
+
+    #[test]
+    fn mfcc_rejects_more_coefficients_than_bands() {
+        let config = MfccConfig { mel_bands: 10, coefficients: 12, ..MfccConfig::default() };
+        let result = mfcc(&tone(440.0, 8000), &config);
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+}
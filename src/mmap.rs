@@ -0,0 +1,88 @@
+use std::fs::File;
+use std::path::Path;
+use memmap2::Mmap;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::wav_binary::{self, HeaderDiagnosis, WavBinary};
+
+pub struct MappedWav {
+    map: Mmap,
+}
+
+impl MappedWav {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, BramError> {
+        let file = File::open(path)?;
+        // SAFETY: the map is only read; callers must not truncate the file while it is mapped.
+        let map = unsafe { Mmap::map(&file)? };
+        let wav = MappedWav { map };
+        if !wav.check() {
+            return Err(BramError::Header(wav.diagnose_header()));
+        }
+        Ok(wav)
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.map
+    }
+
+    pub fn check(&self) -> bool {
+        self.diagnose_header() == HeaderDiagnosis::Valid
+    }
+
+    pub fn diagnose_header(&self) -> HeaderDiagnosis {
+        wav_binary::diagnose(&self.map)
+    }
+}
+
+impl TryFrom<&MappedWav> for AudioData {
+    type Error = BramError;
+
+    fn try_from(wav: &MappedWav) -> Result<Self, Self::Error> {
+        if &wav.map[0..4] == b"RIFX" {
+            return AudioData::try_from(&WavBinary::from_bytes(wav.bytes())?);
+        }
+        AudioData::from_wav_bytes(wav.bytes())
+    }
+}
+
+#[cfg(test)]
+mod mmap_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::mmap::MappedWav;
+
+    #[test]
+    fn mapped_wav_decodes_like_wav_binary() {
+        let path = std::env::temp_dir().join("mmap_test.wav");
+        let mut data = Vec::new();
+        data.extend_from_slice(b"RIFF");
+        data.extend_from_slice(&44u32.to_le_bytes());
+        data.extend_from_slice(b"WAVE");
+        data.extend_from_slice(b"fmt ");
+        data.extend_from_slice(&16u32.to_le_bytes());
+        data.extend_from_slice(&[0x01, 0x00, 0x01, 0x00]);
+        data.extend_from_slice(&8000u32.to_le_bytes());
+        data.extend_from_slice(&16000u32.to_le_bytes());
+        data.extend_from_slice(&[0x02, 0x00, 0x10, 0x00]);
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&8u32.to_le_bytes());
+        for sample in [1i16, -1, 32767, -32768] {
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+        fs::write(&path, data).unwrap();
+
+        let wav = MappedWav::open(path.to_str().unwrap()).unwrap();
+        let audio = AudioData::try_from(&wav).unwrap();
+        assert_eq!(audio.samples, vec![1, -1, 32767, -32768]);
+        assert_eq!(audio.sample_rate, 8000);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn mapped_wav_rejects_non_wav_file() {
+        let path = std::env::temp_dir().join("mmap_test_invalid.wav");
+        fs::write(&path, b"not a wave file at all").unwrap();
+        assert!(MappedWav::open(path.to_str().unwrap()).is_err());
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{Cursor, ErrorKind};
+use std::path::Path;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+
+pub(crate) fn has_mp3_extension(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("mp3"))
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<AudioData, BramError> {
+    let source = MediaSourceStream::new(Box::new(Cursor::new(data.to_vec())), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension("mp3");
+    let probed = symphonia::default::get_probe()
+        .format(&hint, source, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(invalid_data)?;
+    let mut format = probed.format;
+    let track = format
+        .default_track()
+        .ok_or_else(|| BramError::Decode("no mpeg audio track found".to_string()))?;
+    let track_id = track.id;
+    let mut channels = track.codec_params.channels.map_or(0, |channels| channels.count() as u16);
+    let mut sample_rate = track.codec_params.sample_rate.unwrap_or(0);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(invalid_data)?;
+
+    let mut samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error)) if error.kind() == ErrorKind::UnexpectedEof => break,
+            Err(error) => return Err(invalid_data(error)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                channels = spec.channels.count() as u16;
+                sample_rate = spec.rate;
+                let mut buffer = SampleBuffer::<i16>::new(decoded.capacity() as u64, spec);
+                buffer.copy_interleaved_ref(decoded);
+                samples.extend_from_slice(buffer.samples());
+            }
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => return Err(invalid_data(error)),
+        }
+    }
+
+    Ok(AudioData {
+        samples,
+        channels,
+        sample_rate,
+        bits_per_sample: 16,
+        channel_mask: None,
+    })
+}
+
+fn invalid_data(error: SymphoniaError) -> BramError {
+    BramError::Decode(error.to_string())
+}
+
+#[cfg(test)]
+mod mp3_tests {
+    use crate::mp3::{has_mp3_extension, parse};
+
+    fn silent_mp3(frames: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        for _ in 0..frames {
+            let mut frame = vec![0u8; 417];
+            frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+            data.extend_from_slice(&frame);
+        }
+        data
+    }
+
+    #[test]
+    fn parse_silent_stereo_mp3() {
+        let audio = parse(&silent_mp3(8)).unwrap();
+        assert_eq!(audio.channels, 2);
+        assert_eq!(audio.sample_rate, 44100);
+        assert!(!audio.samples.is_empty());
+        assert!(audio.samples.iter().all(|sample| *sample == 0));
+    }
+
+    #[test]
+    fn parse_rejects_non_mp3() {
+        assert!(parse(b"RIFF\x00\x00\x00\x00WAVE").is_err());
+    }
+
+    #[test]
+    fn has_mp3_extension_is_case_insensitive() {
+        assert!(has_mp3_extension("episode.MP3"));
+        assert!(!has_mp3_extension("episode.wav"));
+    }
+}
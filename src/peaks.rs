@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+use crate::audio_data::AudioData;
+use crate::audio_presentation::{normalize, Envelope};
+use crate::error::BramError;
+
+const MAGIC: &[u8; 4] = b"RPKN";
+const HEADER_LEN: usize = 22;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Peaks {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub source_len: u32,
+    pub frames_per_peak: u32,
+    pub max: Vec<i16>,
+    pub min: Vec<i16>,
+}
+
+impl Peaks {
+    pub fn from_audio(audio: &AudioData, frames_per_peak: u32) -> Result<Self, BramError> {
+        if frames_per_peak == 0 {
+            return Err(BramError::InvalidParameter("frames per peak must be non-zero"));
+        }
+        if audio.channels == 0 || audio.channels > u8::MAX as u16 {
+            return Err(BramError::UnsupportedChannels { channels: audio.channels });
+        }
+        let channels = audio.channels as usize;
+        let mut max = Vec::new();
+        let mut min = Vec::new();
+        for block in audio.samples.chunks(channels * frames_per_peak as usize) {
+            for channel in 0..channels {
+                let samples = block.iter().skip(channel).step_by(channels);
+                max.push(samples.clone().copied().max().unwrap_or(0));
+                min.push(samples.copied().min().unwrap_or(0));
+            }
+        }
+        Ok(Self {
+            channels: audio.channels,
+            sample_rate: audio.sample_rate,
+            source_len: 0,
+            frames_per_peak,
+            max,
+            min,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.max.len() / self.channels.max(1) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.max.is_empty()
+    }
+
+    pub fn envelope(&self, channel: u16) -> Option<Envelope> {
+        if channel >= self.channels {
+            return None;
+        }
+        let channels = self.channels as usize;
+        let pick = |values: &[i16]| values.iter().skip(channel as usize).step_by(channels).map(|value| normalize(*value)).collect();
+        Some(Envelope {
+            low: pick(&self.min),
+            high: pick(&self.max),
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.max.len() * 4);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(self.channels as u8);
+        bytes.push(1);
+        bytes.extend_from_slice(&self.sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&self.source_len.to_le_bytes());
+        bytes.extend_from_slice(&self.frames_per_peak.to_le_bytes());
+        bytes.extend_from_slice(&(self.len() as u32).to_le_bytes());
+        for (max, min) in self.max.iter().zip(&self.min) {
+            bytes.extend_from_slice(&max.to_le_bytes());
+            bytes.extend_from_slice(&min.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, BramError> {
+        if bytes.len() < HEADER_LEN || &bytes[0..4] != MAGIC {
+            return Err(BramError::Decode("not a peaks file".to_string()));
+        }
+        let read_u32 = |offset: usize| u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]]);
+        let channels = bytes[4] as u16;
+        if channels == 0 || bytes[5] == 0 {
+            return Err(BramError::Decode("peaks file has no channels or mipmaps".to_string()));
+        }
+        let count = read_u32(18) as usize * channels as usize;
+        let data = bytes
+            .get(HEADER_LEN..HEADER_LEN + count * 4)
+            .ok_or(BramError::Truncated { offset: bytes.len(), chunk: *MAGIC })?;
+        let (max, min) = data
+            .chunks_exact(4)
+            .map(|b| (i16::from_le_bytes([b[0], b[1]]), i16::from_le_bytes([b[2], b[3]])))
+            .unzip();
+        Ok(Self {
+            channels,
+            sample_rate: read_u32(6),
+            source_len: read_u32(10),
+            frames_per_peak: read_u32(14),
+            max,
+            min,
+        })
+    }
+}
+
+pub fn write_peaks(peaks: &Peaks, path: impl AsRef<Path>) -> Result<(), BramError> {
+    fs::write(path, peaks.encode())?;
+    Ok(())
+}
+
+pub fn read_peaks(path: impl AsRef<Path>) -> Result<Peaks, BramError> {
+    Peaks::decode(&fs::read(path)?)
+}
+
+#[cfg(test)]
+mod peaks_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::peaks::Peaks;
+
+    fn stereo(samples: Vec<i16>) -> AudioData {
+        AudioData {
+            samples,
+            channels: 2,
+            sample_rate: 8000,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn from_audio_keeps_min_and_max_per_block() {
+        let audio = stereo(vec![0, 5, 900, -5, -700, 7, 3, 1, 40, -2]);
+        let peaks = Peaks::from_audio(&audio, 3).unwrap();
+        assert_eq!(peaks.len(), 2);
+        assert_eq!(peaks.max, vec![900, 7, 40, 1]);
+        assert_eq!(peaks.min, vec![-700, -5, 3, -2]);
+        let right = peaks.envelope(1).unwrap();
+        assert!((right.high[0] - (7.0 / 32768.0 + 1.0) / 2.0).abs() < 1e-6);
+        assert!(peaks.envelope(2).is_none());
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let mut peaks = Peaks::from_audio(&stereo(vec![1, -1, 32767, -32768, 12, 34]), 2).unwrap();
+        peaks.source_len = 1234;
+        assert_eq!(Peaks::decode(&peaks.encode()).unwrap(), peaks);
+    }
+
+    #[test]
+    fn decode_rejects_bad_magic_and_truncated_data() {
+        let bytes = Peaks::from_audio(&stereo(vec![1, 2, 3, 4]), 1).unwrap().encode();
+        assert!(matches!(Peaks::decode(&bytes[..bytes.len() - 1]), Err(BramError::Truncated { .. })));
+        let mut wrong = bytes.clone();
+        wrong[0] = b'X';
+        assert!(matches!(Peaks::decode(&wrong), Err(BramError::Decode(_))));
+    }
+
+    #[test]
+    fn from_audio_rejects_zero_block() {
+        assert!(matches!(Peaks::from_audio(&stereo(vec![0, 0]), 0), Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::hash::Fnv1a;
+
+const MAGIC: &[u8; 4] = b"BPKS";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PeaksKey {
+    pub content_hash: u64,
+    pub rate: u32,
+}
+
+pub trait PeaksStore {
+    fn get(&self, key: &PeaksKey) -> Result<Option<StereoAudioPresentation>, BramError>;
+    fn put(&self, key: &PeaksKey, presentation: &StereoAudioPresentation) -> Result<(), BramError>;
+}
+
+pub struct FsPeaksStore {
+    root: PathBuf,
+}
+
+impl FsPeaksStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &PeaksKey) -> PathBuf {
+        self.root
+            .join(format!("{:016x}", key.content_hash))
+            .join(format!("{}.peaks", key.rate))
+    }
+}
+
+impl PeaksStore for FsPeaksStore {
+    fn get(&self, key: &PeaksKey) -> Result<Option<StereoAudioPresentation>, BramError> {
+        match fs::read(self.path_for(key)) {
+            Ok(bytes) => decode(&bytes).map(Some),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn put(&self, key: &PeaksKey, presentation: &StereoAudioPresentation) -> Result<(), BramError> {
+        let path = self.path_for(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("peaks.tmp");
+        fs::write(&temp_path, encode(presentation))?;
+        fs::rename(temp_path, path)?;
+        Ok(())
+    }
+}
+
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn encode(presentation: &StereoAudioPresentation) -> Vec<u8> {
+    let count = presentation.left_channel_points.len();
+    let mut bytes = Vec::with_capacity(8 + count * 8);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&(count as u32).to_le_bytes());
+    for point in presentation.left_channel_points.iter().chain(&presentation.right_channel_points) {
+        bytes.extend_from_slice(&point.to_le_bytes());
+    }
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> Result<StereoAudioPresentation, BramError> {
+    if bytes.len() < 8 || &bytes[0..4] != MAGIC {
+        return Err(BramError::Decode("not a peaks file".to_string()));
+    }
+    let count = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    if bytes.len() != 8 + count * 8 {
+        return Err(BramError::Decode("incomplete peaks file".to_string()));
+    }
+    let mut points = bytes[8..]
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let left_channel_points = points.by_ref().take(count).collect();
+    let right_channel_points = points.collect();
+    Ok(StereoAudioPresentation {
+        left_channel_points,
+        right_channel_points,
+        left_envelope: None,
+        right_envelope: None,
+        spectral_centroid: None,
+        annotations: Vec::new(),
+        source_sample_rate: 0,
+        source_channels: 0,
+        source_frames: 0,
+        samples_per_point: 0,
+    })
+}
+
+#[cfg(test)]
+mod peaks_store_tests {
+    use std::fs;
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::peaks_store::{content_hash, decode, encode, FsPeaksStore, PeaksKey, PeaksStore};
+
+    #[test]
+    fn content_hash_is_stable_and_content_sensitive() {
+        assert_eq!(content_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(content_hash(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(content_hash(b"RIFF1"), content_hash(b"RIFF2"));
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.0, 0.5, 1.0],
+            right_channel_points: vec![0.25, 0.75, 0.5],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        };
+        let decoded = decode(&encode(&presentation)).unwrap();
+        assert_eq!(decoded.left_channel_points, presentation.left_channel_points);
+        assert_eq!(decoded.right_channel_points, presentation.right_channel_points);
+    }
+
+    #[test]
+    fn decode_rejects_truncated_file() {
+        let mut bytes = encode(&StereoAudioPresentation {
+            left_channel_points: vec![0.5],
+            right_channel_points: vec![0.5],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        });
+        bytes.pop();
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn fs_store_put_then_get() {
+        let root = std::env::temp_dir().join("bram_peaks_store_test");
+        let store = FsPeaksStore::new(&root);
+        let key = PeaksKey { content_hash: 42, rate: 100 };
+        assert!(store.get(&key).unwrap().is_none());
+
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![0.1, 0.2],
+            right_channel_points: vec![0.3, 0.4],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        };
+        store.put(&key, &presentation).unwrap();
+        let cached = store.get(&key).unwrap().unwrap();
+        assert_eq!(cached.left_channel_points, vec![0.1, 0.2]);
+        assert!(store.get(&PeaksKey { content_hash: 42, rate: 50 }).unwrap().is_none());
+        let _ = fs::remove_dir_all(root);
+    }
+}
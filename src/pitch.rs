@@ -0,0 +1,145 @@
+use std::time::Duration;
+use crate::analysis::{frames, mono_samples};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PitchConfig {
+    pub frame_size: usize,
+    pub hop_size: usize,
+    pub min_frequency: f32,
+    pub max_frequency: f32,
+    pub threshold: f32,
+}
+
+impl Default for PitchConfig {
+    fn default() -> Self {
+        Self {
+            frame_size: 2048,
+            hop_size: 512,
+            min_frequency: 50.0,
+            max_frequency: 2000.0,
+            threshold: 0.15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PitchEstimate {
+    pub time: Duration,
+    pub frequency: Option<f32>,
+    pub clarity: f32,
+}
+
+pub fn detect_pitch(audio: &AudioData, config: &PitchConfig) -> Result<Vec<PitchEstimate>, BramError> {
+    if config.frame_size < 4 || config.hop_size == 0 {
+        return Err(BramError::InvalidParameter("pitch frame size must be at least four and hop size non-zero"));
+    }
+    if config.min_frequency <= 0.0 || config.max_frequency <= config.min_frequency {
+        return Err(BramError::InvalidParameter("pitch frequency range must be positive and increasing"));
+    }
+    let sample_rate = audio.sample_rate as f32;
+    let max_lag = ((sample_rate / config.min_frequency).ceil() as usize).min(config.frame_size / 2);
+    let min_lag = ((sample_rate / config.max_frequency).floor() as usize).max(2);
+    let samples = mono_samples(audio);
+    Ok(frames(&samples, config.frame_size, config.hop_size)
+        .enumerate()
+        .map(|(index, frame)| {
+            let time = Duration::from_secs_f64((index * config.hop_size) as f64 / audio.sample_rate.max(1) as f64);
+            match yin(frame, min_lag, max_lag, config.threshold) {
+                Some((lag, difference)) => PitchEstimate {
+                    time,
+                    frequency: Some(sample_rate / lag),
+                    clarity: 1.0 - difference,
+                },
+                None => PitchEstimate { time, frequency: None, clarity: 0.0 },
+            }
+        })
+        .collect())
+}
+
+fn yin(frame: &[f32], min_lag: usize, max_lag: usize, threshold: f32) -> Option<(f32, f32)> {
+    if max_lag <= min_lag || frame.len() < max_lag * 2 {
+        return None;
+    }
+    let window = frame.len() - max_lag;
+    let difference: Vec<f32> = (0..=max_lag)
+        .map(|lag| (0..window).map(|i| (frame[i] - frame[i + lag]).powi(2)).sum())
+        .collect();
+    let mut running = 0.0f32;
+    let normalized: Vec<f32> = difference
+        .iter()
+        .enumerate()
+        .map(|(lag, value)| {
+            if lag == 0 {
+                return 1.0;
+            }
+            running += value;
+            if running > 0.0 { value * lag as f32 / running } else { 1.0 }
+        })
+        .collect();
+
+    let mut lag = min_lag;
+    while lag < max_lag {
+        if normalized[lag] < threshold {
+            while lag + 1 < max_lag && normalized[lag + 1] < normalized[lag] {
+                lag += 1;
+            }
+            let (before, at, after) = (normalized[lag - 1], normalized[lag], normalized[lag + 1]);
+            let curvature = before - 2.0 * at + after;
+            let offset = if curvature > 0.0 { 0.5 * (before - after) / curvature } else { 0.0 };
+            return Some((lag as f32 + offset, at));
+        }
+        lag += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod pitch_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::pitch::{detect_pitch, PitchConfig};
+
+    fn tone(frequency: f32, sample_rate: u32, frames: u32) -> AudioData {
+        AudioData {
+            samples: (0..frames)
+                .map(|i| {
+                    let phase = 2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32;
+                    (12000.0 * (phase.sin() + 0.5 * (2.0 * phase).sin())) as i16
+                })
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn detects_fundamental_of_harmonic_tone() {
+        for frequency in [110.0, 220.0, 440.0] {
+            let estimates = detect_pitch(&tone(frequency, 16000, 8000), &PitchConfig::default()).unwrap();
+            assert!(!estimates.is_empty());
+            for estimate in &estimates {
+                let detected = estimate.frequency.unwrap();
+                assert!((detected - frequency).abs() < frequency * 0.01, "expected {} got {}", frequency, detected);
+                assert!(estimate.clarity > 0.85);
+            }
+        }
+    }
+
+    #[test]
+    fn silence_has_no_pitch_and_times_advance_by_hop() {
+        let silent = AudioData { samples: vec![0i16; 8000], channels: 1, sample_rate: 16000, bits_per_sample: 16, channel_mask: None };
+        let estimates = detect_pitch(&silent, &PitchConfig::default()).unwrap();
+        assert!(estimates.iter().all(|estimate| estimate.frequency.is_none()));
+        assert_eq!(estimates[1].time.as_millis(), 32);
+    }
+
+    #[test]
+    fn rejects_inverted_frequency_range() {
+        let config = PitchConfig { min_frequency: 500.0, max_frequency: 100.0, ..PitchConfig::default() };
+        assert!(matches!(detect_pitch(&tone(220.0, 16000, 4000), &config), Err(BramError::InvalidParameter(_))));
+    }
+}
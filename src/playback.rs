@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::resample::ResampleQuality;
+use crate::sample::Sample;
+
+pub struct PlaybackHandle {
+    stream: cpal::Stream,
+    position: Arc<AtomicUsize>,
+    finished: Arc<AtomicBool>,
+    channels: usize,
+    sample_rate: u32,
+}
+
+impl PlaybackHandle {
+    pub fn stop(self) {
+        let _ = self.stream.pause();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+
+    pub fn position(&self) -> std::time::Duration {
+        let frames = self.position.load(Ordering::Acquire) / self.channels.max(1);
+        std::time::Duration::from_secs_f64(frames as f64 / self.sample_rate.max(1) as f64)
+    }
+}
+
+pub fn play<S: Sample>(audio: &AudioData<S>) -> Result<PlaybackHandle, BramError> {
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| BramError::Decode("no audio output device available".to_string()))?;
+    let config: cpal::StreamConfig = device.default_output_config().map_err(playback_error)?.into();
+    let channels = config.channels as usize;
+    let resampled = audio.resample(config.sample_rate.0, ResampleQuality::Linear)?;
+    let samples = Arc::new(device_samples(&resampled, channels));
+    let position = Arc::new(AtomicUsize::new(0));
+    let finished = Arc::new(AtomicBool::new(samples.is_empty()));
+
+    let (stream_position, stream_finished) = (position.clone(), finished.clone());
+    let stream = device
+        .build_output_stream(
+            &config,
+            move |output: &mut [f32], _| {
+                let start = stream_position.load(Ordering::Acquire).min(samples.len());
+                let available = (samples.len() - start).min(output.len());
+                output[..available].copy_from_slice(&samples[start..start + available]);
+                output[available..].fill(0.0);
+                stream_position.store(start + available, Ordering::Release);
+                if start + available == samples.len() {
+                    stream_finished.store(true, Ordering::Release);
+                }
+            },
+            |_| {},
+            None,
+        )
+        .map_err(playback_error)?;
+    stream.play().map_err(playback_error)?;
+    Ok(PlaybackHandle {
+        stream,
+        position,
+        finished,
+        channels,
+        sample_rate: config.sample_rate.0,
+    })
+}
+
+fn device_samples<S: Sample>(audio: &AudioData<S>, device_channels: usize) -> Vec<f32> {
+    let channels = audio.channels.max(1) as usize;
+    audio
+        .samples
+        .chunks_exact(channels)
+        .flat_map(|frame| (0..device_channels).map(move |channel| frame[channel.min(channels - 1)].to_f64() as f32))
+        .collect()
+}
+
+fn playback_error(error: impl std::fmt::Display) -> BramError {
+    BramError::Decode(error.to_string())
+}
+
+#[cfg(test)]
+mod playback_tests {
+    use crate::audio_data::AudioData;
+    use crate::playback::device_samples;
+
+    #[test]
+    fn device_samples_map_channels_onto_device_layout() {
+        let mono = AudioData { samples: vec![16384i16, -16384], channels: 1, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+        assert_eq!(device_samples(&mono, 2), vec![0.5, 0.5, -0.5, -0.5]);
+        let stereo = AudioData { samples: vec![0.25f32, -0.25, 0.5, -0.5], channels: 2, sample_rate: 8000, bits_per_sample: 32, channel_mask: None };
+        assert_eq!(device_samples(&stereo, 1), vec![0.25, 0.5]);
+        assert_eq!(device_samples(&stereo, 3), vec![0.25, -0.25, -0.25, 0.5, -0.5, -0.5]);
+    }
+}
@@ -0,0 +1,101 @@
+use plotters::prelude::*;
+use plotters::coord::types::{RangedCoordf32, RangedCoordf64};
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+
+pub type WaveformChart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf32>>;
+
+impl StereoAudioPresentation {
+    pub fn left_series<DB: DrawingBackend>(&self, style: impl Into<ShapeStyle>) -> LineSeries<DB, (f64, f32)> {
+        LineSeries::new(self.trace(&self.left_channel_points), style)
+    }
+
+    pub fn right_series<DB: DrawingBackend>(&self, style: impl Into<ShapeStyle>) -> LineSeries<DB, (f64, f32)> {
+        LineSeries::new(self.trace(&self.right_channel_points), style)
+    }
+
+    pub fn draw_on_chart<DB: DrawingBackend>(&self, chart: &mut WaveformChart<'_, DB>) -> Result<(), BramError> {
+        chart
+            .draw_series(self.left_series(BLUE))
+            .map_err(|error| BramError::Decode(error.to_string()))?
+            .label("left");
+        chart
+            .draw_series(self.right_series(RED))
+            .map_err(|error| BramError::Decode(error.to_string()))?
+            .label("right");
+        Ok(())
+    }
+
+    fn trace(&self, points: &[f32]) -> Vec<(f64, f32)> {
+        points.iter().enumerate().map(|(index, point)| (self.point_time(index), *point)).collect()
+    }
+}
+
+#[cfg(test)]
+mod plot_tests {
+    use std::cell::RefCell;
+    use std::io;
+    use std::rc::Rc;
+    use plotters::prelude::*;
+    use plotters_backend::{BackendColor, BackendCoord, DrawingErrorKind};
+    use crate::audio_presentation::StereoAudioPresentation;
+
+    #[derive(Default)]
+    struct RecordingBackend {
+        colors: Rc<RefCell<Vec<(u8, u8, u8)>>>,
+    }
+
+    impl DrawingBackend for RecordingBackend {
+        type ErrorType = io::Error;
+
+        fn get_size(&self) -> (u32, u32) {
+            (100, 100)
+        }
+
+        fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<io::Error>> {
+            Ok(())
+        }
+
+        fn present(&mut self) -> Result<(), DrawingErrorKind<io::Error>> {
+            Ok(())
+        }
+
+        fn draw_pixel(&mut self, _: BackendCoord, color: BackendColor) -> Result<(), DrawingErrorKind<io::Error>> {
+            self.colors.borrow_mut().push(color.rgb);
+            Ok(())
+        }
+    }
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.0, 1.0, 0.5],
+            right_channel_points: vec![1.0, 0.0, 0.5],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 10,
+            source_channels: 2,
+            source_frames: 30,
+            samples_per_point: 10,
+        }
+    }
+
+    #[test]
+    fn series_are_indexed_by_point_time() {
+        let presentation = presentation();
+        assert_eq!(presentation.trace(&presentation.left_channel_points), vec![(0.0, 0.0), (1.0, 1.0), (2.0, 0.5)]);
+    }
+
+    #[test]
+    fn draw_on_chart_plots_both_channels() {
+        let backend = RecordingBackend::default();
+        let colors = backend.colors.clone();
+        let root = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0f64..2f64, 0f32..1f32).unwrap();
+        presentation().draw_on_chart(&mut chart).unwrap();
+        root.present().unwrap();
+        assert!(colors.borrow().contains(&(0, 0, 255)));
+        assert!(colors.borrow().contains(&(255, 0, 0)));
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::Cursor;
+use std::path::Path;
+use image::{ImageFormat, Rgba, RgbaImage};
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::svg::column_bounds;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PngStyle {
+    pub background: [u8; 4],
+    pub left_color: [u8; 4],
+    pub right_color: [u8; 4],
+}
+
+impl Default for PngStyle {
+    fn default() -> Self {
+        Self {
+            background: [0, 0, 0, 0],
+            left_color: [0x3b, 0x82, 0xf6, 0xff],
+            right_color: [0xf9, 0x73, 0x16, 0xff],
+        }
+    }
+}
+
+impl StereoAudioPresentation {
+    pub fn render_png(&self, width: u32, height: u32, style: &PngStyle) -> Result<Vec<u8>, BramError> {
+        if width == 0 || height < 2 {
+            return Err(BramError::InvalidParameter("png must be at least one pixel wide and two pixels high"));
+        }
+        let mut image = RgbaImage::from_pixel(width, height, Rgba(style.background));
+        let lane = height / 2;
+        let channels = [
+            (&self.left_channel_points, self.left_envelope.as_ref(), style.left_color, 0),
+            (&self.right_channel_points, self.right_envelope.as_ref(), style.right_color, lane),
+        ];
+        for (points, envelope, color, top) in channels {
+            let row = |value: f32| top + ((1.0 - value.clamp(0.0, 1.0)) * (lane - 1) as f32).round() as u32;
+            for (x, (low, high)) in column_bounds(points, envelope, width as usize).into_iter().enumerate() {
+                for y in row(high)..=row(low) {
+                    image.put_pixel(x as u32, y, Rgba(color));
+                }
+            }
+        }
+        let mut bytes = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .map_err(|error| BramError::Decode(error.to_string()))?;
+        Ok(bytes)
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>, width: u32, height: u32, style: &PngStyle) -> Result<(), BramError> {
+        std::fs::write(path, self.render_png(width, height, style)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod png_tests {
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::error::BramError;
+    use crate::png::PngStyle;
+
+    #[test]
+    fn render_png_fills_each_channel_lane() {
+        let presentation = StereoAudioPresentation {
+            left_channel_points: vec![1.0, 0.5],
+            right_channel_points: vec![0.5, 0.0],
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 4,
+            source_channels: 2,
+            source_frames: 4,
+            samples_per_point: 2,
+        };
+        let style = PngStyle::default();
+        let bytes = presentation.render_png(2, 10, &style).unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+        let image = image::load_from_memory(&bytes).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (2, 10));
+        assert_eq!(image.get_pixel(0, 0).0, style.left_color);
+        assert_eq!(image.get_pixel(1, 0).0, style.background);
+        assert_eq!(image.get_pixel(1, 2).0, style.left_color);
+        assert_eq!(image.get_pixel(1, 9).0, style.right_color);
+        assert_eq!(image.get_pixel(0, 9).0, style.background);
+        assert!(matches!(presentation.render_png(0, 10, &style), Err(BramError::InvalidParameter(_))));
+    }
+}
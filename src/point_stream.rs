@@ -0,0 +1,147 @@
+use std::io::Read;
+use std::time::Duration;
+use crate::audio_data::SampleStream;
+use crate::audio_presentation::{DownsampleStrategy, NormalizationMode, PresentationAccumulator, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::weighting::Weighting;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PresentationPoint {
+    pub index: usize,
+    pub time: Duration,
+    pub left: f32,
+    pub right: f32,
+}
+
+pub struct PresentationPoints<R> {
+    stream: SampleStream<R>,
+    accumulator: Option<PresentationAccumulator>,
+    pending: StereoAudioPresentation,
+    consumed: usize,
+    index: usize,
+}
+
+impl<R: Read> PresentationPoints<R> {
+    pub(crate) fn open(reader: R, rate: u32, mode: DownsampleStrategy) -> Result<Self, BramError> {
+        let stream = SampleStream::open(reader)?;
+        let accumulator = PresentationAccumulator::new(
+            stream.channels,
+            stream.sample_rate,
+            rate,
+            mode,
+            Weighting::None,
+            NormalizationMode::ZeroToOne,
+        )?;
+        let pending = StereoAudioPresentation {
+            left_channel_points: Vec::new(),
+            right_channel_points: Vec::new(),
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        };
+        Ok(Self { stream, accumulator: Some(accumulator), pending, consumed: 0, index: 0 })
+    }
+
+    fn pop(&mut self) -> Option<PresentationPoint> {
+        if self.consumed == self.pending.left_channel_points.len() {
+            self.pending.left_channel_points.clear();
+            self.pending.right_channel_points.clear();
+            self.consumed = 0;
+            return None;
+        }
+        let point = PresentationPoint {
+            index: self.index,
+            time: self.pending.time_at(self.index),
+            left: self.pending.left_channel_points[self.consumed],
+            right: self.pending.right_channel_points[self.consumed],
+        };
+        self.consumed += 1;
+        self.index += 1;
+        Some(point)
+    }
+}
+
+impl<R: Read> Iterator for PresentationPoints<R> {
+    type Item = Result<PresentationPoint, BramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.pop() {
+                return Some(Ok(point));
+            }
+            let accumulator = self.accumulator.as_mut()?;
+            match self.stream.next_block_as::<f32>() {
+                Ok(Some(block)) => accumulator.push(&block),
+                Ok(None) => {
+                    accumulator.flush_buckets();
+                    accumulator.drain_into(&mut self.pending);
+                    self.accumulator = None;
+                    continue;
+                }
+                Err(error) => {
+                    self.accumulator = None;
+                    return Some(Err(error));
+                }
+            }
+            accumulator.drain_into(&mut self.pending);
+            self.pending.left_envelope = None;
+            self.pending.right_envelope = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod point_stream_tests {
+    use std::io::Cursor;
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::{DownsampleStrategy, RatedAudioData, StereoAudioPresentation};
+    use crate::error::BramError;
+    use crate::point_stream::PresentationPoints;
+    use crate::wav_writer::encode_wav;
+
+    fn audio(frames: usize) -> AudioData {
+        let samples = (0..frames * 2).map(|index| ((index * 91) % 4000) as i16 - 2000).collect();
+        AudioData { samples, channels: 2, sample_rate: 100, bits_per_sample: 16, channel_mask: None }
+    }
+
+    #[test]
+    fn points_match_whole_file_presentation() {
+        let audio = audio(20_000);
+        for mode in [DownsampleStrategy::Decimate, DownsampleStrategy::MinMax] {
+            let expected = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio, 3).with_mode(mode)).unwrap();
+            let points: Vec<_> = PresentationPoints::open(Cursor::new(encode_wav(&audio)), 3, mode)
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(points.len(), expected.left_channel_points.len());
+            assert!(points.iter().enumerate().all(|(index, point)| point.index == index));
+            assert_eq!(points.iter().map(|point| point.left).collect::<Vec<_>>(), expected.left_channel_points);
+            assert_eq!(points.iter().map(|point| point.right).collect::<Vec<_>>(), expected.right_channel_points);
+            assert_eq!(points[3].time, expected.time_at(3));
+        }
+    }
+
+    #[test]
+    fn first_points_arrive_before_the_file_is_fully_read() {
+        let bytes = encode_wav(&audio(200_000));
+        let mut cursor = Cursor::new(bytes.as_slice());
+        let mut points = PresentationPoints::open(&mut cursor, 10, DownsampleStrategy::Peak).unwrap();
+        assert_eq!(points.next().unwrap().unwrap().index, 0);
+        drop(points);
+        assert!(cursor.position() < bytes.len() as u64 / 2);
+    }
+
+    #[test]
+    fn truncated_data_is_reported_once() {
+        let mut bytes = encode_wav(&audio(10));
+        bytes.truncate(bytes.len() - 3);
+        let results: Vec<_> = PresentationPoints::open(Cursor::new(bytes), 100, DownsampleStrategy::Decimate).unwrap().collect();
+        assert!(matches!(results.last(), Some(Err(BramError::Truncated { .. }))));
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+    }
+}
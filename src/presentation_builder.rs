@@ -0,0 +1,517 @@
+use std::fs;
+use std::io::Read;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::audio_data::{AudioData, SampleStream};
+use crate::audio_presentation::{ChannelPresentation, ChannelSpec, Downmix, DownsampleStrategy, Interpolation, MonoAudioPresentation, MultiChannelAudioPresentation, NormalizationMode, PresentationAccumulator, RatedAudioData, StereoAudioPresentation};
+use crate::cancel::CancellationToken;
+use crate::error::BramError;
+#[cfg(feature = "experimental")]
+use crate::loudness::LoudnessAlignment;
+use crate::progress::{ProgressCallback, ProgressReader};
+use crate::remix::{self, SurroundDownmix};
+use crate::spectrum;
+use crate::wav_binary::WavBinary;
+use crate::weighting::Weighting;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChannelSelection {
+    #[default]
+    Stereo,
+    Mono(Downmix),
+    All,
+}
+
+#[derive(Debug)]
+pub enum Presentation {
+    Stereo(StereoAudioPresentation),
+    Mono(MonoAudioPresentation),
+    MultiChannel(MultiChannelAudioPresentation),
+}
+
+impl Presentation {
+    pub fn builder(path: impl AsRef<Path>) -> PresentationBuilder {
+        PresentationBuilder {
+            path: path.as_ref().to_path_buf(),
+            rate: 100,
+            strategy: DownsampleStrategy::Decimate,
+            weighting: Weighting::None,
+            normalization: NormalizationMode::ZeroToOne,
+            interpolation: Interpolation::Linear,
+            channels: ChannelSelection::Stereo,
+            progress: None,
+            cancellation: None,
+            require_wav_extension: false,
+            #[cfg(feature = "experimental")]
+            loudness: LoudnessAlignment::None,
+            spectral_centroid: false,
+            surround_downmix: SurroundDownmix::Reject,
+        }
+    }
+
+    pub fn into_stereo(self) -> Option<StereoAudioPresentation> {
+        match self {
+            Presentation::Stereo(presentation) => Some(presentation),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PresentationBuilder {
+    path: PathBuf,
+    rate: u32,
+    strategy: DownsampleStrategy,
+    weighting: Weighting,
+    normalization: NormalizationMode,
+    interpolation: Interpolation,
+    channels: ChannelSelection,
+    progress: Option<ProgressCallback>,
+    cancellation: Option<CancellationToken>,
+    require_wav_extension: bool,
+    #[cfg(feature = "experimental")]
+    loudness: LoudnessAlignment,
+    spectral_centroid: bool,
+    surround_downmix: SurroundDownmix,
+}
+
+impl PresentationBuilder {
+    pub fn rate(mut self, rate: u32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    pub fn strategy(mut self, strategy: DownsampleStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn weighting(mut self, weighting: Weighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    pub fn normalization(mut self, normalization: NormalizationMode) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    pub fn range(mut self, range: Range<f32>) -> Self {
+        self.normalization = NormalizationMode::Linear {
+            center: (range.start + range.end) / 2.0,
+            scale: (range.end - range.start) / 2.0,
+        };
+        self
+    }
+
+    pub fn channels(mut self, channels: ChannelSelection) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    pub fn on_progress(mut self, callback: impl Fn(u64, u64) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn cancellation(mut self, token: impl Into<CancellationToken>) -> Self {
+        self.cancellation = Some(token.into());
+        self
+    }
+
+    pub fn require_wav_extension(mut self, require: bool) -> Self {
+        self.require_wav_extension = require;
+        self
+    }
+
+    #[cfg(feature = "experimental")]
+    pub fn loudness(mut self, alignment: LoudnessAlignment) -> Self {
+        self.loudness = alignment;
+        self
+    }
+
+    pub fn spectral_centroid(mut self, enabled: bool) -> Self {
+        self.spectral_centroid = enabled;
+        self
+    }
+
+    pub fn surround_downmix(mut self, downmix: SurroundDownmix) -> Self {
+        self.surround_downmix = downmix;
+        self
+    }
+
+    pub fn build(self) -> Result<Presentation, BramError> {
+        match self.channels {
+            ChannelSelection::Stereo => self.build_stereo().map(Presentation::Stereo),
+            ChannelSelection::Mono(downmix) => {
+                MonoAudioPresentation::new(&self.rated_audio_data()?, downmix).map(Presentation::Mono)
+            }
+            ChannelSelection::All => {
+                MultiChannelAudioPresentation::try_from(&self.rated_audio_data()?).map(Presentation::MultiChannel)
+            }
+        }
+    }
+
+    pub fn build_channels(self, specs: &[ChannelSpec]) -> Result<Vec<ChannelPresentation>, BramError> {
+        let rated_audio_data = PresentationBuilder { channels: ChannelSelection::All, ..self }.rated_audio_data()?;
+        specs.iter().map(|spec| ChannelPresentation::build(&rated_audio_data, *spec)).collect()
+    }
+
+    pub fn build_stereo(self) -> Result<StereoAudioPresentation, BramError> {
+        self.validate()?;
+        if self.spectral_centroid {
+            let audio_data = self.loading_whole_file(|| crate::load_audio(&self.path))?;
+            let mut presentation = StereoAudioPresentation::try_from(&self.rate_audio_data(&audio_data)?)?;
+            presentation.spectral_centroid = Some(spectrum::point_centroids(&audio_data, presentation.left_channel_points.len()));
+            return Ok(presentation);
+        }
+        #[cfg(feature = "experimental")]
+        if self.loudness != LoudnessAlignment::None {
+            return StereoAudioPresentation::try_from(&self.rated_audio_data()?);
+        }
+        if crate::other_container_parser(&self.path).is_some() {
+            return StereoAudioPresentation::try_from(&self.rated_audio_data()?);
+        }
+        let reader = WavBinary::open_reader(&self.path)?;
+        match &self.progress {
+            Some(progress) => {
+                let total = fs::metadata(&self.path)?.len();
+                self.stream_stereo(ProgressReader::new(reader, total, progress.clone()))
+            }
+            None => self.stream_stereo(reader),
+        }
+    }
+
+    fn stream_stereo(&self, reader: impl Read) -> Result<StereoAudioPresentation, BramError> {
+        let mut stream = SampleStream::open(reader)?;
+        if self.rate > stream.sample_rate {
+            let audio_data = stream.read_all()?;
+            self.check_cancelled()?;
+            return StereoAudioPresentation::try_from(&self.rate_audio_data(&audio_data)?);
+        }
+        let matrix = self.surround_downmix.matrix(stream.channels, stream.channel_mask)?;
+        let mut accumulator = PresentationAccumulator::new(
+            if matrix.is_some() { 2 } else { stream.channels },
+            stream.sample_rate,
+            self.rate,
+            self.strategy,
+            self.weighting,
+            self.normalization,
+        )?;
+        while let Some(block) = stream.next_block_as::<f32>()? {
+            self.check_cancelled()?;
+            match &matrix {
+                Some(matrix) => accumulator.push_block(&remix::apply_matrix(&block, matrix)),
+                None => accumulator.push_block(&block),
+            }
+        }
+        Ok(accumulator.finish())
+    }
+
+    fn rated_audio_data(&self) -> Result<RatedAudioData, BramError> {
+        self.validate()?;
+        let audio_data = self.loading_whole_file(|| crate::load_audio(&self.path))?;
+        self.rate_audio_data(&audio_data)
+    }
+
+    fn rate_audio_data(&self, audio_data: &AudioData) -> Result<RatedAudioData, BramError> {
+        let downmixed;
+        let audio_data = if self.channels == ChannelSelection::Stereo && audio_data.channels > 2 && self.surround_downmix != SurroundDownmix::Reject {
+            downmixed = audio_data.downmix_to_stereo(self.surround_downmix)?;
+            &downmixed
+        } else {
+            audio_data
+        };
+        #[cfg(feature = "experimental")]
+        let rated = match self.loudness.gain(audio_data)? {
+            1.0 => RatedAudioData::new(audio_data, self.rate),
+            gain => RatedAudioData::from_samples(&audio_data.convert::<f32>().with_gain(gain), self.rate),
+        };
+        #[cfg(not(feature = "experimental"))]
+        let rated = RatedAudioData::new(audio_data, self.rate);
+        Ok(rated
+            .with_mode(self.strategy)
+            .with_weighting(self.weighting)
+            .with_normalization(self.normalization)
+            .with_interpolation(self.interpolation))
+    }
+
+    fn loading_whole_file<T>(&self, load: impl FnOnce() -> Result<T, BramError>) -> Result<T, BramError> {
+        let Some(progress) = &self.progress else {
+            let result = load()?;
+            self.check_cancelled()?;
+            return Ok(result);
+        };
+        let total = fs::metadata(&self.path)?.len();
+        progress(0, total);
+        let result = load()?;
+        self.check_cancelled()?;
+        progress(total, total);
+        Ok(result)
+    }
+
+    fn check_cancelled(&self) -> Result<(), BramError> {
+        self.cancellation.as_ref().map_or(Ok(()), CancellationToken::check)
+    }
+
+    fn validate(&self) -> Result<(), BramError> {
+        if self.rate == 0 {
+            return Err(BramError::InvalidParameter("presentation rate must be non-zero"));
+        }
+        if self.require_wav_extension && crate::other_container_parser(&self.path).is_none() {
+            WavBinary::check_extension(&self.path)?;
+        }
+        self.check_cancelled()
+    }
+}
+
+#[cfg(test)]
+mod presentation_builder_tests {
+    use std::fs;
+    use std::sync::{Arc, Mutex};
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::{ChannelSpec, Downmix, DownsampleStrategy, Interpolation, NormalizationMode};
+    use crate::cancel::CancellationToken;
+    use crate::error::BramError;
+    #[cfg(feature = "experimental")]
+    use crate::loudness::LoudnessAlignment;
+    use crate::presentation_builder::{ChannelSelection, Presentation};
+    use crate::remix::SurroundDownmix;
+    use crate::wav_writer::write_wav;
+
+    fn write_stereo_wav(name: &str) -> String {
+        let samples: [i16; 8] = [0, 0, 32767, -32768, 16384, -16384, 0, 0];
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + samples.len() as u32 * 2).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&4u32.to_le_bytes());
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(samples.len() as u32 * 2).to_le_bytes());
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, bytes).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn range_presentation_interpolates_like_the_builder() {
+        let path = write_stereo_wav("bram_builder_range.wav");
+        let ranged = crate::presentation_for_range(&path, 0.0..1.0, 8).unwrap();
+        let loaded = crate::load_presentation(&path, 8).unwrap();
+        assert_eq!(ranged.left_channel_points.len(), 8);
+        assert_eq!(ranged.left_channel_points, loaded.left_channel_points);
+        assert_eq!(ranged.right_channel_points, loaded.right_channel_points);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_matches_plain_load_presentation() {
+        let path = write_stereo_wav("bram_builder_plain.wav");
+        let built = Presentation::builder(&path).rate(2).build().unwrap().into_stereo().unwrap();
+        let loaded = crate::load_presentation(&path, 2).unwrap();
+        assert_eq!(built.left_channel_points, loaded.left_channel_points);
+        assert_eq!(built.right_channel_points, loaded.right_channel_points);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_interpolates_rates_above_source_rate() {
+        let path = write_stereo_wav("bram_builder_interpolated.wav");
+        let stereo = Presentation::builder(&path)
+            .rate(8)
+            .interpolation(Interpolation::Linear)
+            .normalization(NormalizationMode::MinusOneToOne)
+            .build_stereo()
+            .unwrap();
+        assert_eq!(stereo.left_channel_points.len(), 8);
+        assert_eq!(stereo.left_channel_points[1], 16383.5 / 32768.0);
+        let loaded = crate::load_presentation_interpolated(&path, 8, Interpolation::Cubic).unwrap();
+        assert_eq!(loaded.right_channel_points.len(), 8);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_applies_strategy_range_and_channels() {
+        let path = write_stereo_wav("bram_builder_options.wav");
+        let stereo = Presentation::builder(&path)
+            .rate(2)
+            .strategy(DownsampleStrategy::Peak)
+            .range(-1.0..1.0)
+            .build_stereo()
+            .unwrap();
+        assert_eq!(stereo.left_channel_points, vec![32767.0 / 32768.0, 0.5]);
+        assert!(stereo.left_envelope.is_some());
+
+        let mono = Presentation::builder(&path)
+            .rate(4)
+            .normalization(NormalizationMode::MinusOneToOne)
+            .channels(ChannelSelection::Mono(Downmix::Left))
+            .build()
+            .unwrap();
+        match mono {
+            Presentation::Mono(mono) => assert_eq!(mono.points, vec![0.0, 32767.0 / 32768.0, 0.5, 0.0]),
+            other => panic!("expected mono presentation, got {:?}", other),
+        }
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_builds_each_channel_with_its_own_spec() {
+        let path = write_stereo_wav("bram_builder_channels.wav");
+        let channels = Presentation::builder(&path)
+            .normalization(NormalizationMode::MinusOneToOne)
+            .build_channels(&[ChannelSpec::new(0, 2, DownsampleStrategy::Peak), ChannelSpec::new(1, 4, DownsampleStrategy::Decimate)])
+            .unwrap();
+        assert_eq!(channels[0].points, vec![32767.0 / 32768.0, 0.5]);
+        assert_eq!(channels[1].points, vec![0.0, -1.0, -0.5, 0.0]);
+        assert_eq!(channels[1].envelope, None);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_reports_progress_up_to_file_size() {
+        let path = write_stereo_wav("bram_builder_progress.wav");
+        let total = fs::metadata(&path).unwrap().len();
+        for channels in [ChannelSelection::Stereo, ChannelSelection::All] {
+            let calls = Arc::new(Mutex::new(Vec::new()));
+            let recorded = calls.clone();
+            Presentation::builder(&path)
+                .rate(2)
+                .channels(channels)
+                .on_progress(move |done, total| recorded.lock().unwrap().push((done, total)))
+                .build()
+                .unwrap();
+            let calls = calls.lock().unwrap();
+            assert_eq!(calls.first(), Some(&(0, total)));
+            assert_eq!(calls.last(), Some(&(total, total)));
+            assert!(calls.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        }
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_stops_with_cancelled_error() {
+        let path = write_stereo_wav("bram_builder_cancel.wav");
+        let token = CancellationToken::new();
+        let trigger = token.clone();
+        let result = Presentation::builder(&path)
+            .rate(2)
+            .cancellation(token.clone())
+            .on_progress(move |done, _| {
+                if done > 0 {
+                    trigger.cancel();
+                }
+            })
+            .build();
+        assert!(matches!(result, Err(BramError::Cancelled)));
+
+        let result = Presentation::builder(&path).cancellation(token).channels(ChannelSelection::All).build();
+        assert!(matches!(result, Err(BramError::Cancelled)));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_sniffs_content_unless_extension_is_required() {
+        let source = write_stereo_wav("bram_builder_sniff.wav");
+        let path = std::env::temp_dir().join("bram_builder_download");
+        fs::rename(&source, &path).unwrap();
+        let sniffed = Presentation::builder(&path).rate(2).build_stereo().unwrap();
+        assert_eq!(sniffed.left_channel_points, crate::load_presentation(&path, 2).unwrap().left_channel_points);
+
+        let result = Presentation::builder(&path).rate(2).require_wav_extension(true).build_stereo();
+        assert!(matches!(result, Err(BramError::NotAWavFile)));
+        let _ = fs::remove_file(path);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn loudness_alignment_evens_out_quiet_and_loud_files() {
+        let peak = |amplitude: f64, alignment| {
+            let path = std::env::temp_dir().join(format!("bram_builder_loudness_{amplitude}.wav"));
+            let samples = (0..16000).map(|i| (amplitude * 32767.0 * (i as f64 * 0.3).sin()) as i16).collect();
+            let audio = AudioData { samples, channels: 1, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+            write_wav(&audio, &path).unwrap();
+            let stereo = Presentation::builder(&path)
+                .rate(4)
+                .strategy(DownsampleStrategy::Peak)
+                .normalization(NormalizationMode::MinusOneToOne)
+                .loudness(alignment)
+                .build_stereo()
+                .unwrap();
+            let _ = fs::remove_file(path);
+            stereo.left_channel_points.iter().copied().fold(0.0f32, f32::max)
+        };
+        assert!((peak(0.5, LoudnessAlignment::None) / peak(0.05, LoudnessAlignment::None) - 10.0).abs() < 0.1);
+        let aligned = LoudnessAlignment::TargetLufs(-20.0);
+        assert!((peak(0.5, aligned) / peak(0.05, aligned) - 1.0).abs() < 0.01);
+        assert!((peak(0.5, LoudnessAlignment::GainDb(-6.0)) / peak(0.5, LoudnessAlignment::None) - 0.501).abs() < 0.01);
+    }
+
+    #[test]
+    fn spectral_centroid_tracks_brightness_per_point() {
+        let path = std::env::temp_dir().join("bram_builder_centroid.wav");
+        let samples = (0..16000)
+            .map(|i| {
+                let frequency = if i < 8000 { 200.0 } else { 3000.0 };
+                (12000.0 * (2.0 * std::f64::consts::PI * frequency * i as f64 / 8000.0).sin()) as i16
+            })
+            .collect();
+        let audio = AudioData { samples, channels: 1, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+        write_wav(&audio, &path).unwrap();
+        let plain = Presentation::builder(&path).rate(4).build_stereo().unwrap();
+        assert_eq!(plain.spectral_centroid, None);
+
+        let colored = Presentation::builder(&path).rate(4).spectral_centroid(true).build_stereo().unwrap();
+        assert_eq!(colored.left_channel_points, plain.left_channel_points);
+        let centroids = colored.spectral_centroid.as_ref().unwrap();
+        assert_eq!(centroids.len(), 8);
+        assert!(centroids[..4].iter().all(|centroid| (centroid - 200.0).abs() < 100.0), "{centroids:?}");
+        assert!(centroids[4..].iter().all(|centroid| (centroid - 3000.0).abs() < 300.0), "{centroids:?}");
+        let brightness = colored.brightness().unwrap();
+        assert!(brightness[0] < 0.1 && brightness[7] > 0.6);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn surround_files_downmix_to_stereo_when_requested() {
+        let path = std::env::temp_dir().join("bram_builder_surround.wav");
+        let samples = (0..6000).map(|index| if index % 6 == 2 { 16384i16 } else { 0 }).collect();
+        let audio = AudioData { samples, channels: 6, sample_rate: 1000, bits_per_sample: 16, channel_mask: None };
+        write_wav(&audio, &path).unwrap();
+        assert!(matches!(Presentation::builder(&path).rate(10).build_stereo(), Err(BramError::UnsupportedChannels { channels: 6 })));
+
+        let center = 0.5 * std::f32::consts::FRAC_1_SQRT_2 / (1.0 + 2.0 * std::f32::consts::FRAC_1_SQRT_2);
+        for rate in [10, 2000] {
+            let stereo = Presentation::builder(&path)
+                .rate(rate)
+                .normalization(NormalizationMode::MinusOneToOne)
+                .surround_downmix(SurroundDownmix::Standard)
+                .build_stereo()
+                .unwrap();
+            assert!(stereo.left_channel_points.iter().all(|point| (point - center).abs() < 1e-3));
+            assert_eq!(stereo.left_channel_points, stereo.right_channel_points);
+        }
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn builder_rejects_zero_rate() {
+        let result = Presentation::builder("missing.wav").rate(0).build();
+        assert!(matches!(result, Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use crate::audio_presentation::{DownsampleStrategy, NormalizationMode, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::hash::Fnv1a;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileIdentityKey {
+    pub identity: u64,
+    pub rate: u32,
+    pub settings: u64,
+}
+
+impl FileIdentityKey {
+    pub fn for_file(
+        path: impl AsRef<Path>,
+        rate: u32,
+        strategy: DownsampleStrategy,
+        normalization: NormalizationMode,
+    ) -> Result<Self, BramError> {
+        let path = path.as_ref();
+        let metadata = fs::metadata(path)?;
+        let modified = metadata.modified()?.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mut identity = Fnv1a::new();
+        identity.write(fs::canonicalize(path)?.to_string_lossy().as_bytes());
+        identity.write(&metadata.len().to_le_bytes());
+        identity.write(&modified.as_secs().to_le_bytes());
+        identity.write(&modified.subsec_nanos().to_le_bytes());
+        let mut settings = Fnv1a::new();
+        settings.write(format!("{:?}|{:?}", strategy, normalization).as_bytes());
+        Ok(FileIdentityKey { identity: identity.finish(), rate, settings: settings.finish() })
+    }
+}
+
+pub struct PresentationCache {
+    root: PathBuf,
+}
+
+impl PresentationCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &FileIdentityKey) -> PathBuf {
+        self.root
+            .join(format!("{:016x}", key.identity))
+            .join(format!("{}_{:016x}.brmw", key.rate, key.settings))
+    }
+
+    pub fn get(&self, key: &FileIdentityKey) -> Result<Option<StereoAudioPresentation>, BramError> {
+        match fs::read(self.path_for(key)) {
+            Ok(bytes) => StereoAudioPresentation::from_compact(&bytes).map(Some),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub fn put(&self, key: &FileIdentityKey, presentation: &StereoAudioPresentation) -> Result<(), BramError> {
+        let path = self.path_for(key);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp_path = path.with_extension("brmw.tmp");
+        fs::write(&temp_path, presentation.to_compact())?;
+        fs::rename(temp_path, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod presentation_cache_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::{DownsampleStrategy, NormalizationMode};
+    use crate::presentation_cache::{FileIdentityKey, PresentationCache};
+    use crate::wav_writer::write_wav;
+    use crate::{load_presentation, load_presentation_cached_by_identity};
+
+    #[test]
+    fn file_key_changes_when_file_or_settings_change() {
+        let path = std::env::temp_dir().join("bram_presentation_identity.wav");
+        fs::write(&path, b"RIFF").unwrap();
+        let key = |rate, strategy| FileIdentityKey::for_file(&path, rate, strategy, NormalizationMode::ZeroToOne).unwrap();
+        let first = key(100, DownsampleStrategy::Decimate);
+        assert_eq!(key(100, DownsampleStrategy::Decimate), first);
+        assert_ne!(key(50, DownsampleStrategy::Decimate), first);
+        assert_ne!(key(100, DownsampleStrategy::Peak).settings, first.settings);
+        fs::write(&path, b"RIFF----").unwrap();
+        assert_ne!(key(100, DownsampleStrategy::Decimate).identity, first.identity);
+        let missing = FileIdentityKey::for_file("missing_identity.wav", 100, DownsampleStrategy::Decimate, NormalizationMode::ZeroToOne);
+        assert!(missing.is_err());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn cached_load_equals_uncached_load() {
+        let root = std::env::temp_dir().join("bram_presentation_cache_test");
+        let _ = fs::remove_dir_all(&root);
+        let path = std::env::temp_dir().join("bram_presentation_cache_source.wav");
+        let samples = (0..800i16).map(|index| index * 40 - 16000).collect();
+        let audio = AudioData { samples, channels: 2, sample_rate: 400, bits_per_sample: 16, channel_mask: None };
+        write_wav(&audio, &path).unwrap();
+        let cache = PresentationCache::new(&root);
+
+        let uncached = load_presentation(&path, 20).unwrap();
+        let miss = load_presentation_cached_by_identity(&path, 20, &cache).unwrap();
+        let hit = load_presentation_cached_by_identity(&path, 20, &cache).unwrap();
+        for presentation in [&miss, &hit] {
+            assert_eq!(presentation.left_channel_points, uncached.left_channel_points);
+            assert_eq!(presentation.right_channel_points, uncached.right_channel_points);
+            assert_eq!(presentation.left_envelope, uncached.left_envelope);
+            assert_eq!(presentation.source_sample_rate, uncached.source_sample_rate);
+            assert_eq!(presentation.source_frames, uncached.source_frames);
+            assert_eq!(presentation.samples_per_point, uncached.samples_per_point);
+            assert_eq!(presentation.duration(), uncached.duration());
+        }
+        let key = FileIdentityKey::for_file(&path, 20, DownsampleStrategy::Decimate, NormalizationMode::ZeroToOne).unwrap();
+        assert!(cache.get(&key).unwrap().is_some());
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+use crate::analysis;
+use crate::audio_data::AudioData;
+
+const PREVIEW_SAMPLE_RATE: u32 = 44100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PreviewOptions {
+    pub length: Duration,
+    pub start: Option<Duration>,
+    pub fade: Duration,
+}
+
+impl Default for PreviewOptions {
+    fn default() -> Self {
+        Self {
+            length: Duration::from_secs(30),
+            start: None,
+            fade: Duration::from_secs(2),
+        }
+    }
+}
+
+pub(crate) fn preview_clip(audio: &AudioData, options: &PreviewOptions) -> AudioData {
+    let channels = audio.channels.max(1) as usize;
+    let total_frames = audio.frames();
+    let frames_for = |duration: Duration| (duration.as_secs_f64() * audio.sample_rate as f64) as usize;
+    let range = match options.start {
+        Some(start) => {
+            let first = frames_for(start).min(total_frames);
+            first..(first + frames_for(options.length)).min(total_frames)
+        }
+        None => analysis::interesting_region(audio, options.length),
+    };
+    let sliced = &audio.samples[range.start * channels..range.end * channels];
+    let mut samples = resample(sliced, channels, audio.sample_rate, PREVIEW_SAMPLE_RATE);
+    let fade_frames = (options.fade.as_secs_f64() * PREVIEW_SAMPLE_RATE as f64) as usize;
+    apply_fades(&mut samples, channels, fade_frames);
+    AudioData {
+        samples,
+        channels: audio.channels,
+        sample_rate: PREVIEW_SAMPLE_RATE,
+        bits_per_sample: 16,
+        channel_mask: audio.channel_mask,
+    }
+}
+
+fn resample(samples: &[i16], channels: usize, from_rate: u32, to_rate: u32) -> Vec<i16> {
+    let frames = samples.len() / channels;
+    if from_rate == to_rate || from_rate == 0 || frames == 0 {
+        return samples.to_vec();
+    }
+    let output_frames = (frames as u64 * to_rate as u64 / from_rate as u64) as usize;
+    let step = from_rate as f64 / to_rate as f64;
+    let mut output = Vec::with_capacity(output_frames * channels);
+    for frame in 0..output_frames {
+        let position = frame as f64 * step;
+        let index = position as usize;
+        let next = (index + 1).min(frames - 1);
+        let fraction = position - index as f64;
+        for channel in 0..channels {
+            let a = samples[index * channels + channel] as f64;
+            let b = samples[next * channels + channel] as f64;
+            output.push((a + (b - a) * fraction).round() as i16);
+        }
+    }
+    output
+}
+
+fn apply_fades(samples: &mut [i16], channels: usize, fade_frames: usize) {
+    let frames = samples.len() / channels;
+    let fade_frames = fade_frames.min(frames / 2);
+    if fade_frames == 0 {
+        return;
+    }
+    for frame in 0..fade_frames {
+        let gain = frame as f32 / fade_frames as f32;
+        for channel in 0..channels {
+            let head = frame * channels + channel;
+            let tail = (frames - 1 - frame) * channels + channel;
+            samples[head] = (samples[head] as f32 * gain).round() as i16;
+            samples[tail] = (samples[tail] as f32 * gain).round() as i16;
+        }
+    }
+}
+
+#[cfg(test)]
+mod preview_tests {
+    use std::time::Duration;
+    use crate::audio_data::AudioData;
+    use crate::preview::{apply_fades, preview_clip, resample, PreviewOptions};
+
+    fn constant(value: i16, frames: usize, sample_rate: u32) -> AudioData {
+        AudioData {
+            samples: vec![value; frames * 2],
+            channels: 2,
+            sample_rate,
+            bits_per_sample: 24,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn resample_interpolates_linearly() {
+        assert_eq!(resample(&[0, 100], 1, 1, 2), vec![0, 50, 100, 100]);
+        assert_eq!(resample(&[0, 10, 20, 30], 1, 2, 1), vec![0, 20]);
+    }
+
+    #[test]
+    fn fades_ramp_both_ends() {
+        let mut samples = vec![1000; 10];
+        apply_fades(&mut samples, 1, 2);
+        assert_eq!(samples, vec![0, 500, 1000, 1000, 1000, 1000, 1000, 1000, 500, 0]);
+    }
+
+    #[test]
+    fn preview_clip_is_44100_hz_16_bit_and_bounded() {
+        let audio = constant(1000, 48000 * 2, 48000);
+        let options = PreviewOptions {
+            length: Duration::from_secs(1),
+            start: Some(Duration::from_millis(500)),
+            fade: Duration::from_millis(100),
+        };
+        let clip = preview_clip(&audio, &options);
+        assert_eq!(clip.sample_rate, 44100);
+        assert_eq!(clip.bits_per_sample, 16);
+        assert_eq!(clip.channels, 2);
+        assert_eq!(clip.frames(), 44100);
+        assert_eq!(clip.samples[0], 0);
+        assert_eq!(clip.samples[44100], 1000);
+    }
+
+    #[test]
+    fn preview_clip_defaults_to_interesting_region() {
+        let mut audio = constant(0, 44100 * 4, 44100);
+        audio.samples[44100 * 2 * 3..].iter_mut().enumerate().for_each(|(i, s)| *s = if i % 4 < 2 { 8000 } else { -8000 });
+        let options = PreviewOptions { length: Duration::from_secs(1), fade: Duration::ZERO, ..PreviewOptions::default() };
+        let clip = preview_clip(&audio, &options);
+        assert_eq!(clip.frames(), 44100);
+        assert!(clip.samples.iter().all(|s| s.abs() == 8000));
+    }
+}
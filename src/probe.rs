@@ -0,0 +1,159 @@
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::time::Duration;
+use crate::audio_data::{AudioData, WAVE_FORMAT_PCM};
+use crate::error::BramError;
+use crate::wav_binary::{self, HeaderDiagnosis};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WavInfo {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub bits_per_sample: u16,
+    pub format: u16,
+    pub duration: Duration,
+    pub data_size: usize,
+    pub frames: usize,
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "scan_chunks", skip_all, err))]
+pub(crate) fn probe_reader<R: Read + Seek>(mut reader: R) -> Result<WavInfo, BramError> {
+    let len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+    let mut riff = [0u8; 12];
+    reader.read_exact(&mut riff)?;
+    let diagnosis = wav_binary::diagnose(&riff);
+    if diagnosis != HeaderDiagnosis::Valid {
+        return Err(BramError::Header(diagnosis));
+    }
+    let mut header = riff.to_vec();
+    let mut pos = 12u64;
+    let data_size = loop {
+        let mut chunk_header = [0u8; 8];
+        match reader.read_exact(&mut chunk_header) {
+            Ok(()) => {}
+            Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                return Err(BramError::MissingChunk { id: *b"data" });
+            }
+            Err(error) => return Err(error.into()),
+        }
+        pos += 8;
+        let id = &chunk_header[0..4];
+        let chunk_size = u32::from_le_bytes([chunk_header[4], chunk_header[5], chunk_header[6], chunk_header[7]]) as u64;
+        if id == b"data" {
+            let available = len.saturating_sub(pos);
+            break match chunk_size {
+                0xFFFF_FFFF => available,
+                size => size.min(available),
+            };
+        }
+        let padded_size = chunk_size + chunk_size % 2;
+        if id == b"fmt " || id == b"fact" {
+            header.extend_from_slice(&chunk_header);
+            reader.by_ref().take(padded_size).read_to_end(&mut header)?;
+        } else {
+            reader.seek(SeekFrom::Current(padded_size as i64))?;
+        }
+        pos += padded_size;
+    };
+    let mut info = AudioData::read_header_info(&header)?;
+    info.data_size = data_size as usize;
+    Ok(WavInfo {
+        channels: info.channels,
+        sample_rate: info.sample_rate,
+        bits_per_sample: info.bits_per_sample,
+        format: info.format_tag,
+        duration: info.duration(),
+        data_size: info.data_size,
+        frames: info.frames(),
+    })
+}
+
+pub(crate) fn probe_decoded(audio: &AudioData) -> WavInfo {
+    WavInfo {
+        channels: audio.channels,
+        sample_rate: audio.sample_rate,
+        bits_per_sample: audio.bits_per_sample,
+        format: WAVE_FORMAT_PCM,
+        duration: audio.duration(),
+        data_size: audio.samples.len() * (audio.bits_per_sample as usize).div_ceil(8),
+        frames: audio.frames(),
+    }
+}
+
+#[cfg(test)]
+mod probe_tests {
+    use std::io::Cursor;
+    use std::time::Duration;
+    use crate::error::BramError;
+    use crate::probe::probe_reader;
+
+    fn wav_bytes(data_size: u32, payload: usize) -> Vec<u8> {
+        let mut bytes = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+        bytes.extend_from_slice(b"LIST\x03\x00\x00\x00abc\x00");
+        bytes.extend_from_slice(b"fmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&8000u32.to_le_bytes());
+        bytes.extend_from_slice(&32000u32.to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_size.to_le_bytes());
+        bytes.extend(std::iter::repeat_n(0u8, payload));
+        bytes
+    }
+
+    #[test]
+    fn probe_reads_header_without_samples() {
+        let info = probe_reader(Cursor::new(wav_bytes(16000, 16000))).unwrap();
+        assert_eq!((info.channels, info.sample_rate, info.bits_per_sample, info.format), (2, 8000, 16, 1));
+        assert_eq!(info.data_size, 16000);
+        assert_eq!(info.duration, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn probe_clamps_data_size_to_file_length() {
+        let info = probe_reader(Cursor::new(wav_bytes(0xFFFF_FFFF, 3200))).unwrap();
+        assert_eq!(info.data_size, 3200);
+        let info = probe_reader(Cursor::new(wav_bytes(64000, 3200))).unwrap();
+        assert_eq!(info.duration, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn probe_reports_empty_data_chunk() {
+        let mut bytes = wav_bytes(0, 0);
+        bytes.extend_from_slice(b"LIST\x04\x00\x00\x00INFO");
+        let info = probe_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!((info.data_size, info.frames), (0, 0));
+    }
+
+    #[test]
+    fn probe_rejects_missing_data_chunk() {
+        let mut bytes = wav_bytes(0, 0);
+        bytes.truncate(bytes.len() - 8);
+        assert!(matches!(probe_reader(Cursor::new(bytes)), Err(BramError::MissingChunk { .. })));
+    }
+
+    #[test]
+    fn probe_prefers_fact_frame_count_for_adpcm() {
+        let mut bytes = wav_bytes(16000, 16000);
+        let data_pos = bytes.len() - 16008;
+        bytes.splice(data_pos..data_pos, b"fact\x04\x00\x00\x00\x40\x1f\x00\x00".iter().copied());
+        bytes[32..34].copy_from_slice(&0x11u16.to_le_bytes());
+        let info = probe_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!(info.frames, 8000);
+        assert_eq!(info.duration, Duration::from_secs(1));
+        assert_eq!(probe_reader(Cursor::new(wav_bytes(16000, 16000))).unwrap().frames, 4000);
+    }
+
+    #[test]
+    fn probe_ignores_zero_fact_for_pcm() {
+        let mut bytes = wav_bytes(16000, 16000);
+        let data_pos = bytes.len() - 16008;
+        bytes.splice(data_pos..data_pos, b"fact\x04\x00\x00\x00\x00\x00\x00\x00".iter().copied());
+        let info = probe_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!(info.frames, 4000);
+        assert_eq!(info.duration, Duration::from_millis(500));
+    }
+}
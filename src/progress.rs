@@ -0,0 +1,50 @@
+use std::io::{self, Read};
+use std::sync::Arc;
+
+pub(crate) type ProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+pub(crate) struct ProgressReader<R> {
+    inner: R,
+    done: u64,
+    total: u64,
+    callback: ProgressCallback,
+}
+
+impl<R: Read> ProgressReader<R> {
+    pub(crate) fn new(inner: R, total: u64, callback: ProgressCallback) -> Self {
+        callback(0, total);
+        Self { inner, done: 0, total, callback }
+    }
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        if read > 0 {
+            self.done += read as u64;
+            (self.callback)(self.done, self.total.max(self.done));
+        }
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod progress_tests {
+    use std::io::{Cursor, Read};
+    use std::sync::{Arc, Mutex};
+    use crate::progress::ProgressReader;
+
+    #[test]
+    fn progress_reader_reports_cumulative_bytes() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let mut reader = ProgressReader::new(
+            Cursor::new(vec![0u8; 10]),
+            10,
+            Arc::new(move |done, total| recorded.lock().unwrap().push((done, total))),
+        );
+        let mut buf = [0u8; 4];
+        while reader.read(&mut buf).unwrap() > 0 {}
+        assert_eq!(*calls.lock().unwrap(), vec![(0, 10), (4, 10), (8, 10), (10, 10)]);
+    }
+}
@@ -0,0 +1,93 @@
+use pyo3::exceptions::{PyIOError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use crate::error::BramError;
+
+impl From<BramError> for PyErr {
+    fn from(error: BramError) -> Self {
+        match error {
+            BramError::Io(error) => PyIOError::new_err(error.to_string()),
+            error => PyValueError::new_err(error.to_string()),
+        }
+    }
+}
+
+fn float_array<'py>(py: Python<'py>, values: &[f32]) -> PyResult<Bound<'py, PyAny>> {
+    let bytes: Vec<u8> = values.iter().flat_map(|value| value.to_ne_bytes()).collect();
+    py.import("array")?.getattr("array")?.call1(("f", PyBytes::new(py, &bytes)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, rate = 100))]
+fn load_presentation<'py>(py: Python<'py>, path: &str, rate: u32) -> PyResult<Bound<'py, PyDict>> {
+    let presentation = crate::load_presentation(path, rate)?;
+    let points_per_second = presentation.points_per_second();
+    let dict = PyDict::new(py);
+    dict.set_item("left", float_array(py, &presentation.left_channel_points)?)?;
+    dict.set_item("right", float_array(py, &presentation.right_channel_points)?)?;
+    dict.set_item("source_sample_rate", presentation.source_sample_rate)?;
+    dict.set_item("source_channels", presentation.source_channels)?;
+    dict.set_item("source_frames", presentation.source_frames)?;
+    dict.set_item("samples_per_point", presentation.samples_per_point)?;
+    dict.set_item("points_per_second", points_per_second)?;
+    Ok(dict)
+}
+
+#[pyfunction]
+fn probe<'py>(py: Python<'py>, path: &str) -> PyResult<Bound<'py, PyDict>> {
+    let info = crate::probe(path)?;
+    let dict = PyDict::new(py);
+    dict.set_item("channels", info.channels)?;
+    dict.set_item("sample_rate", info.sample_rate)?;
+    dict.set_item("bits_per_sample", info.bits_per_sample)?;
+    dict.set_item("format", info.format)?;
+    dict.set_item("duration", info.duration.as_secs_f64())?;
+    dict.set_item("data_size", info.data_size)?;
+    dict.set_item("frames", info.frames)?;
+    Ok(dict)
+}
+
+#[pymodule]
+fn bram_audio_parser(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(load_presentation, module)?)?;
+    module.add_function(wrap_pyfunction!(probe, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod python_tests {
+    use std::fs;
+    use pyo3::exceptions::PyIOError;
+    use pyo3::prelude::*;
+    use crate::audio_data::AudioData;
+    use crate::python::{load_presentation, probe};
+    use crate::wav_writer::encode_wav;
+
+    fn write_stereo_wav(name: &str) -> String {
+        let samples = vec![0, 0, 32767, -32768, 16384, -16384, 0, 0];
+        let audio = AudioData { samples, channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, encode_wav(&audio)).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn python_functions_return_plain_dicts() {
+        let path = write_stereo_wav("bram_python.wav");
+        Python::initialize();
+        Python::attach(|py| {
+            let presentation = load_presentation(py, &path, 2).unwrap();
+            let left = presentation.get_item("left").unwrap().unwrap();
+            assert_eq!(left.getattr("typecode").unwrap().extract::<String>().unwrap(), "f");
+            let buffer: Vec<u8> = left.call_method0("tobytes").unwrap().extract().unwrap();
+            let left: Vec<f32> = buffer.chunks_exact(4).map(|bytes| f32::from_ne_bytes(bytes.try_into().unwrap())).collect();
+            assert_eq!(left, crate::load_presentation(&path, 2).unwrap().left_channel_points);
+            let info = probe(py, &path).unwrap();
+            let channels: u16 = info.get_item("channels").unwrap().unwrap().extract().unwrap();
+            let duration: f64 = info.get_item("duration").unwrap().unwrap().extract().unwrap();
+            assert_eq!((channels, duration), (2, 1.0));
+            assert!(probe(py, "missing.wav").unwrap_err().is_instance_of::<PyIOError>(py));
+        });
+        let _ = fs::remove_file(path);
+    }
+}
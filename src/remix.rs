@@ -0,0 +1,193 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+use crate::audio_data::AudioData;
+use crate::channel_layout::{ChannelLayout, Speaker};
+use crate::error::BramError;
+use crate::sample::Sample;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanLaw {
+    #[default]
+    Linear,
+    ConstantPower,
+    Unity,
+}
+
+impl PanLaw {
+    fn gain(self, channels: usize) -> f64 {
+        match self {
+            PanLaw::Linear => 1.0 / channels as f64,
+            PanLaw::ConstantPower => 1.0 / (channels as f64).sqrt(),
+            PanLaw::Unity => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SurroundDownmix {
+    #[default]
+    Reject,
+    Standard,
+    PairAverage,
+}
+
+impl SurroundDownmix {
+    pub(crate) fn matrix(self, channels: u16, channel_mask: Option<u32>) -> Result<Option<Vec<[f64; 2]>>, BramError> {
+        if channels <= 2 {
+            return Ok(None);
+        }
+        let layout = ChannelLayout::resolve(channel_mask, channels);
+        let mut matrix: Vec<[f64; 2]> = (0..channels as usize)
+            .map(|channel| match self {
+                SurroundDownmix::Reject => Err(BramError::UnsupportedChannels { channels }),
+                SurroundDownmix::PairAverage if channel % 2 == 0 => Ok([1.0, 0.0]),
+                SurroundDownmix::PairAverage => Ok([0.0, 1.0]),
+                SurroundDownmix::Standard => Ok(standard_gains(layout.speaker(channel))),
+            })
+            .collect::<Result<_, _>>()?;
+        for side in 0..2 {
+            let total: f64 = matrix.iter().map(|gains| gains[side]).sum();
+            if total > 0.0 {
+                matrix.iter_mut().for_each(|gains| gains[side] /= total);
+            }
+        }
+        Ok(Some(matrix))
+    }
+}
+
+fn standard_gains(speaker: Option<Speaker>) -> [f64; 2] {
+    use Speaker::*;
+    match speaker {
+        Some(FrontLeft | FrontLeftOfCenter) => [1.0, 0.0],
+        Some(FrontRight | FrontRightOfCenter) => [0.0, 1.0],
+        Some(BackLeft | SideLeft | TopFrontLeft | TopBackLeft) => [FRAC_1_SQRT_2, 0.0],
+        Some(BackRight | SideRight | TopFrontRight | TopBackRight) => [0.0, FRAC_1_SQRT_2],
+        Some(LowFrequency) => [0.0, 0.0],
+        Some(FrontCenter | BackCenter | TopCenter | TopFrontCenter | TopBackCenter) | None => [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+    }
+}
+
+pub(crate) fn apply_matrix<S: Sample>(samples: &[S], matrix: &[[f64; 2]]) -> Vec<S> {
+    samples
+        .chunks_exact(matrix.len())
+        .flat_map(|frame| {
+            let mix = |side: usize| frame.iter().zip(matrix).map(|(sample, gains)| sample.to_f64() * gains[side]).sum::<f64>();
+            [S::from_f64(mix(0)), S::from_f64(mix(1))]
+        })
+        .collect()
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn downmix_to_stereo(&self, downmix: SurroundDownmix) -> Result<AudioData<S>, BramError> {
+        let Some(matrix) = downmix.matrix(self.channels, self.channel_mask)? else {
+            return self.to_stereo();
+        };
+        Ok(AudioData {
+            samples: apply_matrix(&self.samples, &matrix),
+            channels: 2,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: None,
+        })
+    }
+
+    pub fn to_mono(&self, pan_law: PanLaw) -> Result<AudioData<S>, BramError> {
+        let channels = self.channels as usize;
+        if channels == 0 {
+            return Err(BramError::UnsupportedChannels { channels: 0 });
+        }
+        let gain = pan_law.gain(channels);
+        let samples = self
+            .samples
+            .chunks_exact(channels)
+            .map(|frame| S::from_f64(frame.iter().map(|sample| sample.to_f64()).sum::<f64>() * gain))
+            .collect();
+        Ok(AudioData {
+            samples,
+            channels: 1,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: None,
+        })
+    }
+
+    pub fn to_stereo(&self) -> Result<AudioData<S>, BramError> {
+        let samples = match self.channels {
+            1 => self.samples.iter().flat_map(|sample| [*sample, *sample]).collect(),
+            2 => self.samples.clone(),
+            channels => return Err(BramError::UnsupportedChannels { channels }),
+        };
+        Ok(AudioData {
+            samples,
+            channels: 2,
+            sample_rate: self.sample_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod remix_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::remix::{PanLaw, SurroundDownmix};
+
+    fn stereo(samples: Vec<f32>) -> AudioData<f32> {
+        AudioData { samples, channels: 2, sample_rate: 8000, bits_per_sample: 32, channel_mask: Some(0x3) }
+    }
+
+    #[test]
+    fn to_mono_applies_pan_law_gain() {
+        let audio = stereo(vec![0.5, 0.5, 0.25, -0.25]);
+        assert_eq!(audio.to_mono(PanLaw::Linear).unwrap().samples, vec![0.5, 0.0]);
+        assert_eq!(audio.to_mono(PanLaw::Unity).unwrap().samples, vec![1.0, 0.0]);
+        let constant_power = audio.to_mono(PanLaw::ConstantPower).unwrap();
+        assert!((constant_power.samples[0] - std::f32::consts::SQRT_2 / 2.0).abs() < 1e-6);
+        assert_eq!((constant_power.channels, constant_power.channel_mask), (1, None));
+    }
+
+    #[test]
+    fn to_mono_clamps_integer_samples() {
+        let audio = AudioData { samples: vec![30000i16, 30000], channels: 2, sample_rate: 8000, bits_per_sample: 16, channel_mask: None };
+        assert_eq!(audio.to_mono(PanLaw::Unity).unwrap().samples, vec![i16::MAX]);
+    }
+
+    #[test]
+    fn to_stereo_duplicates_mono_and_keeps_stereo() {
+        let mono = AudioData { samples: vec![0.5f32, -0.25], channels: 1, sample_rate: 8000, bits_per_sample: 32, channel_mask: None };
+        let upmixed = mono.to_stereo().unwrap();
+        assert_eq!(upmixed.samples, vec![0.5, 0.5, -0.25, -0.25]);
+        assert_eq!(upmixed.channels, 2);
+        assert_eq!(stereo(vec![0.1, 0.2]).to_stereo().unwrap().samples, vec![0.1, 0.2]);
+
+        let surround = AudioData { samples: vec![0.0f32; 6], channels: 6, sample_rate: 8000, bits_per_sample: 32, channel_mask: None };
+        assert!(matches!(surround.to_stereo(), Err(BramError::UnsupportedChannels { channels: 6 })));
+    }
+
+    #[test]
+    fn surround_downmix_uses_layout_coefficients() {
+        let frame = [0.4f32, 0.2, 0.3, 1.0, 0.1, 0.0];
+        let surround = AudioData { samples: frame.to_vec(), channels: 6, sample_rate: 48000, bits_per_sample: 32, channel_mask: None };
+        assert!(matches!(surround.downmix_to_stereo(SurroundDownmix::Reject), Err(BramError::UnsupportedChannels { channels: 6 })));
+
+        let standard = surround.downmix_to_stereo(SurroundDownmix::Standard).unwrap();
+        let h = std::f32::consts::FRAC_1_SQRT_2;
+        let norm = 1.0 + 2.0 * h;
+        assert_eq!((standard.channels, standard.samples.len()), (2, 2));
+        assert!((standard.samples[0] - (0.4 + h * 0.3 + h * 0.1) / norm).abs() < 1e-6);
+        assert!((standard.samples[1] - (0.2 + h * 0.3) / norm).abs() < 1e-6);
+
+        let pairs = surround.downmix_to_stereo(SurroundDownmix::PairAverage).unwrap();
+        assert!((pairs.samples[0] - 0.8 / 3.0).abs() < 1e-6);
+        assert!((pairs.samples[1] - 1.2 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn full_scale_surround_stays_in_range() {
+        let surround = AudioData { samples: vec![i16::MAX; 8], channels: 8, sample_rate: 48000, bits_per_sample: 16, channel_mask: None };
+        let standard = surround.downmix_to_stereo(SurroundDownmix::Standard).unwrap();
+        assert_eq!(standard.samples, vec![i16::MAX, i16::MAX]);
+        let stereo = AudioData { samples: vec![1i16, 2], channels: 2, ..surround };
+        assert_eq!(stereo.downmix_to_stereo(SurroundDownmix::Reject).unwrap().samples, vec![1, 2]);
+    }
+}
@@ -0,0 +1,132 @@
+use std::f64::consts::PI;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::sample::Sample;
+
+const SINC_ZERO_CROSSINGS: f64 = 16.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResampleQuality {
+    Linear,
+    #[default]
+    Sinc,
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn resample(&self, target_rate: u32, quality: ResampleQuality) -> Result<AudioData<S>, BramError> {
+        if target_rate == 0 || self.sample_rate == 0 {
+            return Err(BramError::InvalidParameter("sample rates must be non-zero"));
+        }
+        if target_rate == self.sample_rate {
+            return Ok(self.clone());
+        }
+        let channels = self.channels.max(1) as usize;
+        let frames = self.frames();
+        let output_frames = (frames as u64 * target_rate as u64).div_ceil(self.sample_rate as u64) as usize;
+        let step = self.sample_rate as f64 / target_rate as f64;
+        let sample = |frame: usize, channel: usize| self.samples[frame * channels + channel].to_f64();
+
+        let mut samples = Vec::with_capacity(output_frames * channels);
+        for index in 0..output_frames {
+            let position = index as f64 * step;
+            for channel in 0..channels {
+                let value = match quality {
+                    ResampleQuality::Linear => {
+                        let left = (position.floor() as usize).min(frames - 1);
+                        let right = (left + 1).min(frames - 1);
+                        let fraction = position - left as f64;
+                        sample(left, channel) + (sample(right, channel) - sample(left, channel)) * fraction
+                    }
+                    ResampleQuality::Sinc => {
+                        let cutoff = (1.0 / step).min(1.0);
+                        let half_width = SINC_ZERO_CROSSINGS / cutoff;
+                        let first = (position - half_width).ceil().max(0.0) as usize;
+                        let last = ((position + half_width).floor() as usize).min(frames - 1);
+                        (first..=last)
+                            .map(|frame| {
+                                let offset = position - frame as f64;
+                                let window = 0.5 * (1.0 + (PI * offset / half_width).cos());
+                                sample(frame, channel) * cutoff * sinc(cutoff * offset) * window
+                            })
+                            .sum()
+                    }
+                };
+                samples.push(S::from_f64(value));
+            }
+        }
+        Ok(AudioData {
+            samples,
+            channels: self.channels,
+            sample_rate: target_rate,
+            bits_per_sample: self.bits_per_sample,
+            channel_mask: self.channel_mask,
+        })
+    }
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-12 {
+        return 1.0;
+    }
+    (PI * x).sin() / (PI * x)
+}
+
+#[cfg(test)]
+mod resample_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::resample::ResampleQuality;
+
+    fn tone(frequency: f64, sample_rate: u32, frames: usize) -> AudioData<f32> {
+        AudioData {
+            samples: (0..frames)
+                .map(|i| (0.5 * (2.0 * std::f64::consts::PI * frequency * i as f64 / sample_rate as f64).sin()) as f32)
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 32,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn linear_resample_interpolates_between_frames() {
+        let audio = AudioData {
+            samples: vec![0.0f32, 10.0, 0.0, 20.0, 0.0, 30.0],
+            channels: 2,
+            sample_rate: 100,
+            bits_per_sample: 32,
+            channel_mask: None,
+        };
+        let resampled = audio.resample(200, ResampleQuality::Linear).unwrap();
+        assert_eq!(resampled.sample_rate, 200);
+        assert_eq!(resampled.samples, vec![0.0, 10.0, 0.0, 15.0, 0.0, 20.0, 0.0, 25.0, 0.0, 30.0, 0.0, 30.0]);
+    }
+
+    #[test]
+    fn sinc_resample_preserves_tone_amplitude() {
+        let audio = tone(1000.0, 48000, 4800);
+        let resampled = audio.resample(44100, ResampleQuality::Sinc).unwrap();
+        assert_eq!(resampled.frames(), 4410);
+        let expected = tone(1000.0, 44100, 4410);
+        let error = resampled.samples[100..4300]
+            .iter()
+            .zip(&expected.samples[100..4300])
+            .fold(0.0f32, |max, (a, b)| max.max((a - b).abs()));
+        assert!(error < 1e-3, "max error {}", error);
+    }
+
+    #[test]
+    fn sinc_downsampling_removes_content_above_new_nyquist() {
+        let audio = tone(15000.0, 48000, 4800);
+        let resampled = audio.resample(16000, ResampleQuality::Sinc).unwrap();
+        let peak = resampled.samples[100..1500].iter().fold(0.0f32, |max, sample| max.max(sample.abs()));
+        assert!(peak < 0.01, "peak {}", peak);
+    }
+
+    #[test]
+    fn resample_rejects_zero_rate() {
+        let audio = tone(1000.0, 48000, 10);
+        assert!(matches!(audio.resample(0, ResampleQuality::Linear), Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,95 @@
+use core::fmt;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+
+const I16_SCALE: f64 = 32768.0;
+const I32_SCALE: f64 = 2147483648.0;
+
+pub trait Sample: Copy + Default + PartialEq + fmt::Debug + Send + Sync + 'static {
+    const BITS: u16;
+    const FLOAT: bool;
+
+    fn from_i32(value: i32) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+impl Sample for i16 {
+    const BITS: u16 = 16;
+    const FLOAT: bool = false;
+
+    fn from_i32(value: i32) -> Self {
+        (value >> 16) as i16
+    }
+
+    fn from_f64(value: f64) -> Self {
+        (value * I16_SCALE).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64 / I16_SCALE
+    }
+}
+
+impl Sample for i32 {
+    const BITS: u16 = 32;
+    const FLOAT: bool = false;
+
+    fn from_i32(value: i32) -> Self {
+        value
+    }
+
+    fn from_f64(value: f64) -> Self {
+        (value * I32_SCALE).round().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64 / I32_SCALE
+    }
+}
+
+impl Sample for f32 {
+    const BITS: u16 = 32;
+    const FLOAT: bool = true;
+
+    fn from_i32(value: i32) -> Self {
+        (value as f64 / I32_SCALE) as f32
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+#[cfg(test)]
+mod sample_tests {
+    use crate::sample::Sample;
+
+    #[test]
+    fn integer_samples_round_trip_through_f64() {
+        for value in [i16::MIN, -1, 0, 1, i16::MAX] {
+            assert_eq!(i16::from_f64(value.to_f64()), value);
+            assert_eq!(i32::from_f64(value.to_f64()), (value as i32) << 16);
+        }
+        assert_eq!(i32::from_f64(i32::MAX.to_f64()), i32::MAX);
+    }
+
+    #[test]
+    fn from_i32_keeps_available_precision() {
+        let value = 0x1234_5600;
+        assert_eq!(i16::from_i32(value), 0x1234);
+        assert_eq!(i32::from_i32(value), value);
+        assert_eq!(f32::from_i32(i32::MIN), -1.0);
+    }
+
+    #[test]
+    fn from_f64_clamps_integer_samples() {
+        assert_eq!(i16::from_f64(2.0), i16::MAX);
+        assert_eq!(i16::from_f64(-2.0), i16::MIN);
+        assert_eq!(f32::from_f64(2.0), 2.0);
+    }
+}
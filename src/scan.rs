@@ -0,0 +1,148 @@
+use std::fs::{self, ReadDir};
+use std::path::{Path, PathBuf};
+use crate::audio_presentation::StereoAudioPresentation;
+use crate::error::BramError;
+use crate::probe::WavInfo;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanOptions {
+    pub recursive: bool,
+    pub presentation_rate: Option<u32>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self { recursive: true, presentation_rate: None }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScannedFile {
+    pub path: PathBuf,
+    pub info: Result<WavInfo, BramError>,
+    pub presentation: Option<Result<StereoAudioPresentation, BramError>>,
+}
+
+#[derive(Debug)]
+pub struct SkippedDirectory {
+    pub path: PathBuf,
+    pub error: BramError,
+}
+
+#[derive(Debug, Default)]
+pub struct ScanReport {
+    pub files: Vec<ScannedFile>,
+    pub skipped_directories: Vec<SkippedDirectory>,
+}
+
+impl ScanReport {
+    pub fn failures(&self) -> impl Iterator<Item = &ScannedFile> {
+        self.files
+            .iter()
+            .filter(|file| file.info.is_err() || file.presentation.as_ref().is_some_and(Result::is_err))
+    }
+}
+
+pub fn scan_directory(path: impl AsRef<Path>, options: &ScanOptions) -> Result<ScanReport, BramError> {
+    let path = path.as_ref();
+    let mut paths = Vec::new();
+    let mut skipped_directories = Vec::new();
+    collect_audio_files(path, fs::read_dir(path)?, options.recursive, &mut paths, &mut skipped_directories);
+    paths.sort();
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let info = crate::probe(&path);
+            let presentation = match (&info, options.presentation_rate) {
+                (Ok(_), Some(rate)) => Some(crate::load_presentation(&path, rate)),
+                _ => None,
+            };
+            ScannedFile { path, info, presentation }
+        })
+        .collect();
+    Ok(ScanReport { files, skipped_directories })
+}
+
+fn collect_audio_files(dir: &Path, entries: ReadDir, recursive: bool, paths: &mut Vec<PathBuf>, skipped: &mut Vec<SkippedDirectory>) {
+    for entry in entries {
+        let (path, file_type) = match entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))) {
+            Ok(entry) => entry,
+            Err(error) => {
+                skipped.push(SkippedDirectory { path: dir.to_path_buf(), error: error.into() });
+                continue;
+            }
+        };
+        if file_type.is_dir() {
+            if recursive {
+                match fs::read_dir(&path) {
+                    Ok(entries) => collect_audio_files(&path, entries, recursive, paths, skipped),
+                    Err(error) => skipped.push(SkippedDirectory { path, error: error.into() }),
+                }
+            }
+        } else if !file_type.is_symlink() && is_audio_file(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("wav")) {
+        return true;
+    }
+    crate::other_container_parser(path).is_some()
+}
+
+#[cfg(test)]
+mod scan_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::scan::{scan_directory, ScanOptions};
+    use crate::wav_writer::write_wav;
+
+    #[test]
+    fn scan_probes_nested_audio_and_reports_failures() {
+        let root = std::env::temp_dir().join("bram_scan_directory");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("takes")).unwrap();
+        let audio = AudioData { samples: vec![0i16; 16], channels: 2, sample_rate: 8, bits_per_sample: 16, channel_mask: None };
+        write_wav(&audio, root.join("a.wav")).unwrap();
+        write_wav(&audio, root.join("takes").join("b.WAV")).unwrap();
+        fs::write(root.join("takes").join("broken.wav"), b"RIFF").unwrap();
+        fs::write(root.join("notes.txt"), b"not audio").unwrap();
+
+        let report = scan_directory(&root, &ScanOptions { presentation_rate: Some(4), ..ScanOptions::default() }).unwrap();
+        let names: Vec<_> = report.files.iter().map(|file| file.path.file_name().unwrap().to_str().unwrap()).collect();
+        assert_eq!(names, vec!["a.wav", "b.WAV", "broken.wav"]);
+        assert_eq!(report.files[0].info.as_ref().unwrap().frames, 8);
+        assert_eq!(report.files[1].presentation.as_ref().unwrap().as_ref().unwrap().left_channel_points.len(), 4);
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].presentation.is_none());
+
+        let shallow = scan_directory(&root, &ScanOptions { recursive: false, presentation_rate: None }).unwrap();
+        assert_eq!(shallow.files.len(), 1);
+        assert!(shallow.files[0].presentation.is_none());
+        assert!(report.skipped_directories.is_empty());
+        assert!(matches!(scan_directory(root.join("missing"), &ScanOptions::default()), Err(BramError::Io(_))));
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_does_not_follow_symlinks() {
+        let root = std::env::temp_dir().join("bram_scan_symlinks");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let audio = AudioData { samples: vec![0i16; 16], channels: 2, sample_rate: 8, bits_per_sample: 16, channel_mask: None };
+        write_wav(&audio, root.join("a.wav")).unwrap();
+        std::os::unix::fs::symlink(&root, root.join("loop")).unwrap();
+        std::os::unix::fs::symlink(root.join("a.wav"), root.join("alias.wav")).unwrap();
+
+        let report = scan_directory(&root, &ScanOptions::default()).unwrap();
+        assert_eq!(report.files.len(), 1);
+        assert!(report.files[0].path.ends_with("a.wav"));
+        assert!(report.skipped_directories.is_empty());
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,100 @@
+use std::ops::Range;
+use crate::audio_data::AudioData;
+use crate::markers::Marker;
+use crate::sample::Sample;
+
+#[derive(Debug, Clone)]
+pub struct Segment<S: Sample = i16> {
+    pub label: String,
+    pub frames: Range<usize>,
+    pub audio: AudioData<S>,
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn split_at_markers(&self, markers: &[Marker]) -> Vec<Segment<S>> {
+        let channels = self.channels.max(1) as usize;
+        let frames = self.frames();
+        let mut markers: Vec<&Marker> = markers.iter().filter(|marker| marker.position < frames).collect();
+        markers.sort_by_key(|marker| marker.position);
+        markers
+            .iter()
+            .enumerate()
+            .map(|(index, marker)| {
+                let end = match marker.length {
+                    0 => markers.get(index + 1).map_or(frames, |next| next.position),
+                    length => (marker.position + length).min(frames),
+                };
+                let range = marker.position..end;
+                Segment {
+                    label: marker.label.clone(),
+                    audio: AudioData {
+                        samples: self.samples[range.start * channels..range.end * channels].to_vec(),
+                        channels: self.channels,
+                        sample_rate: self.sample_rate,
+                        bits_per_sample: self.bits_per_sample,
+                        channel_mask: self.channel_mask,
+                    },
+                    frames: range,
+                }
+            })
+            .filter(|segment| !segment.frames.is_empty())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod segments_tests {
+    use std::fs;
+    use crate::audio_data::AudioData;
+    use crate::markers::Marker;
+    use crate::wav_writer::encode_wav;
+
+    fn marker(position: usize, length: usize, label: &str) -> Marker {
+        Marker { id: position as u32, position, length, label: label.to_string(), note: None }
+    }
+
+    #[test]
+    fn markers_delimit_consecutive_segments() {
+        let audio = AudioData { samples: (0..20).collect(), channels: 2, sample_rate: 10, bits_per_sample: 16, channel_mask: None };
+        let segments = audio.split_at_markers(&[marker(6, 0, "take 2"), marker(2, 0, "take 1"), marker(10, 0, "past end")]);
+        assert_eq!(segments.len(), 2);
+        assert_eq!((segments[0].label.as_str(), segments[0].frames.clone()), ("take 1", 2..6));
+        assert_eq!(segments[0].audio.samples, (4..12).collect::<Vec<i16>>());
+        assert_eq!((segments[1].label.as_str(), segments[1].frames.clone()), ("take 2", 6..10));
+        assert_eq!(segments[1].audio.channels, 2);
+    }
+
+    #[test]
+    fn region_markers_use_their_length() {
+        let audio = AudioData { samples: (0..10).collect(), channels: 1, sample_rate: 10, bits_per_sample: 16, channel_mask: None };
+        let segments = audio.split_at_markers(&[marker(1, 2, "region"), marker(5, 20, "tail"), marker(7, 0, "empty")]);
+        assert_eq!(segments.iter().map(|segment| segment.frames.clone()).collect::<Vec<_>>(), vec![1..3, 5..10, 7..10]);
+        assert_eq!(segments[0].audio.samples, vec![1, 2]);
+    }
+
+    #[test]
+    fn export_writes_one_file_per_cue() {
+        let audio = AudioData { samples: (0..100i16).collect(), channels: 1, sample_rate: 10, bits_per_sample: 16, channel_mask: None };
+        let mut bytes = encode_wav(&audio);
+        bytes.extend_from_slice(b"cue ");
+        bytes.extend_from_slice(&52u32.to_le_bytes());
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        for (id, position) in [(1u32, 30u32), (2, 70)] {
+            bytes.extend_from_slice(&id.to_le_bytes());
+            bytes.extend_from_slice(&[0; 16]);
+            bytes.extend_from_slice(&position.to_le_bytes());
+        }
+        let dir = std::env::temp_dir().join("bram_segments_export");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("session.wav");
+        fs::write(&source, bytes).unwrap();
+
+        let written = crate::export_segments(&source, &dir).unwrap();
+        assert_eq!(written, vec![dir.join("session_001.wav"), dir.join("session_002.wav")]);
+        let second = crate::load_audio(&written[1]).unwrap();
+        assert_eq!(second.samples, (70..100).collect::<Vec<i16>>());
+        assert_eq!(crate::load_segments(&source).unwrap()[0].frames, 30..70);
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::wav_binary::WavBinary;
+
+const HEADER_LEN: usize = 36;
+const LOOP_LEN: usize = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopType {
+    Forward,
+    PingPong,
+    Backward,
+    Other(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleLoop {
+    pub id: u32,
+    pub kind: LoopType,
+    pub start: usize,
+    pub end: usize,
+    pub play_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopInfo {
+    pub root_note: u8,
+    pub pitch_fraction: u32,
+    pub loops: Vec<SampleLoop>,
+}
+
+pub(crate) fn read_loop_info(wav: &WavBinary) -> Option<LoopInfo> {
+    let (_, offset, size) = wav.chunk_list().into_iter().find(|(id, _, _)| id == b"smpl")?;
+    let payload = wav.data.get(offset..offset + size)?;
+    if payload.len() < HEADER_LEN {
+        return None;
+    }
+    let field = |index: usize| read_u32(&payload[index * 4..]);
+    let loop_count = field(7) as usize;
+    let loops = payload[HEADER_LEN..]
+        .chunks_exact(LOOP_LEN)
+        .take(loop_count)
+        .map(|bytes| SampleLoop {
+            id: read_u32(&bytes[0..]),
+            kind: match read_u32(&bytes[4..]) {
+                0 => LoopType::Forward,
+                1 => LoopType::PingPong,
+                2 => LoopType::Backward,
+                other => LoopType::Other(other),
+            },
+            start: read_u32(&bytes[8..]) as usize,
+            end: read_u32(&bytes[12..]) as usize,
+            play_count: read_u32(&bytes[20..]),
+        })
+        .collect();
+    Some(LoopInfo {
+        root_note: field(3).min(127) as u8,
+        pitch_fraction: field(4),
+        loops,
+    })
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+mod smpl_tests {
+    use crate::smpl::{read_loop_info, LoopType, SampleLoop};
+    use crate::wav_binary::WavBinary;
+
+    fn wav_with_smpl(payload: &[u8]) -> WavBinary {
+        let mut data = b"RIFF\x00\x00\x00\x00WAVE".to_vec();
+        data.extend_from_slice(b"smpl");
+        data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(payload);
+        data.extend_from_slice(b"data\x00\x00\x00\x00");
+        WavBinary { data }
+    }
+
+    #[test]
+    fn read_loop_info_parses_root_note_and_loops() {
+        let mut payload = Vec::new();
+        for value in [0u32, 0, 22675, 60, 0x8000_0000, 0, 0, 2, 0] {
+            payload.extend_from_slice(&value.to_le_bytes());
+        }
+        for (id, kind, start, end) in [(1u32, 0u32, 1000u32, 5000u32), (2, 1, 200, 400)] {
+            for value in [id, kind, start, end, 0, 0] {
+                payload.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let info = read_loop_info(&wav_with_smpl(&payload)).unwrap();
+        assert_eq!(info.root_note, 60);
+        assert_eq!(info.pitch_fraction, 0x8000_0000);
+        assert_eq!(
+            info.loops,
+            vec![
+                SampleLoop { id: 1, kind: LoopType::Forward, start: 1000, end: 5000, play_count: 0 },
+                SampleLoop { id: 2, kind: LoopType::PingPong, start: 200, end: 400, play_count: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn read_loop_info_ignores_missing_or_short_chunk() {
+        assert!(read_loop_info(&wav_with_smpl(&[0; 20])).is_none());
+        let data = b"RIFF\x00\x00\x00\x00WAVEdata\x00\x00\x00\x00".to_vec();
+        assert!(read_loop_info(&WavBinary { data }).is_none());
+    }
+}
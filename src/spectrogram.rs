@@ -0,0 +1,105 @@
+use crate::analysis::mono_samples;
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::spectrum::{self, SpectrumConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectrogramPresentation {
+    pub columns: Vec<Vec<f32>>,
+    pub frequencies: Vec<f32>,
+    pub source_sample_rate: u32,
+    pub source_frames: usize,
+    pub samples_per_column: usize,
+}
+
+impl SpectrogramPresentation {
+    pub fn new(audio: &AudioData, rate: u32, config: &SpectrumConfig) -> Result<Self, BramError> {
+        spectrum::validate(config)?;
+        if rate == 0 || rate > audio.sample_rate {
+            return Err(BramError::InvalidParameter("spectrogram rate must be between one and the sample rate"));
+        }
+        let samples = mono_samples(audio);
+        let samples_per_column = (audio.sample_rate / rate) as usize;
+        let window = config.window.coefficients(config.window_size);
+        let columns = (0..samples.len().div_ceil(samples_per_column))
+            .map(|column| {
+                let start = column * samples_per_column;
+                let frame = &samples[start..(start + config.window_size).min(samples.len())];
+                spectrum::frame_magnitudes(frame, &window)
+            })
+            .collect();
+        Ok(SpectrogramPresentation {
+            columns,
+            frequencies: spectrum::bin_frequencies(config.window_size, audio.sample_rate),
+            source_sample_rate: audio.sample_rate,
+            source_frames: audio.frames(),
+            samples_per_column,
+        })
+    }
+
+    pub fn decibels(&self, floor_db: f32) -> Vec<Vec<f32>> {
+        self.columns
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|magnitude| (20.0 * magnitude.log10()).max(floor_db))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod spectrogram_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::spectrogram::SpectrogramPresentation;
+    use crate::spectrum::SpectrumConfig;
+
+    fn chirp_steps(sample_rate: u32) -> AudioData {
+        AudioData {
+            samples: (0..sample_rate)
+                .map(|i| {
+                    let frequency = if i < sample_rate / 2 { 500.0 } else { 2000.0 };
+                    (16384.0 * (2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32).sin()) as i16
+                })
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn columns_follow_frequency_changes_over_time() {
+        let config = SpectrumConfig { window_size: 512, ..SpectrumConfig::default() };
+        let spectrogram = SpectrogramPresentation::new(&chirp_steps(16000), 10, &config).unwrap();
+        assert_eq!(spectrogram.columns.len(), 10);
+        assert_eq!(spectrogram.samples_per_column, 1600);
+        assert!(spectrogram.columns.iter().all(|column| column.len() == 257));
+        let peak = |column: &Vec<f32>| {
+            let bin = column.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap().0;
+            spectrogram.frequencies[bin]
+        };
+        assert_eq!(peak(&spectrogram.columns[1]), 500.0);
+        assert_eq!(peak(&spectrogram.columns[8]), 2000.0);
+    }
+
+    #[test]
+    fn decibels_are_floored() {
+        let silent = AudioData { samples: vec![0i16; 1600], channels: 1, sample_rate: 16000, bits_per_sample: 16, channel_mask: None };
+        let config = SpectrumConfig { window_size: 256, ..SpectrumConfig::default() };
+        let spectrogram = SpectrogramPresentation::new(&silent, 100, &config).unwrap();
+        assert!(spectrogram.decibels(-96.0).iter().flatten().all(|db| *db == -96.0));
+    }
+
+    #[test]
+    fn rejects_zero_rate() {
+        assert!(matches!(
+            SpectrogramPresentation::new(&chirp_steps(16000), 0, &SpectrumConfig::default()),
+            Err(BramError::InvalidParameter(_))
+        ));
+    }
+}
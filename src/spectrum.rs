@@ -0,0 +1,184 @@
+use std::f32::consts::PI;
+use crate::analysis::{frames, mono_samples};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+use crate::fft;
+
+const CENTROID_MIN_WINDOW: usize = 256;
+const CENTROID_MAX_WINDOW: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowFunction {
+    Rectangular,
+    #[default]
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl WindowFunction {
+    pub fn coefficients(self, size: usize) -> Vec<f32> {
+        let phase = |i: usize| 2.0 * PI * i as f32 / (size.max(2) - 1) as f32;
+        match self {
+            WindowFunction::Rectangular => vec![1.0; size],
+            WindowFunction::Hann => fft::hann_window(size),
+            WindowFunction::Hamming => (0..size).map(|i| 0.54 - 0.46 * phase(i).cos()).collect(),
+            WindowFunction::Blackman => (0..size)
+                .map(|i| 0.42 - 0.5 * phase(i).cos() + 0.08 * (2.0 * phase(i)).cos())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpectrumConfig {
+    pub window_size: usize,
+    pub hop_size: usize,
+    pub window: WindowFunction,
+}
+
+impl Default for SpectrumConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 2048,
+            hop_size: 1024,
+            window: WindowFunction::Hann,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrum {
+    pub frequencies: Vec<f32>,
+    pub magnitudes: Vec<f32>,
+}
+
+impl Spectrum {
+    pub fn peak_frequency(&self) -> Option<f32> {
+        self.magnitudes
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(bin, _)| self.frequencies[bin])
+    }
+}
+
+pub fn spectrum(audio: &AudioData, config: &SpectrumConfig) -> Result<Spectrum, BramError> {
+    validate(config)?;
+    let samples = mono_samples(audio);
+    let window = config.window.coefficients(config.window_size);
+    let mut magnitudes = vec![0.0f32; config.window_size / 2 + 1];
+    let mut count = 0usize;
+    for frame in frames(&samples, config.window_size, config.hop_size) {
+        for (sum, magnitude) in magnitudes.iter_mut().zip(frame_magnitudes(frame, &window)) {
+            *sum += magnitude;
+        }
+        count += 1;
+    }
+    for magnitude in &mut magnitudes {
+        *magnitude /= count.max(1) as f32;
+    }
+    Ok(Spectrum {
+        frequencies: bin_frequencies(config.window_size, audio.sample_rate),
+        magnitudes,
+    })
+}
+
+pub(crate) fn point_centroids(audio: &AudioData, points: usize) -> Vec<f32> {
+    let samples = mono_samples(audio);
+    if samples.is_empty() || points == 0 {
+        return vec![0.0; points];
+    }
+    let frames_per_point = samples.len() as f64 / points as f64;
+    let window_size = (frames_per_point.ceil() as usize).next_power_of_two().clamp(CENTROID_MIN_WINDOW, CENTROID_MAX_WINDOW);
+    let window = fft::hann_window(window_size);
+    let frequencies = bin_frequencies(window_size, audio.sample_rate);
+    (0..points)
+        .map(|index| {
+            let center = ((index as f64 + 0.5) * frames_per_point) as usize;
+            let start = center.saturating_sub(window_size / 2).min(samples.len().saturating_sub(window_size));
+            let frame = &samples[start..(start + window_size).min(samples.len())];
+            let magnitudes = frame_magnitudes(frame, &window);
+            let total: f32 = magnitudes.iter().sum();
+            if total <= f32::EPSILON {
+                return 0.0;
+            }
+            magnitudes.iter().zip(&frequencies).map(|(magnitude, frequency)| magnitude * frequency).sum::<f32>() / total
+        })
+        .collect()
+}
+
+pub(crate) fn validate(config: &SpectrumConfig) -> Result<(), BramError> {
+    if !config.window_size.is_power_of_two() || config.window_size < 2 || config.hop_size == 0 {
+        return Err(BramError::InvalidParameter("window size must be a power of two and hop size non-zero"));
+    }
+    Ok(())
+}
+
+pub(crate) fn frame_magnitudes(frame: &[f32], window: &[f32]) -> Vec<f32> {
+    let gain = window.iter().sum::<f32>().max(f32::EPSILON);
+    let power = fft::power_spectrum(frame, window);
+    let last = power.len() - 1;
+    power
+        .iter()
+        .enumerate()
+        .map(|(bin, power)| {
+            let scale = if bin == 0 || bin == last { 1.0 } else { 2.0 };
+            scale * power.sqrt() / gain
+        })
+        .collect()
+}
+
+pub(crate) fn bin_frequencies(window_size: usize, sample_rate: u32) -> Vec<f32> {
+    (0..=window_size / 2)
+        .map(|bin| bin as f32 * sample_rate as f32 / window_size as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod spectrum_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::spectrum::{spectrum, SpectrumConfig, WindowFunction};
+
+    fn tone(frequency: f32, sample_rate: u32) -> AudioData {
+        AudioData {
+            samples: (0..sample_rate)
+                .map(|i| (16384.0 * (2.0 * std::f32::consts::PI * frequency * i as f32 / sample_rate as f32).sin()) as i16)
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn spectrum_peaks_at_tone_frequency_with_its_amplitude() {
+        let config = SpectrumConfig { window_size: 1024, hop_size: 512, window: WindowFunction::Rectangular };
+        let result = spectrum(&tone(1000.0, 16000), &config).unwrap();
+        assert_eq!(result.frequencies.len(), 513);
+        assert_eq!(result.magnitudes.len(), 513);
+        assert_eq!(result.frequencies[64], 1000.0);
+        assert_eq!(result.peak_frequency(), Some(1000.0));
+        assert!((result.magnitudes[64] - 0.5).abs() < 0.01, "{}", result.magnitudes[64]);
+    }
+
+    #[test]
+    fn window_functions_taper_except_rectangular() {
+        for window in [WindowFunction::Hann, WindowFunction::Hamming, WindowFunction::Blackman] {
+            let coefficients = window.coefficients(9);
+            assert!(coefficients[0] < 0.1);
+            assert!((coefficients[4] - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(WindowFunction::Rectangular.coefficients(3), vec![1.0; 3]);
+        let result = spectrum(&tone(1000.0, 16000), &SpectrumConfig::default()).unwrap();
+        assert_eq!(result.peak_frequency(), Some(1000.0));
+    }
+
+    #[test]
+    fn spectrum_rejects_bad_window_size() {
+        let config = SpectrumConfig { window_size: 1000, ..SpectrumConfig::default() };
+        assert!(matches!(spectrum(&tone(1000.0, 16000), &config), Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::audio_data::AudioData;
+use crate::sample::Sample;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ChannelStats {
+    pub peak: f64,
+    pub rms: f64,
+    pub crest_factor: f64,
+    pub zero_crossing_rate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AudioStats {
+    pub peak: f64,
+    pub rms: f64,
+    pub crest_factor: f64,
+    pub zero_crossing_rate: f64,
+    pub channels: Vec<ChannelStats>,
+}
+
+impl<S: Sample> AudioData<S> {
+    pub fn stats(&self) -> AudioStats {
+        let channel_count = self.channels.max(1) as usize;
+        let frames = self.samples.len() / channel_count;
+        let mut peaks = vec![0.0f64; channel_count];
+        let mut squares = vec![0.0f64; channel_count];
+        let mut crossings = vec![0usize; channel_count];
+        let mut previous: Option<&[S]> = None;
+        for frame in self.samples.chunks_exact(channel_count) {
+            for (channel, sample) in frame.iter().enumerate() {
+                let value = sample.to_f64();
+                peaks[channel] = peaks[channel].max(value.abs());
+                squares[channel] += value * value;
+                if previous.is_some_and(|previous| (previous[channel].to_f64() < 0.0) != (value < 0.0)) {
+                    crossings[channel] += 1;
+                }
+            }
+            previous = Some(frame);
+        }
+
+        let channels: Vec<ChannelStats> = (0..channel_count)
+            .map(|channel| {
+                let rms = (squares[channel] / frames.max(1) as f64).sqrt();
+                ChannelStats {
+                    peak: peaks[channel],
+                    rms,
+                    crest_factor: crest_factor(peaks[channel], rms),
+                    zero_crossing_rate: crossings[channel] as f64 / frames.max(1) as f64,
+                }
+            })
+            .collect();
+        let peak = peaks.iter().fold(0.0f64, |peak, channel| peak.max(*channel));
+        let rms = (squares.iter().sum::<f64>() / (frames * channel_count).max(1) as f64).sqrt();
+        AudioStats {
+            peak,
+            rms,
+            crest_factor: crest_factor(peak, rms),
+            zero_crossing_rate: crossings.iter().sum::<usize>() as f64 / (frames * channel_count).max(1) as f64,
+            channels,
+        }
+    }
+}
+
+fn crest_factor(peak: f64, rms: f64) -> f64 {
+    if rms > 0.0 { peak / rms } else { 0.0 }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use crate::audio_data::AudioData;
+
+    #[test]
+    fn square_wave_and_silence_per_channel() {
+        let samples = [16384i16, 0, -16384, 0, 16384, 0, -16384, 0].to_vec();
+        let audio = AudioData { samples, channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let stats = audio.stats();
+        assert_eq!(stats.channels.len(), 2);
+        assert_eq!(stats.channels[0].peak, 0.5);
+        assert_eq!(stats.channels[0].rms, 0.5);
+        assert_eq!(stats.channels[0].crest_factor, 1.0);
+        assert_eq!(stats.channels[0].zero_crossing_rate, 0.75);
+        assert_eq!(stats.channels[1].rms, 0.0);
+        assert_eq!(stats.channels[1].crest_factor, 0.0);
+        assert_eq!(stats.channels[1].zero_crossing_rate, 0.0);
+        assert_eq!(stats.peak, 0.5);
+        assert!((stats.rms - 0.125f64.sqrt()).abs() < 1e-12);
+        assert!((stats.crest_factor - 2.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(stats.zero_crossing_rate, 0.375);
+    }
+
+    #[test]
+    fn sine_has_sqrt_two_crest_factor() {
+        let samples: Vec<f32> = (0..48000).map(|i| (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 48000.0).sin()).collect();
+        let audio = AudioData { samples, channels: 1, sample_rate: 48000, bits_per_sample: 32, channel_mask: None };
+        let stats = audio.stats();
+        assert!((stats.peak - 1.0).abs() < 1e-3);
+        assert!((stats.crest_factor - 2.0f64.sqrt()).abs() < 1e-3);
+        assert!((stats.zero_crossing_rate * 48000.0 - 2000.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn empty_audio_has_zero_stats() {
+        let audio: AudioData = AudioData { samples: Vec::new(), channels: 2, sample_rate: 44100, bits_per_sample: 16, channel_mask: None };
+        let stats = audio.stats();
+        assert_eq!(stats.peak, 0.0);
+        assert_eq!(stats.rms, 0.0);
+        assert_eq!(stats.channels.len(), 2);
+    }
+}
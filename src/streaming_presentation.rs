@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+use crate::audio_presentation::{DownsampleStrategy, NormalizationMode, PresentationAccumulator, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::weighting::Weighting;
+
+pub struct StreamingPresentationBuilder {
+    channels: u16,
+    accumulator: PresentationAccumulator,
+    presentation: StereoAudioPresentation,
+    partial_frame: Vec<i16>,
+}
+
+impl StreamingPresentationBuilder {
+    pub fn new(channels: u16, sample_rate: u32, rate: u32, strategy: DownsampleStrategy) -> Result<Self, BramError> {
+        if rate == 0 {
+            return Err(BramError::InvalidParameter("presentation rate must be non-zero"));
+        }
+        let mut accumulator = PresentationAccumulator::new(
+            channels,
+            sample_rate,
+            rate,
+            strategy,
+            Weighting::None,
+            NormalizationMode::ZeroToOne,
+        )?;
+        let mut presentation = StereoAudioPresentation {
+            left_channel_points: Vec::new(),
+            right_channel_points: Vec::new(),
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        };
+        accumulator.drain_into(&mut presentation);
+        Ok(Self { channels, accumulator, presentation, partial_frame: Vec::new() })
+    }
+
+    pub fn push_frames(&mut self, mut samples: &[i16]) -> usize {
+        let channels = self.channels as usize;
+        let before = self.presentation.left_channel_points.len();
+        if !self.partial_frame.is_empty() {
+            let missing = (channels - self.partial_frame.len()).min(samples.len());
+            self.partial_frame.extend_from_slice(&samples[..missing]);
+            samples = &samples[missing..];
+            if self.partial_frame.len() < channels {
+                return 0;
+            }
+            self.accumulator.push(&self.partial_frame);
+            self.partial_frame.clear();
+        }
+        let whole = samples.len() - samples.len() % channels;
+        self.accumulator.push(&samples[..whole]);
+        self.partial_frame.extend_from_slice(&samples[whole..]);
+        self.accumulator.drain_into(&mut self.presentation);
+        self.presentation.left_channel_points.len() - before
+    }
+
+    pub fn presentation(&self) -> &StereoAudioPresentation {
+        &self.presentation
+    }
+
+    pub fn finish(mut self) -> StereoAudioPresentation {
+        self.accumulator.flush_buckets();
+        self.accumulator.drain_into(&mut self.presentation);
+        self.presentation
+    }
+}
+
+#[cfg(test)]
+mod streaming_presentation_tests {
+    #[cfg(feature = "std")]
+    use crate::audio_data::AudioData;
+    use crate::audio_presentation::DownsampleStrategy;
+    #[cfg(feature = "std")]
+    use crate::audio_presentation::{RatedAudioData, StereoAudioPresentation};
+    use crate::error::BramError;
+    use crate::streaming_presentation::StreamingPresentationBuilder;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pushed_frames_match_whole_file_presentation() {
+        let samples: Vec<i16> = (0..400).map(|index| ((index * 37) % 2000 - 1000) as i16).collect();
+        let audio = AudioData { samples: samples.clone(), channels: 2, sample_rate: 100, bits_per_sample: 16, channel_mask: None };
+        for strategy in [DownsampleStrategy::Decimate, DownsampleStrategy::Peak, DownsampleStrategy::MinMax] {
+            let expected = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio, 3).with_mode(strategy)).unwrap();
+            let mut builder = StreamingPresentationBuilder::new(2, 100, 3, strategy).unwrap();
+            for block in samples.chunks(14) {
+                builder.push_frames(block);
+            }
+            let streamed = builder.finish();
+            assert_eq!(streamed.left_channel_points, expected.left_channel_points);
+            assert_eq!(streamed.right_channel_points, expected.right_channel_points);
+            assert_eq!(streamed.left_envelope, expected.left_envelope);
+            assert_eq!(streamed.source_frames, 200);
+        }
+    }
+
+    #[test]
+    fn push_frames_reports_completed_points() {
+        let mut builder = StreamingPresentationBuilder::new(1, 8, 2, DownsampleStrategy::Peak).unwrap();
+        assert_eq!(builder.push_frames(&[1, 2, 3]), 0);
+        assert_eq!(builder.push_frames(&[4, 5, 6, 7, 8, 9]), 2);
+        assert_eq!(builder.presentation().left_channel_points.len(), 2);
+        assert_eq!(builder.presentation().samples_per_point, 4);
+        assert_eq!(builder.finish().left_channel_points.len(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn partial_frames_are_carried_into_the_next_push() {
+        let mut builder = StreamingPresentationBuilder::new(2, 4, 2, DownsampleStrategy::Peak).unwrap();
+        assert_eq!(builder.push_frames(&[1000, -2000, 3000]), 0);
+        assert_eq!(builder.push_frames(&[-4000, 5000, -6000]), 1);
+        let streamed = builder.finish();
+
+        let audio = AudioData { samples: vec![1000, -2000, 3000, -4000, 5000, -6000], channels: 2, sample_rate: 4, bits_per_sample: 16, channel_mask: None };
+        let expected = StereoAudioPresentation::try_from(&RatedAudioData::new(&audio, 2).with_mode(DownsampleStrategy::Peak)).unwrap();
+        assert_eq!(streamed.source_frames, 3);
+        assert_eq!(streamed.left_channel_points, expected.left_channel_points);
+        assert_eq!(streamed.right_channel_points, expected.right_channel_points);
+    }
+
+    #[test]
+    fn invalid_configuration_is_rejected() {
+        assert!(matches!(StreamingPresentationBuilder::new(2, 100, 0, DownsampleStrategy::Peak), Err(BramError::InvalidParameter(_))));
+        assert!(matches!(StreamingPresentationBuilder::new(6, 100, 10, DownsampleStrategy::Peak), Err(BramError::UnsupportedChannels { channels: 6 })));
+    }
+}
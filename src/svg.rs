@@ -0,0 +1,136 @@
+use std::fmt::Write;
+use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    pub width: u32,
+    pub height: u32,
+    pub background: Option<String>,
+    pub left_color: String,
+    pub right_color: String,
+    pub stroke_width: f32,
+    pub fill: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            width: 800,
+            height: 200,
+            background: None,
+            left_color: "#3b82f6".to_string(),
+            right_color: "#f97316".to_string(),
+            stroke_width: 1.0,
+            fill: true,
+        }
+    }
+}
+
+impl StereoAudioPresentation {
+    pub fn render_svg(&self, options: &SvgOptions) -> String {
+        let (width, height) = (options.width.max(1), options.height.max(2));
+        let lane = height as f32 / 2.0;
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        if let Some(background) = &options.background {
+            let _ = write!(svg, r#"<rect width="{width}" height="{height}" fill="{}"/>"#, escape(background));
+        }
+        let channels = [
+            (&self.left_channel_points, self.left_envelope.as_ref(), &options.left_color, 0.0),
+            (&self.right_channel_points, self.right_envelope.as_ref(), &options.right_color, lane),
+        ];
+        for (points, envelope, color, top) in channels {
+            let columns = column_bounds(points, envelope, width as usize);
+            let y = |value: f32| top + (1.0 - value.clamp(0.0, 1.0)) * lane;
+            let coordinates: Vec<String> = if options.fill {
+                let upper = columns.iter().enumerate().map(|(x, (_, high))| (x, y(*high)));
+                let lower = columns.iter().enumerate().rev().map(|(x, (low, _))| (x, y(*low)));
+                upper.chain(lower).map(|(x, y)| format!("{x},{y:.2}")).collect()
+            } else {
+                columns
+                    .iter()
+                    .enumerate()
+                    .map(|(x, (low, high))| format!("{x},{:.2}", y((low + high) / 2.0)))
+                    .collect()
+            };
+            let color = escape(color);
+            let (element, paint) = if options.fill {
+                ("polygon", format!(r#"fill="{color}" stroke="{color}""#))
+            } else {
+                ("polyline", format!(r#"fill="none" stroke="{color}""#))
+            };
+            let _ = write!(
+                svg,
+                r#"<{element} points="{}" {paint} stroke-width="{}"/>"#,
+                coordinates.join(" "),
+                options.stroke_width
+            );
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+pub(crate) fn column_bounds(points: &[f32], envelope: Option<&Envelope>, columns: usize) -> Vec<(f32, f32)> {
+    if points.is_empty() {
+        return vec![(0.5, 0.5); columns];
+    }
+    (0..columns)
+        .map(|column| {
+            let start = column * points.len() / columns;
+            let end = ((column + 1) * points.len() / columns).max(start + 1).min(points.len());
+            (start..end).fold((f32::MAX, f32::MIN), |(low, high), index| match envelope {
+                Some(envelope) => (low.min(envelope.low[index]), high.max(envelope.high[index])),
+                None => (low.min(points[index]), high.max(points[index])),
+            })
+        })
+        .collect()
+}
+
+fn escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod svg_tests {
+    use crate::audio_presentation::{Envelope, StereoAudioPresentation};
+    use crate::svg::SvgOptions;
+
+    fn presentation() -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: vec![0.5, 1.0, 0.0, 0.5],
+            right_channel_points: vec![0.5, 0.75, 0.25, 0.5],
+            left_envelope: Some(Envelope { low: vec![0.5, 0.0, 0.0, 0.5], high: vec![0.5, 1.0, 1.0, 0.5] }),
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 8,
+            source_channels: 2,
+            source_frames: 8,
+            samples_per_point: 2,
+        }
+    }
+
+    #[test]
+    fn filled_svg_draws_one_polygon_per_channel() {
+        let options = SvgOptions { width: 2, height: 100, background: Some("#000".to_string()), ..SvgOptions::default() };
+        let svg = presentation().render_svg(&options);
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="2" height="100""#));
+        assert!(svg.contains(r##"<rect width="2" height="100" fill="#000"/>"##));
+        assert!(svg.contains(r##"<polygon points="0,0.00 1,0.00 1,50.00 0,50.00" fill="#3b82f6""##));
+        assert!(svg.contains(r##"<polygon points="0,62.50 1,75.00 1,87.50 0,75.00" fill="#f97316""##));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn stroke_only_svg_uses_polylines() {
+        let options = SvgOptions { width: 4, height: 20, fill: false, stroke_width: 2.5, ..SvgOptions::default() };
+        let svg = presentation().render_svg(&options);
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        assert!(svg.contains(r##"<polyline points="0,15.00 1,12.50 2,17.50 3,15.00" fill="none" stroke="#f97316" stroke-width="2.5"/>"##));
+        assert!(!svg.contains("<rect"));
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use crate::audio_data::AudioData;
+use crate::audio_presentation::{DownsampleStrategy, StereoAudioPresentation};
+use crate::error::BramError;
+use crate::streaming_presentation::StreamingPresentationBuilder;
+use crate::wav_binary::{self, HeaderDiagnosis, WavBinary};
+
+pub struct WavTail {
+    path: PathBuf,
+    header: Vec<u8>,
+    channels: u16,
+    block_align: u64,
+    data_start: u64,
+    consumed: u64,
+    builder: StreamingPresentationBuilder,
+}
+
+impl WavTail {
+    pub fn open(path: impl AsRef<Path>, rate: u32, strategy: DownsampleStrategy) -> Result<Self, BramError> {
+        let path = path.as_ref();
+        let mut reader = WavBinary::open_reader(path)?;
+        let mut riff = [0u8; 12];
+        reader.read_exact(&mut riff)?;
+        let diagnosis = wav_binary::diagnose(&riff);
+        if diagnosis != HeaderDiagnosis::Valid {
+            return Err(BramError::Header(diagnosis));
+        }
+        let mut header = riff.to_vec();
+        let mut pos = 12u64;
+        loop {
+            let mut chunk_header = [0u8; 8];
+            match reader.read_exact(&mut chunk_header) {
+                Ok(()) => {}
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                    return Err(BramError::MissingChunk { id: *b"data" });
+                }
+                Err(error) => return Err(error.into()),
+            }
+            pos += 8;
+            let chunk_size = u32::from_le_bytes([chunk_header[4], chunk_header[5], chunk_header[6], chunk_header[7]]) as u64;
+            match &chunk_header[0..4] {
+                b"data" => break,
+                b"fmt " => {
+                    header.extend_from_slice(&chunk_header);
+                    reader.by_ref().take(chunk_size).read_to_end(&mut header)?;
+                    reader.seek(SeekFrom::Current((chunk_size % 2) as i64))?;
+                }
+                _ => {
+                    reader.seek(SeekFrom::Current((chunk_size + chunk_size % 2) as i64))?;
+                }
+            }
+            pos += chunk_size + chunk_size % 2;
+        }
+        let info = AudioData::read_header_info(&header)?;
+        let builder = StreamingPresentationBuilder::new(info.channels, info.sample_rate, rate, strategy)?;
+        Ok(WavTail {
+            path: path.to_path_buf(),
+            header,
+            channels: info.channels,
+            block_align: info.block_align.max(1) as u64,
+            data_start: pos,
+            consumed: 0,
+            builder,
+        })
+    }
+
+    pub fn poll(&mut self) -> Result<usize, BramError> {
+        let mut reader = WavBinary::open_reader(&self.path)?;
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(self.data_start - 4))?;
+        let mut declared = [0u8; 4];
+        reader.read_exact(&mut declared)?;
+        let written = len.saturating_sub(self.data_start);
+        let available = match u32::from_le_bytes(declared) {
+            0 | u32::MAX => written,
+            size => (size as u64).min(written),
+        };
+        let pending = available.saturating_sub(self.consumed);
+        let usable = pending - pending % self.block_align;
+        if usable == 0 {
+            return Ok(0);
+        }
+        reader.seek(SeekFrom::Start(self.data_start + self.consumed))?;
+        let mut bytes = vec![0u8; usable as usize];
+        reader.read_exact(&mut bytes)?;
+        let samples: Vec<i16> = AudioData::decode_samples(&self.header, &bytes, self.channels)?;
+        self.consumed += usable;
+        self.builder.push_frames(&samples);
+        Ok(samples.len() / self.channels.max(1) as usize)
+    }
+
+    pub fn presentation(&self) -> &StereoAudioPresentation {
+        self.builder.presentation()
+    }
+
+    pub fn into_presentation(self) -> StereoAudioPresentation {
+        self.builder.finish()
+    }
+}
+
+#[cfg(test)]
+mod tail_tests {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use crate::audio_presentation::DownsampleStrategy;
+    use crate::tail::WavTail;
+
+    fn header(data_size: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&8u32.to_le_bytes());
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_size.to_le_bytes());
+        bytes
+    }
+
+    fn append(path: &std::path::Path, samples: &[i16], extra: &[u8]) {
+        let mut file = OpenOptions::new().append(true).open(path).unwrap();
+        for sample in samples {
+            file.write_all(&sample.to_le_bytes()).unwrap();
+        }
+        file.write_all(extra).unwrap();
+    }
+
+    #[test]
+    fn poll_decodes_only_newly_appended_whole_frames() {
+        let path = std::env::temp_dir().join("bram_tail_growing.wav");
+        fs::write(&path, header(0)).unwrap();
+        let mut tail = WavTail::open(path.to_str().unwrap(), 2, DownsampleStrategy::Peak).unwrap();
+        assert_eq!(tail.poll().unwrap(), 0);
+
+        append(&path, &[100, -200, 300], &[0x10]);
+        assert_eq!(tail.poll().unwrap(), 3);
+        assert_eq!(tail.presentation().left_channel_points.len(), 0);
+
+        append(&path, &[], &[0x00]);
+        append(&path, &[16384, 0, 0, 0, 0], &[]);
+        assert_eq!(tail.poll().unwrap(), 6);
+        assert_eq!(tail.presentation().left_channel_points.len(), 2);
+        assert_eq!(tail.presentation().source_frames, 9);
+        assert_eq!(tail.poll().unwrap(), 0);
+
+        let finished = tail.into_presentation();
+        assert_eq!(finished.left_channel_points.len(), 3);
+        assert_eq!(finished.left_envelope.unwrap().high.len(), 3);
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn poll_respects_finalized_data_size() {
+        let path = std::env::temp_dir().join("bram_tail_finalized.wav");
+        fs::write(&path, header(4)).unwrap();
+        append(&path, &[1, 2], b"LIST\x00\x00\x00\x00");
+        let mut tail = WavTail::open(path.to_str().unwrap(), 4, DownsampleStrategy::Decimate).unwrap();
+        assert_eq!(tail.poll().unwrap(), 2);
+        assert_eq!(tail.poll().unwrap(), 0);
+        assert_eq!(tail.presentation().left_channel_points.len(), 1);
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::analysis::{frames, mono_samples};
+use crate::audio_data::AudioData;
+use crate::error::BramError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempoConfig {
+    pub hop_size: usize,
+    pub min_bpm: f32,
+    pub max_bpm: f32,
+}
+
+impl Default for TempoConfig {
+    fn default() -> Self {
+        Self {
+            hop_size: 256,
+            min_bpm: 60.0,
+            max_bpm: 200.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tempo {
+    pub bpm: f32,
+    pub confidence: f32,
+}
+
+pub fn estimate_tempo(audio: &AudioData, config: &TempoConfig) -> Result<Option<Tempo>, BramError> {
+    if config.hop_size == 0 || config.min_bpm <= 0.0 || config.max_bpm <= config.min_bpm {
+        return Err(BramError::InvalidParameter("tempo hop size must be non-zero and bpm range increasing"));
+    }
+    let onsets = onset_strength(&mono_samples(audio), config.hop_size);
+    let frame_rate = audio.sample_rate as f32 / config.hop_size as f32;
+    let shortest = ((60.0 * frame_rate / config.max_bpm).floor() as usize).max(1);
+    let longest = ((60.0 * frame_rate / config.min_bpm).ceil() as usize).min(onsets.len().saturating_sub(1));
+    if shortest >= longest {
+        return Ok(None);
+    }
+
+    let energy: f32 = onsets.iter().map(|onset| onset * onset).sum();
+    if energy == 0.0 {
+        return Ok(None);
+    }
+    let correlation = |lag: usize| onsets.iter().zip(&onsets[lag..]).map(|(a, b)| a * b).sum::<f32>();
+    let scores: Vec<f32> = (shortest - 1..=longest + 1).map(correlation).collect();
+    let best = (1..scores.len() - 1).max_by(|a, b| scores[*a].total_cmp(&scores[*b])).unwrap_or(1);
+    let (before, peak, after) = (scores[best - 1], scores[best], scores[best + 1]);
+    let curvature = before - 2.0 * peak + after;
+    let offset = if curvature < 0.0 { 0.5 * (before - after) / curvature } else { 0.0 };
+    let lag = (shortest - 1 + best) as f32 + offset;
+    Ok(Some(Tempo {
+        bpm: 60.0 * frame_rate / lag,
+        confidence: (peak / energy).clamp(0.0, 1.0),
+    }))
+}
+
+fn onset_strength(samples: &[f32], hop_size: usize) -> Vec<f32> {
+    let energies: Vec<f32> = frames(samples, hop_size, hop_size)
+        .map(|frame| (frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32 + 1e-10).ln())
+        .collect();
+    let mut onsets: Vec<f32> = energies.windows(2).map(|pair| (pair[1] - pair[0]).max(0.0)).collect();
+    let mean = onsets.iter().sum::<f32>() / onsets.len().max(1) as f32;
+    for onset in &mut onsets {
+        *onset = (*onset - mean).max(0.0);
+    }
+    onsets
+}
+
+#[cfg(test)]
+mod tempo_tests {
+    use crate::audio_data::AudioData;
+    use crate::error::BramError;
+    use crate::tempo::{estimate_tempo, TempoConfig};
+
+    fn click_track(bpm: f32, seconds: u32) -> AudioData {
+        let sample_rate = 16000;
+        let period = (60.0 / bpm * sample_rate as f32) as u32;
+        AudioData {
+            samples: (0..sample_rate * seconds)
+                .map(|i| {
+                    let since_click = i % period;
+                    if since_click < 400 {
+                        let decay = 1.0 - since_click as f32 / 400.0;
+                        (20000.0 * decay * (i as f32 * 0.7).sin()) as i16
+                    } else {
+                        0
+                    }
+                })
+                .collect(),
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn estimates_click_track_tempo() {
+        for bpm in [90.0, 120.0, 150.0] {
+            let tempo = estimate_tempo(&click_track(bpm, 8), &TempoConfig::default()).unwrap().unwrap();
+            assert!((tempo.bpm - bpm).abs() < 2.0, "expected {} got {}", bpm, tempo.bpm);
+            assert!(tempo.confidence > 0.0);
+        }
+    }
+
+    #[test]
+    fn silence_and_short_audio_have_no_tempo() {
+        let silent = AudioData { samples: vec![0i16; 16000 * 4], channels: 1, sample_rate: 16000, bits_per_sample: 16, channel_mask: None };
+        assert_eq!(estimate_tempo(&silent, &TempoConfig::default()).unwrap(), None);
+        let short = AudioData { samples: vec![0i16; 100], ..silent };
+        assert_eq!(estimate_tempo(&short, &TempoConfig::default()).unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_inverted_bpm_range() {
+        let config = TempoConfig { min_bpm: 200.0, max_bpm: 60.0, ..TempoConfig::default() };
+        assert!(matches!(estimate_tempo(&click_track(120.0, 1), &config), Err(BramError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::audio_presentation::StereoAudioPresentation;
+
+const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const BRAILLE_BASE: u32 = 0x2800;
+const BRAILLE_DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+pub fn render_blocks(presentation: &StereoAudioPresentation, width: usize) -> String {
+    column_amplitudes(presentation, width)
+        .into_iter()
+        .map(|amplitude| BLOCKS[(amplitude * 8.0).round() as usize])
+        .collect()
+}
+
+pub fn render_braille(presentation: &StereoAudioPresentation, width: usize, rows: usize) -> String {
+    let dot_rows = rows * 4;
+    let amplitudes = column_amplitudes(presentation, width * 2);
+    let center = dot_rows as f32 / 2.0;
+    let mut cells = vec![vec![0u32; width]; rows];
+    for (dot_column, amplitude) in amplitudes.iter().enumerate() {
+        let half = (amplitude * center).max(0.5);
+        let top = (center - half).round().max(0.0) as usize;
+        let bottom = ((center + half).round() as usize).min(dot_rows);
+        for dot_row in top..bottom {
+            cells[dot_row / 4][dot_column / 2] |= BRAILLE_DOTS[dot_column % 2][dot_row % 4];
+        }
+    }
+    cells
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|bits| char::from_u32(BRAILLE_BASE + bits).unwrap_or(' '))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn column_amplitudes(presentation: &StereoAudioPresentation, columns: usize) -> Vec<f32> {
+    let points = presentation.left_channel_points.len().min(presentation.right_channel_points.len());
+    (0..columns)
+        .map(|column| {
+            let start = column * points / columns;
+            let end = ((column + 1) * points / columns).max(start + 1).min(points);
+            (start..end)
+                .flat_map(|index| [presentation.left_channel_points[index], presentation.right_channel_points[index]])
+                .map(|point| ((point - 0.5).abs() * 2.0).min(1.0))
+                .fold(0.0, f32::max)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tui_tests {
+    use crate::audio_presentation::StereoAudioPresentation;
+    use crate::tui::{render_blocks, render_braille};
+
+    fn presentation(points: Vec<f32>) -> StereoAudioPresentation {
+        StereoAudioPresentation {
+            left_channel_points: points.clone(),
+            right_channel_points: points,
+            left_envelope: None,
+            right_envelope: None,
+            spectral_centroid: None,
+            annotations: Vec::new(),
+            source_sample_rate: 0,
+            source_channels: 0,
+            source_frames: 0,
+            samples_per_point: 0,
+        }
+    }
+
+    #[test]
+    fn render_blocks_maps_amplitude_to_block_height() {
+        let rendered = render_blocks(&presentation(vec![0.5, 0.75, 1.0, 0.0]), 4);
+        assert_eq!(rendered, " ▄██");
+    }
+
+    #[test]
+    fn render_blocks_squeezes_points_into_width() {
+        let rendered = render_blocks(&presentation(vec![0.5, 1.0, 0.5, 0.5]), 2);
+        assert_eq!(rendered.chars().count(), 2);
+        assert_eq!(rendered, "█ ");
+    }
+
+    #[test]
+    fn render_braille_draws_centered_bars() {
+        let rendered = render_braille(&presentation(vec![1.0, 1.0, 0.5, 0.5]), 2, 2);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "⣿⠀");
+        assert_eq!(lines[1], "⣿⠉");
+    }
+
+    #[test]
+    fn render_empty_presentation() {
+        assert_eq!(render_blocks(&presentation(Vec::new()), 3), "   ");
+    }
+}
@@ -0,0 +1,120 @@
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    SkippedChunk { id: [u8; 4], offset: usize },
+    ClampedChunkSize { id: [u8; 4], declared: usize, available: usize },
+    DroppedPartialFrame { bytes: usize },
+    SkippedPadding { id: [u8; 4], offset: usize, size: usize },
+    TrailingGarbage { offset: usize, bytes: usize },
+    RiffSizeMismatch { declared: usize, actual: usize },
+    UnexpectedChunkOrder { id: [u8; 4], offset: usize },
+    BlockAlignMismatch { block_align: u16, expected: u16 },
+    ByteRateMismatch { byte_rate: u32, expected: u32 },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::SkippedChunk { id, offset } => write!(
+                f,
+                "skipped '{}' chunk at offset {}",
+                String::from_utf8_lossy(id),
+                offset
+            ),
+            Warning::ClampedChunkSize { id, declared, available } => write!(
+                f,
+                "'{}' chunk declares {} bytes but only {} are available",
+                String::from_utf8_lossy(id),
+                declared,
+                available
+            ),
+            Warning::DroppedPartialFrame { bytes } => write!(
+                f,
+                "dropped {} trailing bytes of an incomplete frame",
+                bytes
+            ),
+            Warning::SkippedPadding { id, offset, size } => write!(
+                f,
+                "skipped {} bytes of '{}' padding at offset {}",
+                size,
+                String::from_utf8_lossy(id),
+                offset
+            ),
+            Warning::TrailingGarbage { offset, bytes } => write!(
+                f,
+                "ignored {} bytes of trailing garbage at offset {}",
+                bytes,
+                offset
+            ),
+            Warning::RiffSizeMismatch { declared, actual } => write!(
+                f,
+                "RIFF header declares {} bytes but the chunks span {}",
+                declared,
+                actual
+            ),
+            Warning::UnexpectedChunkOrder { id, offset } => write!(
+                f,
+                "'{}' chunk at offset {} appears before the 'fmt ' chunk",
+                String::from_utf8_lossy(id),
+                offset
+            ),
+            Warning::BlockAlignMismatch { block_align, expected } => write!(
+                f,
+                "block align {} disagrees with {} implied by channels and bit depth",
+                block_align,
+                expected
+            ),
+            Warning::ByteRateMismatch { byte_rate, expected } => write!(
+                f,
+                "byte rate {} disagrees with {} implied by sample rate and block align",
+                byte_rate,
+                expected
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod warning_tests {
+    use crate::warning::Warning;
+
+    #[test]
+    fn display_skipped_chunk() {
+        let warning = Warning::SkippedChunk { id: *b"data", offset: 44 };
+        assert_eq!(warning.to_string(), "skipped 'data' chunk at offset 44");
+    }
+
+    #[test]
+    fn display_clamped_chunk_size() {
+        let warning = Warning::ClampedChunkSize { id: *b"data", declared: 100, available: 8 };
+        assert_eq!(warning.to_string(), "'data' chunk declares 100 bytes but only 8 are available");
+    }
+
+    #[test]
+    fn display_dropped_partial_frame() {
+        let warning = Warning::DroppedPartialFrame { bytes: 2 };
+        assert_eq!(warning.to_string(), "dropped 2 trailing bytes of an incomplete frame");
+    }
+
+    #[test]
+    fn display_padding_and_trailing_garbage() {
+        let padding = Warning::SkippedPadding { id: *b"JUNK", offset: 12, size: 28 };
+        assert_eq!(padding.to_string(), "skipped 28 bytes of 'JUNK' padding at offset 12");
+        let garbage = Warning::TrailingGarbage { offset: 100, bytes: 7 };
+        assert_eq!(garbage.to_string(), "ignored 7 bytes of trailing garbage at offset 100");
+    }
+
+    #[test]
+    fn display_spec_deviations() {
+        let riff = Warning::RiffSizeMismatch { declared: 36, actual: 44 };
+        assert_eq!(riff.to_string(), "RIFF header declares 36 bytes but the chunks span 44");
+        let order = Warning::UnexpectedChunkOrder { id: *b"data", offset: 12 };
+        assert_eq!(order.to_string(), "'data' chunk at offset 12 appears before the 'fmt ' chunk");
+        let block_align = Warning::BlockAlignMismatch { block_align: 3, expected: 4 };
+        assert_eq!(block_align.to_string(), "block align 3 disagrees with 4 implied by channels and bit depth");
+        let byte_rate = Warning::ByteRateMismatch { byte_rate: 1000, expected: 176400 };
+        assert_eq!(byte_rate.to_string(), "byte rate 1000 disagrees with 176400 implied by sample rate and block align");
+    }
+}
@@ -0,0 +1,185 @@
+use std::fs;
+use std::path::Path;
+use crate::audio_data::{AudioData, KSDATAFORMAT_SUBTYPE_BASE, WAVE_FORMAT_EXTENSIBLE, WAVE_FORMAT_IEEE_FLOAT, WAVE_FORMAT_PCM};
+use crate::error::BramError;
+use crate::sample::Sample;
+use crate::wav_binary::WavBinary;
+
+const REGENERATED_CHUNKS: [&[u8; 4]; 3] = [b"fmt ", b"data", b"fact"];
+
+pub fn encode_wav<S: Sample>(audio: &AudioData<S>) -> Vec<u8> {
+    let (fmt, data) = encode_chunks(audio);
+    assemble(&[(*b"fmt ", &fmt), (*b"data", &data)])
+}
+
+pub fn encode_wav_with_source<S: Sample>(audio: &AudioData<S>, source: &WavBinary) -> Vec<u8> {
+    let (fmt, data) = encode_chunks(audio);
+    let source = source.to_little_endian();
+    let mut chunks = vec![(*b"fmt ", fmt.as_slice())];
+    let mut wrote_data = false;
+    for chunk in source.chunks() {
+        if &chunk.id == b"data" && !wrote_data {
+            chunks.push((*b"data", data.as_slice()));
+            wrote_data = true;
+        } else if !REGENERATED_CHUNKS.contains(&&chunk.id) {
+            chunks.push((chunk.id, chunk.payload));
+        }
+    }
+    if !wrote_data {
+        chunks.push((*b"data", data.as_slice()));
+    }
+    assemble(&chunks)
+}
+
+fn encode_chunks<S: Sample>(audio: &AudioData<S>) -> (Vec<u8>, Vec<u8>) {
+    let bits = output_bits::<S>(audio.bits_per_sample);
+    let width = bits as usize / 8;
+    let format_tag = if S::FLOAT { WAVE_FORMAT_IEEE_FLOAT } else { WAVE_FORMAT_PCM };
+    let block_align = audio.channels * width as u16;
+
+    let mut fmt = Vec::with_capacity(40);
+    let tag = if audio.channel_mask.is_some() { WAVE_FORMAT_EXTENSIBLE } else { format_tag };
+    fmt.extend_from_slice(&tag.to_le_bytes());
+    fmt.extend_from_slice(&audio.channels.to_le_bytes());
+    fmt.extend_from_slice(&audio.sample_rate.to_le_bytes());
+    fmt.extend_from_slice(&(audio.sample_rate * block_align as u32).to_le_bytes());
+    fmt.extend_from_slice(&block_align.to_le_bytes());
+    fmt.extend_from_slice(&bits.to_le_bytes());
+    if let Some(channel_mask) = audio.channel_mask {
+        fmt.extend_from_slice(&22u16.to_le_bytes());
+        fmt.extend_from_slice(&bits.to_le_bytes());
+        fmt.extend_from_slice(&channel_mask.to_le_bytes());
+        fmt.extend_from_slice(&format_tag.to_le_bytes());
+        fmt.extend_from_slice(&[0, 0]);
+        fmt.extend_from_slice(&KSDATAFORMAT_SUBTYPE_BASE);
+    }
+
+    let mut data = Vec::with_capacity(audio.samples.len() * width);
+    for sample in &audio.samples {
+        if S::FLOAT {
+            data.extend_from_slice(&(sample.to_f64() as f32).to_le_bytes());
+        } else {
+            let bytes = i32::from_f64(sample.to_f64()).to_le_bytes();
+            if width == 1 {
+                data.push(bytes[3] ^ 0x80);
+            } else {
+                data.extend_from_slice(&bytes[4 - width..]);
+            }
+        }
+    }
+
+    (fmt, data)
+}
+
+fn assemble(chunks: &[([u8; 4], &[u8])]) -> Vec<u8> {
+    let body_len: usize = chunks.iter().map(|(_, payload)| 8 + payload.len() + payload.len() % 2).sum();
+    let mut file = Vec::with_capacity(12 + body_len);
+    file.extend_from_slice(b"RIFF");
+    file.extend_from_slice(&((4 + body_len) as u32).to_le_bytes());
+    file.extend_from_slice(b"WAVE");
+    for (id, payload) in chunks {
+        file.extend_from_slice(id);
+        file.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        file.extend_from_slice(payload);
+        if payload.len() % 2 == 1 {
+            file.push(0);
+        }
+    }
+    file
+}
+
+pub fn write_wav<S: Sample>(audio: &AudioData<S>, path: impl AsRef<Path>) -> Result<(), BramError> {
+    fs::write(path, encode_wav(audio))?;
+    Ok(())
+}
+
+pub fn write_wav_with_source<S: Sample>(audio: &AudioData<S>, source: &WavBinary, path: impl AsRef<Path>) -> Result<(), BramError> {
+    fs::write(path, encode_wav_with_source(audio, source))?;
+    Ok(())
+}
+
+fn output_bits<S: Sample>(bits_per_sample: u16) -> u16 {
+    if S::FLOAT || bits_per_sample == 0 {
+        return S::BITS;
+    }
+    (bits_per_sample.div_ceil(8) * 8).clamp(8, S::BITS)
+}
+
+#[cfg(test)]
+mod wav_writer_tests {
+    use crate::audio_data::AudioData;
+    use crate::sample::Sample;
+    use crate::wav_binary::WavBinary;
+    use crate::wav_writer::{encode_wav, encode_wav_with_source};
+
+    fn audio<S: Sample>(samples: Vec<S>, channels: u16, bits_per_sample: u16) -> AudioData<S> {
+        AudioData {
+            samples,
+            channels,
+            sample_rate: 44100,
+            bits_per_sample,
+            channel_mask: None,
+        }
+    }
+
+    #[test]
+    fn encode_round_trips_through_parser() {
+        let audio = audio(vec![0i16, 32767, -32768, 1, 100, -100], 2, 16);
+        let bytes = encode_wav(&audio);
+        assert_eq!(bytes.len(), 44 + 12);
+        assert_eq!(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]), 48);
+        let decoded = AudioData::try_from(&WavBinary { data: bytes }).unwrap();
+        assert_eq!(decoded.samples, audio.samples);
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.sample_rate, 44100);
+    }
+
+    #[test]
+    fn encode_pads_odd_sized_data_chunk() {
+        let bytes = encode_wav(&audio(vec![0i16, 256, -256], 1, 8));
+        assert_eq!(bytes.len(), 44 + 4);
+        assert_eq!(u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]), 3);
+        assert_eq!(&bytes[44..], &[0x80, 0x81, 0x7F, 0x00]);
+        let decoded = AudioData::try_from(&WavBinary { data: bytes }).unwrap();
+        assert_eq!(decoded.samples, vec![0, 256, -256]);
+    }
+
+    #[test]
+    fn encode_keeps_24_bit_and_float_precision() {
+        let wide = audio(vec![0x100, i32::MIN], 1, 24);
+        let decoded = AudioData::<i32>::from_wav(&WavBinary { data: encode_wav(&wide) }).unwrap();
+        assert_eq!(decoded.bits_per_sample, 24);
+        assert_eq!(decoded.samples, wide.samples);
+
+        let float = audio(vec![0.25f32, -0.125], 1, 32);
+        let decoded = AudioData::<f32>::from_wav(&WavBinary { data: encode_wav(&float) }).unwrap();
+        assert_eq!(decoded.samples, float.samples);
+    }
+
+    #[test]
+    fn encode_writes_extensible_header_for_channel_masks() {
+        let mut surround = audio(vec![0i16; 6], 6, 16);
+        surround.channel_mask = Some(0x3F);
+        let decoded = AudioData::try_from(&WavBinary { data: encode_wav(&surround) }).unwrap();
+        assert_eq!(decoded.channel_mask, Some(0x3F));
+        assert_eq!(decoded.channels, 6);
+    }
+
+    #[test]
+    fn encode_with_source_keeps_unknown_chunks_in_place() {
+        let mut source = encode_wav(&audio(vec![1i16, 2, 3, 4], 1, 16));
+        source.splice(36..36, b"bext\x03\x00\x00\x00abc\x00".iter().copied());
+        source.extend_from_slice(b"iXML\x04\x00\x00\x00<x/>");
+        let source = WavBinary { data: source };
+
+        let edited = audio(vec![9i16, 8], 1, 16);
+        let bytes = encode_wav_with_source(&edited, &source);
+        assert_eq!(u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize, bytes.len() - 8);
+        let written = WavBinary { data: bytes };
+        let ids: Vec<[u8; 4]> = written.chunks().map(|chunk| chunk.id).collect();
+        assert_eq!(ids, vec![*b"fmt ", *b"bext", *b"data", *b"iXML"]);
+        assert_eq!(written.chunk(b"bext").unwrap().payload, b"abc");
+        assert_eq!(written.chunk(b"iXML").unwrap().payload, b"<x/>");
+        assert_eq!(AudioData::try_from(&written).unwrap().samples, vec![9, 8]);
+    }
+}
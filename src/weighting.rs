@@ -0,0 +1,208 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_1_SQRT_2, PI};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::FloatMath;
+use crate::sample::Sample;
+
+const A_WEIGHTING_POLES: [f64; 4] = [20.598997, 107.65265, 737.86223, 12194.217];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weighting {
+    #[default]
+    None,
+    K,
+    A,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    state: [f64; 2],
+}
+
+impl Biquad {
+    fn new(b: [f64; 3], a: [f64; 3]) -> Self {
+        Self {
+            b: [b[0] / a[0], b[1] / a[0], b[2] / a[0]],
+            a: [a[1] / a[0], a[2] / a[0]],
+            state: [0.0; 2],
+        }
+    }
+
+    fn high_shelf(sample_rate: f64, frequency: f64, gain_db: f64, q: f64) -> Self {
+        let a = 10f64.powf(gain_db / 40.0);
+        let w0 = 2.0 * PI * frequency / sample_rate;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * q);
+        let sqrt_a = a.sqrt();
+        Self::new(
+            [
+                a * ((a + 1.0) + (a - 1.0) * cos + 2.0 * sqrt_a * alpha),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - 2.0 * sqrt_a * alpha),
+            ],
+            [
+                (a + 1.0) - (a - 1.0) * cos + 2.0 * sqrt_a * alpha,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - 2.0 * sqrt_a * alpha,
+            ],
+        )
+    }
+
+    fn high_pass(sample_rate: f64, frequency: f64, q: f64) -> Self {
+        let w0 = 2.0 * PI * frequency / sample_rate;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * q);
+        Self::new(
+            [(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    fn first_order_high_pass(sample_rate: f64, pole: f64) -> Self {
+        let k = 2.0 * sample_rate;
+        let w = 2.0 * PI * pole;
+        Self::new([k, -k, 0.0], [k + w, w - k, 0.0])
+    }
+
+    fn first_order_low_pass(sample_rate: f64, pole: f64) -> Self {
+        let k = 2.0 * sample_rate;
+        let w = 2.0 * PI * pole;
+        Self::new([w, w, 0.0], [k + w, w - k, 0.0])
+    }
+
+    pub(crate) fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.state[0];
+        self.state[0] = self.b[1] * input - self.a[0] * output + self.state[1];
+        self.state[1] = self.b[2] * input - self.a[1] * output;
+        output
+    }
+
+    fn magnitude_at(&self, sample_rate: f64, frequency: f64) -> f64 {
+        let w = 2.0 * PI * frequency / sample_rate;
+        let (z1, z2) = ((-w).sin_cos(), (-2.0 * w).sin_cos());
+        let numerator = (
+            self.b[0] + self.b[1] * z1.1 + self.b[2] * z2.1,
+            self.b[1] * z1.0 + self.b[2] * z2.0,
+        );
+        let denominator = (
+            1.0 + self.a[0] * z1.1 + self.a[1] * z2.1,
+            self.a[0] * z1.0 + self.a[1] * z2.0,
+        );
+        numerator.0.hypot(numerator.1) / denominator.0.hypot(denominator.1)
+    }
+}
+
+pub(crate) fn k_weighting_filters(sample_rate: u32) -> Vec<Biquad> {
+    let sample_rate = sample_rate as f64;
+    vec![
+        Biquad::high_shelf(sample_rate, 1500.0, 4.0, FRAC_1_SQRT_2),
+        Biquad::high_pass(sample_rate, 38.0, 0.5),
+    ]
+}
+
+fn a_weighting_filters(sample_rate: u32) -> (Vec<Biquad>, f64) {
+    let sample_rate = sample_rate as f64;
+    let [f1, f2, f3, f4] = A_WEIGHTING_POLES;
+    let filters = vec![
+        Biquad::first_order_high_pass(sample_rate, f1),
+        Biquad::first_order_high_pass(sample_rate, f1),
+        Biquad::first_order_high_pass(sample_rate, f2),
+        Biquad::first_order_high_pass(sample_rate, f3),
+        Biquad::first_order_low_pass(sample_rate, f4),
+        Biquad::first_order_low_pass(sample_rate, f4),
+    ];
+    let gain_at_1khz: f64 = filters
+        .iter()
+        .map(|filter| filter.magnitude_at(sample_rate, 1000.0))
+        .product();
+    (filters, 1.0 / gain_at_1khz)
+}
+
+pub(crate) struct WeightingFilter {
+    chains: Vec<Vec<Biquad>>,
+    gain: f64,
+}
+
+impl WeightingFilter {
+    pub(crate) fn new(weighting: Weighting, sample_rate: u32, channels: u16) -> Option<Self> {
+        let (filters, gain) = match weighting {
+            Weighting::None => return None,
+            Weighting::K => (k_weighting_filters(sample_rate), 1.0),
+            Weighting::A => a_weighting_filters(sample_rate),
+        };
+        Some(Self {
+            chains: vec![filters; channels.max(1) as usize],
+            gain,
+        })
+    }
+
+    pub(crate) fn process<S: Sample>(&mut self, samples: &[S]) -> Vec<S> {
+        let channels = self.chains.len();
+        samples
+            .iter()
+            .enumerate()
+            .map(|(index, sample)| {
+                let filtered = self.chains[index % channels]
+                    .iter_mut()
+                    .fold(sample.to_f64(), |value, filter| filter.process(value));
+                S::from_f64((filtered * self.gain).clamp(-1.0, 1.0))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod weighting_tests {
+    use crate::weighting::{Weighting, WeightingFilter};
+
+    fn sine(frequency: f64, sample_rate: u32) -> Vec<i16> {
+        (0..sample_rate)
+            .map(|i| {
+                let t = i as f64 / sample_rate as f64;
+                (10000.0 * (2.0 * std::f64::consts::PI * frequency * t).sin()) as i16
+            })
+            .collect()
+    }
+
+    fn rms_db_change(frequency: f64, weighting: Weighting) -> f64 {
+        let input = sine(frequency, 48000);
+        let mut filter = WeightingFilter::new(weighting, 48000, 1).unwrap();
+        let output = filter.process(&input);
+        let rms = |samples: &[i16]| {
+            let tail = &samples[samples.len() / 2..];
+            (tail.iter().map(|s| (*s as f64).powi(2)).sum::<f64>() / tail.len() as f64).sqrt()
+        };
+        20.0 * (rms(&output) / rms(&input)).log10()
+    }
+
+    #[test]
+    fn a_weighting_is_flat_at_1khz_and_cuts_lows() {
+        assert!(rms_db_change(1000.0, Weighting::A).abs() < 0.2);
+        let low = rms_db_change(50.0, Weighting::A);
+        assert!((-32.0..-28.0).contains(&low), "{low}");
+    }
+
+    #[test]
+    fn k_weighting_boosts_highs_and_cuts_rumble() {
+        assert!(rms_db_change(4000.0, Weighting::K) > 3.0);
+        assert!(rms_db_change(20.0, Weighting::K) < -6.0);
+    }
+
+    #[test]
+    fn no_weighting_builds_no_filter() {
+        assert!(WeightingFilter::new(Weighting::None, 8000, 2).is_none());
+    }
+
+    #[test]
+    fn filter_state_carries_across_blocks() {
+        let input = sine(1000.0, 8000);
+        let whole = WeightingFilter::new(Weighting::A, 8000, 2).unwrap().process(&input);
+        let mut filter = WeightingFilter::new(Weighting::A, 8000, 2).unwrap();
+        let mut blocks = filter.process(&input[..1000]);
+        blocks.extend(filter.process(&input[1000..]));
+        assert_eq!(blocks, whole);
+    }
+}